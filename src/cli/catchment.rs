@@ -0,0 +1,248 @@
+//! Aggregation of replies into catchments
+//!
+//! A reply arrives at the site in whose catchment the replying address is. Instead of one
+//! row per reply, the catchment mode prints one row per block (by default a /24 for IPv4
+//! and a /48 for IPv6) with the site that received most replies from the block. Blocks
+//! that replied to more than one site, because the block is split between catchments or
+//! routing changed during the run, are flagged. Replies are counted for the site code of
+//! the receiving client, or for the client itself if it has no site code.
+
+use super::enrichment::RowData;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const DEFAULT_PREFIX_LENGTH_V4: u8 = 24;
+const DEFAULT_PREFIX_LENGTH_V6: u8 = 48;
+
+/// Replies received by one site from a block
+struct SiteReplies {
+    site: String,
+    replies: u64,
+    first_receive_time: u64,
+}
+
+/// Collects the replies of a run per block
+pub struct Catchment {
+    prefix_length: Option<u8>,
+    blocks: HashMap<IpAddr, Vec<SiteReplies>>,
+}
+
+impl Catchment {
+    /// Without a prefix length, blocks are /24s for IPv4 and /48s for IPv6
+    pub fn new(prefix_length: Option<u8>) -> Catchment {
+        Catchment {
+            prefix_length,
+            blocks: HashMap::new(),
+        }
+    }
+
+    /// Counts a reply, a row as created by Columnizable::get_data
+    pub fn add(&mut self, row: &HashMap<String, RowData>) {
+        let source_address = match row.get("source_address") {
+            Some(RowData::IpAddress(address)) => *address,
+            _ => return,
+        };
+        let site = row
+            .get("site")
+            .map(|site| site.to_string())
+            .filter(|site| !site.is_empty())
+            .or_else(|| row.get("client_id").map(|client_id| client_id.to_string()))
+            .unwrap_or_default();
+        let receive_time = match row.get("receive_time") {
+            Some(RowData::Integer(receive_time)) => *receive_time,
+            _ => 0,
+        };
+
+        let block = self.block(source_address);
+        let sites = self.blocks.entry(block).or_insert_with(Vec::new);
+        match sites.iter_mut().find(|s| s.site == site) {
+            Some(site_replies) => {
+                site_replies.replies += 1;
+                site_replies.first_receive_time = site_replies.first_receive_time.min(receive_time);
+            }
+            None => sites.push(SiteReplies {
+                site,
+                replies: 1,
+                first_receive_time: receive_time,
+            }),
+        }
+    }
+
+    /// Prefix length of the blocks of an address family, at most the length of an address
+    fn prefix_length(&self, address: IpAddr) -> u8 {
+        match address {
+            IpAddr::V4(_) => self.prefix_length.unwrap_or(DEFAULT_PREFIX_LENGTH_V4).min(32),
+            IpAddr::V6(_) => self.prefix_length.unwrap_or(DEFAULT_PREFIX_LENGTH_V6).min(128),
+        }
+    }
+
+    /// Network address of the block an address is in
+    fn block(&self, address: IpAddr) -> IpAddr {
+        let length = self.prefix_length(address);
+        match address {
+            IpAddr::V4(a) => {
+                let mask = u32::max_value()
+                    .checked_shl(32 - u32::from(length))
+                    .unwrap_or(0);
+                IpAddr::V4(Ipv4Addr::from(u32::from(a) & mask))
+            }
+            IpAddr::V6(a) => {
+                let mask = u128::max_value()
+                    .checked_shl(128 - u32::from(length))
+                    .unwrap_or(0);
+                IpAddr::V6(Ipv6Addr::from(u128::from(a) & mask))
+            }
+        }
+    }
+
+    /// One row per block, ordered by block. The winning site is the site with most replies,
+    /// or the site that received the first reply if several sites have as many.
+    pub fn get_data(&self) -> Vec<HashMap<String, RowData>> {
+        let mut blocks = self
+            .blocks
+            .iter()
+            .collect::<Vec<(&IpAddr, &Vec<SiteReplies>)>>();
+        blocks.sort_by_key(|(block, _)| **block);
+
+        blocks
+            .into_iter()
+            .map(|(block, sites)| {
+                let winner = sites
+                    .iter()
+                    .max_by(|a, b| {
+                        a.replies
+                            .cmp(&b.replies)
+                            .then(b.first_receive_time.cmp(&a.first_receive_time))
+                    })
+                    .unwrap();
+                let replies = sites.iter().map(|s| s.replies).sum::<u64>();
+                let mut ordered = sites.iter().collect::<Vec<&SiteReplies>>();
+                ordered.sort_by_key(|s| (std::cmp::Reverse(s.replies), s.first_receive_time));
+                let site_replies = ordered
+                    .iter()
+                    .map(|s| format!("{}:{}", s.site, s.replies))
+                    .collect::<Vec<String>>()
+                    .join(";");
+                let prefix_length = self.prefix_length(*block);
+
+                let mut row: HashMap<String, RowData> = HashMap::new();
+                row.insert("block".to_string(), (*block).into());
+                row.insert("prefix_length".to_string(), u32::from(prefix_length).into());
+                row.insert("site".to_string(), winner.site.as_str().into());
+                row.insert("replies".to_string(), replies.into());
+                row.insert("duplicates".to_string(), (replies - 1).into());
+                row.insert("site_count".to_string(), (sites.len() as u64).into());
+                row.insert("multi_site".to_string(), u64::from(sites.len() > 1).into());
+                row.insert("site_replies".to_string(), site_replies.into());
+                row
+            })
+            .collect()
+    }
+
+    pub fn get_headers() -> Vec<String> {
+        vec![
+            "block",
+            "prefix_length",
+            "site",
+            "replies",
+            "duplicates",
+            "site_count",
+            "multi_site",
+            "site_replies",
+        ]
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>()
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod catchment_aggregation {
+    use super::*;
+    use std::str::FromStr;
+
+    fn reply(site: &str, source_address: &str, receive_time: u64) -> HashMap<String, RowData> {
+        let mut row: HashMap<String, RowData> = HashMap::new();
+        row.insert("client_id".to_string(), site.into());
+        row.insert(
+            "source_address".to_string(),
+            IpAddr::from_str(source_address).unwrap().into(),
+        );
+        row.insert("receive_time".to_string(), receive_time.into());
+        row
+    }
+
+    fn column(row: &HashMap<String, RowData>, name: &str) -> String {
+        row[name].to_string()
+    }
+
+    #[test]
+    fn replies_are_aggregated_per_block() {
+        let mut catchment = Catchment::new(None);
+        catchment.add(&reply("ams", "192.0.2.1", 10));
+        catchment.add(&reply("ams", "192.0.2.1", 11));
+        catchment.add(&reply("lax", "192.0.2.200", 5));
+        catchment.add(&reply("lax", "198.51.100.7", 20));
+        catchment.add(&reply("ams", "2001:db8:0:1::1", 30));
+
+        let rows = catchment.get_data();
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(column(&rows[0], "block"), "192.0.2.0");
+        assert_eq!(column(&rows[0], "site"), "ams");
+        assert_eq!(column(&rows[0], "replies"), "3");
+        assert_eq!(column(&rows[0], "duplicates"), "2");
+        assert_eq!(column(&rows[0], "multi_site"), "1");
+        assert_eq!(column(&rows[0], "site_replies"), "ams:2;lax:1");
+
+        assert_eq!(column(&rows[1], "block"), "198.51.100.0");
+        assert_eq!(column(&rows[1], "duplicates"), "0");
+        assert_eq!(column(&rows[1], "multi_site"), "0");
+
+        assert_eq!(column(&rows[2], "block"), "2001:db8::");
+        assert_eq!(column(&rows[2], "prefix_length"), "48");
+    }
+
+    #[test]
+    fn ties_go_to_the_first_reply() {
+        let mut catchment = Catchment::new(Some(16));
+        catchment.add(&reply("ams", "192.0.2.1", 10));
+        catchment.add(&reply("lax", "192.0.200.1", 5));
+
+        let rows = catchment.get_data();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(column(&rows[0], "block"), "192.0.0.0");
+        assert_eq!(column(&rows[0], "site"), "lax");
+        assert_eq!(column(&rows[0], "site_count"), "2");
+    }
+
+    #[test]
+    fn prefix_lengths_are_capped_per_family() {
+        let mut catchment = Catchment::new(Some(64));
+        catchment.add(&reply("ams", "192.0.2.1", 10));
+        catchment.add(&reply("ams", "2001:db8::1", 10));
+
+        let rows = catchment.get_data();
+        assert_eq!(column(&rows[0], "block"), "192.0.2.1");
+        assert_eq!(column(&rows[0], "prefix_length"), "32");
+        assert_eq!(column(&rows[1], "block"), "2001:db8::");
+        assert_eq!(column(&rows[1], "prefix_length"), "64");
+    }
+
+    #[test]
+    fn clients_are_counted_for_their_site() {
+        let mut catchment = Catchment::new(None);
+        let mut first = reply("ams01", "192.0.2.1", 10);
+        first.insert("site".to_string(), "ams".into());
+        let mut second = reply("ams02", "192.0.2.2", 11);
+        second.insert("site".to_string(), "ams".into());
+        catchment.add(&first);
+        catchment.add(&second);
+
+        let rows = catchment.get_data();
+        assert_eq!(column(&rows[0], "site"), "ams");
+        assert_eq!(column(&rows[0], "site_count"), "1");
+        assert_eq!(column(&rows[0], "site_replies"), "ams:2");
+    }
+}
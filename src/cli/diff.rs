@@ -0,0 +1,414 @@
+//! Comparison of the catchments of two measurement runs
+//!
+//! Reads two result files as printed by the start, campaign and results commands (CSV or
+//! JSON, one row per reply or one row per block in catchment mode) and reports the blocks
+//! that switched site, appeared or disappeared, with summaries per site and per AS.
+
+use super::catchment::Catchment;
+use super::enrichment::{IP2ASNTransformer, RowData, Transformer};
+use super::parse_prefix_length;
+use clap::ArgMatches;
+use prettytable::{format, Cell, Row, Table};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::net::IpAddr;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Switched,
+    Appeared,
+    Disappeared,
+}
+
+impl Change {
+    fn name(self) -> &'static str {
+        match self {
+            Change::Switched => "switched",
+            Change::Appeared => "appeared",
+            Change::Disappeared => "disappeared",
+        }
+    }
+}
+
+/// A block whose site differs between the runs
+#[derive(Debug, PartialEq)]
+pub struct BlockChange {
+    pub block: IpAddr,
+    pub change: Change,
+    pub site_before: Option<String>,
+    pub site_after: Option<String>,
+}
+
+/// Blocks in the catchment of a site, in both runs
+#[derive(Default, Debug, PartialEq)]
+pub struct SiteSummary {
+    pub blocks_before: u64,
+    pub blocks_after: u64,
+    pub gained: u64,
+    pub lost: u64,
+}
+
+pub fn execute(args: &ArgMatches) {
+    let prefix_length = parse_prefix_length(args);
+    let mut catchments = Vec::new();
+    for path in &[
+        args.value_of("BEFORE").unwrap(),
+        args.value_of("AFTER").unwrap(),
+    ] {
+        match load_catchment(path, prefix_length) {
+            Ok(catchment) => catchments.push(catchment),
+            Err(e) => {
+                error!("Unable to read {}: {}", path, e);
+                return;
+            }
+        }
+    }
+    let (before, after) = (&catchments[0], &catchments[1]);
+    let changes = compare(before, after);
+
+    let asn_transformer = args
+        .value_of("ip2asn")
+        .map(|path| IP2ASNTransformer::new("block", "block_asn", path));
+
+    if args.is_present("blocks") {
+        print_changes(args, &changes, &asn_transformer);
+        return;
+    }
+
+    let mut table = summary_table(&["Site", "Before", "After", "Gained", "Lost"]);
+    for (site, summary) in summarize_sites(before, after) {
+        table.add_row(Row::new(vec![
+            Cell::new(&site),
+            Cell::new(&summary.blocks_before.to_string()),
+            Cell::new(&summary.blocks_after.to_string()),
+            Cell::new(&summary.gained.to_string()),
+            Cell::new(&summary.lost.to_string()),
+        ]));
+    }
+    table.printstd();
+    println!(
+        "{} blocks before, {} blocks after, {} switched, {} appeared, {} disappeared",
+        before.len(),
+        after.len(),
+        count(&changes, Change::Switched),
+        count(&changes, Change::Appeared),
+        count(&changes, Change::Disappeared)
+    );
+
+    if let Some(transformer) = asn_transformer {
+        // Changes per AS, the AS with most changes first
+        let mut per_asn: HashMap<String, [u64; 3]> = HashMap::new();
+        for change in &changes {
+            let counts = per_asn
+                .entry(block_asn(&transformer, change.block))
+                .or_insert([0; 3]);
+            counts[change.change as usize] += 1;
+        }
+        let mut per_asn = per_asn.into_iter().collect::<Vec<(String, [u64; 3])>>();
+        per_asn.sort_by_key(|(asn, counts)| {
+            (std::cmp::Reverse(counts.iter().sum::<u64>()), asn.clone())
+        });
+
+        let mut table = summary_table(&["AS", "Switched", "Appeared", "Disappeared"]);
+        for (asn, counts) in per_asn {
+            table.add_row(Row::new(vec![
+                Cell::new(&asn),
+                Cell::new(&counts[Change::Switched as usize].to_string()),
+                Cell::new(&counts[Change::Appeared as usize].to_string()),
+                Cell::new(&counts[Change::Disappeared as usize].to_string()),
+            ]));
+        }
+        table.printstd();
+    }
+}
+
+fn summary_table(titles: &[&str]) -> Table {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.set_titles(Row::new(titles.iter().map(|t| Cell::new(t)).collect()));
+    table
+}
+
+fn count(changes: &[BlockChange], change: Change) -> usize {
+    changes.iter().filter(|c| c.change == change).count()
+}
+
+fn block_asn(transformer: &IP2ASNTransformer, block: IpAddr) -> String {
+    let mut row: HashMap<String, RowData> = HashMap::new();
+    row.insert("block".to_string(), block.into());
+    transformer.transform(row)["block_asn"].to_string()
+}
+
+/// Prints the changed blocks as CSV or JSON
+fn print_changes(
+    args: &ArgMatches,
+    changes: &[BlockChange],
+    asn_transformer: &Option<Box<IP2ASNTransformer>>,
+) {
+    let mut headers = vec!["block", "change", "site_before", "site_after"]
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<String>>();
+    if let Some(transformer) = asn_transformer {
+        transformer.add_header(&mut headers);
+    }
+    if !args.is_present("json") {
+        println!("{}", headers.join(","));
+    }
+
+    for change in changes {
+        let mut row: HashMap<String, RowData> = HashMap::new();
+        row.insert("block".to_string(), change.block.into());
+        row.insert("change".to_string(), change.change.name().into());
+        row.insert(
+            "site_before".to_string(),
+            change.site_before.clone().unwrap_or_default().into(),
+        );
+        row.insert(
+            "site_after".to_string(),
+            change.site_after.clone().unwrap_or_default().into(),
+        );
+        if let Some(transformer) = asn_transformer {
+            row = transformer.transform(row);
+        }
+        if args.is_present("json") {
+            println!("{}", serde_json::to_string(&row).unwrap());
+        } else {
+            let values = headers
+                .iter()
+                .map(|h| row.get(h).map_or_else(String::new, |v| v.to_string()))
+                .collect::<Vec<String>>();
+            println!("{}", values.join(","));
+        }
+    }
+}
+
+/// Reads a result file into the site of every block. Files with one row per reply are
+/// aggregated into blocks of the given prefix length, files in catchment mode are used as is.
+pub fn load_catchment(
+    path: &str,
+    prefix_length: Option<u8>,
+) -> Result<HashMap<IpAddr, String>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    parse_catchment(BufReader::new(file), prefix_length)
+}
+
+pub fn parse_catchment<R: BufRead>(
+    reader: R,
+    prefix_length: Option<u8>,
+) -> Result<HashMap<IpAddr, String>, String> {
+    let mut catchment = Catchment::new(prefix_length);
+    let mut blocks = HashMap::new();
+    let mut headers: Option<Vec<String>> = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| e.to_string())?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let row = if line.starts_with('{') {
+            parse_json_row(&line)
+        } else if let Some(ref headers) = headers {
+            parse_csv_row(headers, &line)
+        } else {
+            headers = Some(line.split(',').map(|h| h.to_string()).collect());
+            continue;
+        }
+        .map_err(|e| format!("line {}: {}", index + 1, e))?;
+
+        if let (Some(RowData::IpAddress(block)), Some(site)) = (row.get("block"), row.get("site")) {
+            blocks.insert(*block, site.to_string());
+        } else if row.contains_key("source_address") {
+            catchment.add(&row);
+        } else {
+            return Err(format!(
+                "line {}: no source_address, or block and site",
+                index + 1
+            ));
+        }
+    }
+
+    for row in catchment.get_data() {
+        if let Some(RowData::IpAddress(block)) = row.get("block") {
+            blocks.insert(*block, row["site"].to_string());
+        }
+    }
+    Ok(blocks)
+}
+
+/// Values are typed the way Columnizable::get_data creates them
+fn parse_value(value: &str) -> RowData {
+    if let Ok(address) = IpAddr::from_str(value) {
+        RowData::IpAddress(address)
+    } else if let Ok(integer) = u64::from_str(value) {
+        RowData::Integer(integer)
+    } else {
+        RowData::String(value.to_string())
+    }
+}
+
+fn parse_csv_row(headers: &[String], line: &str) -> Result<HashMap<String, RowData>, String> {
+    let values = line.split(',').collect::<Vec<&str>>();
+    if values.len() != headers.len() {
+        return Err(format!(
+            "expected {} columns, found {}",
+            headers.len(),
+            values.len()
+        ));
+    }
+    Ok(headers
+        .iter()
+        .cloned()
+        .zip(values.into_iter().map(parse_value))
+        .collect())
+}
+
+fn parse_json_row(line: &str) -> Result<HashMap<String, RowData>, String> {
+    let object: HashMap<String, serde_json::Value> =
+        serde_json::from_str(line).map_err(|e| e.to_string())?;
+    Ok(object
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => parse_value(&s),
+                other => parse_value(&other.to_string()),
+            };
+            (key, value)
+        })
+        .collect())
+}
+
+/// Blocks that switched site, appeared or disappeared, ordered by block
+pub fn compare(
+    before: &HashMap<IpAddr, String>,
+    after: &HashMap<IpAddr, String>,
+) -> Vec<BlockChange> {
+    let mut changes = Vec::new();
+    for (block, site_before) in before {
+        match after.get(block) {
+            Some(site_after) if site_after == site_before => {}
+            Some(site_after) => changes.push(BlockChange {
+                block: *block,
+                change: Change::Switched,
+                site_before: Some(site_before.clone()),
+                site_after: Some(site_after.clone()),
+            }),
+            None => changes.push(BlockChange {
+                block: *block,
+                change: Change::Disappeared,
+                site_before: Some(site_before.clone()),
+                site_after: None,
+            }),
+        }
+    }
+    for (block, site_after) in after {
+        if !before.contains_key(block) {
+            changes.push(BlockChange {
+                block: *block,
+                change: Change::Appeared,
+                site_before: None,
+                site_after: Some(site_after.clone()),
+            });
+        }
+    }
+    changes.sort_by_key(|c| c.block);
+    changes
+}
+
+/// Catchment sizes per site, ordered by site
+pub fn summarize_sites(
+    before: &HashMap<IpAddr, String>,
+    after: &HashMap<IpAddr, String>,
+) -> BTreeMap<String, SiteSummary> {
+    let mut summaries: BTreeMap<String, SiteSummary> = BTreeMap::new();
+    for (block, site) in before {
+        let summary = summaries.entry(site.clone()).or_default();
+        summary.blocks_before += 1;
+        if after.get(block) != Some(site) {
+            summary.lost += 1;
+        }
+    }
+    for (block, site) in after {
+        let summary = summaries.entry(site.clone()).or_default();
+        summary.blocks_after += 1;
+        if before.get(block) != Some(site) {
+            summary.gained += 1;
+        }
+    }
+    summaries
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod catchment_diff {
+    use super::*;
+    use std::io::Cursor;
+
+    fn address(address: &str) -> IpAddr {
+        IpAddr::from_str(address).unwrap()
+    }
+
+    #[test]
+    fn result_files_are_read() {
+        let replies = "task_id,client_id,receive_time,source_address\n\
+                       1,ams,10,192.0.2.1\n\
+                       1,lax,11,192.0.2.2\n\
+                       1,lax,12,192.0.2.3\n";
+        let catchment = parse_catchment(Cursor::new(replies), None).unwrap();
+        assert_eq!(catchment.len(), 1);
+        assert_eq!(catchment[&address("192.0.2.0")], "lax");
+
+        let blocks = "{\"block\":\"198.51.100.0\",\"site\":\"ams\",\"replies\":2}\n\
+                      {\"block\":\"203.0.113.0\",\"site\":\"lax\",\"replies\":1}\n";
+        let catchment = parse_catchment(Cursor::new(blocks), None).unwrap();
+        assert_eq!(catchment.len(), 2);
+        assert_eq!(catchment[&address("198.51.100.0")], "ams");
+
+        let malformed = "block,site\n192.0.2.0,ams\n192.0.2.0\n";
+        assert_eq!(
+            parse_catchment(Cursor::new(malformed), None),
+            Err("line 3: expected 2 columns, found 1".to_string())
+        );
+    }
+
+    #[test]
+    fn changes_are_found() {
+        let mut before = HashMap::new();
+        before.insert(address("192.0.2.0"), "ams".to_string());
+        before.insert(address("198.51.100.0"), "ams".to_string());
+        before.insert(address("203.0.113.0"), "lax".to_string());
+        let mut after = HashMap::new();
+        after.insert(address("192.0.2.0"), "ams".to_string());
+        after.insert(address("198.51.100.0"), "lax".to_string());
+        after.insert(address("2001:db8::"), "lax".to_string());
+
+        let changes = compare(&before, &after);
+        assert_eq!(
+            changes.iter().map(|c| c.change).collect::<Vec<Change>>(),
+            vec![Change::Switched, Change::Disappeared, Change::Appeared]
+        );
+        assert_eq!(changes[0].site_after, Some("lax".to_string()));
+
+        let sites = summarize_sites(&before, &after);
+        assert_eq!(
+            sites["ams"],
+            SiteSummary {
+                blocks_before: 2,
+                blocks_after: 1,
+                gained: 0,
+                lost: 1
+            }
+        );
+        assert_eq!(
+            sites["lax"],
+            SiteSummary {
+                blocks_before: 1,
+                blocks_after: 2,
+                gained: 2,
+                lost: 1
+            }
+        );
+    }
+}
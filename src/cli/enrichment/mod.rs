@@ -1,4 +1,4 @@
-use crate::schema::verfploeter::TaskResult;
+use crate::schema::verfploeter::{Metadata, TaskResult};
 use maxminddb::geoip2::{Country, Isp};
 use maxminddb::Reader;
 use serde::{Serialize, Serializer};
@@ -83,41 +83,93 @@ impl From<f64> for RowData {
 impl Columnizable for TaskResult {
     fn get_data(&self) -> Vec<HashMap<String, RowData>> {
         let task_id = self.get_task_id();
-        let client_id = self.get_client().get_metadata().get_hostname();
+        let campaign_id = self.get_campaign_id();
+        let client = self.get_client().get_metadata();
         let mut results: Vec<HashMap<String, RowData>> = Vec::new();
         for result in self.get_result_list().iter() {
-            if result.has_ping() {
+            // Echo replies and DNS responses both carry the payload of the probe
+            let reply = if result.has_ping() {
                 let ping = result.get_ping();
+                Some((
+                    ping.get_source_address(),
+                    ping.get_destination_address(),
+                    ping.get_receive_time(),
+                    ping.get_payload(),
+                    ping.ttl,
+                ))
+            } else if result.has_dns() {
+                let dns = result.get_dns();
+                Some((
+                    dns.get_source_address(),
+                    dns.get_destination_address(),
+                    dns.get_receive_time(),
+                    dns.get_payload(),
+                    dns.ttl,
+                ))
+            } else {
+                None
+            };
+            if let Some((source_address, destination_address, receive_time, payload, ttl)) = reply {
                 let mut row: HashMap<String, RowData> = HashMap::new();
                 row.insert("task_id".to_string(), task_id.into());
-                row.insert("client_id".to_string(), client_id.into());
+                row.insert("campaign_id".to_string(), campaign_id.into());
+                insert_client(&mut row, client);
                 row.insert(
                     "transmit_time".to_string(),
-                    ping.get_payload().get_transmit_time().into());
-                row.insert("receive_time".to_string(), ping.get_receive_time().into());
+                    payload.get_transmit_time().into());
+                row.insert("receive_time".to_string(), receive_time.into());
+                insert_time_diffs(&mut row, self, payload.get_transmit_time(), receive_time);
                 row.insert(
-                    "send_receive_time_diff".to_string(),
-                    (((ping.get_receive_time() - ping.get_payload().get_transmit_time()) as f64)
-                        / 1_000_000f64)
-                        .into(),
+                    "source_address".to_string(),
+                    IpAddr::from(source_address).into(),
+                );
+                row.insert(
+                    "destination_address".to_string(),
+                    IpAddr::from(destination_address).into(),
+                );
+                row.insert(
+                    "meta_source_address".to_string(),
+                    IpAddr::from(payload.get_source_address()).into(),
+                );
+                row.insert(
+                    "meta_destination_address".to_string(),
+                    IpAddr::from(payload.get_destination_address()).into(),
+                );
+                insert_ttl(&mut row, ttl);
+                results.push(row);
+            } else if result.has_tcp() {
+                // A TCP reply carries no payload, the probe went from the address the
+                // reply was received on to the address that replied
+                let tcp = result.get_tcp();
+                let mut row: HashMap<String, RowData> = HashMap::new();
+                row.insert("task_id".to_string(), task_id.into());
+                row.insert("campaign_id".to_string(), campaign_id.into());
+                insert_client(&mut row, client);
+                row.insert("transmit_time".to_string(), tcp.get_transmit_time().into());
+                row.insert("receive_time".to_string(), tcp.get_receive_time().into());
+                insert_time_diffs(
+                    &mut row,
+                    self,
+                    tcp.get_transmit_time(),
+                    tcp.get_receive_time(),
                 );
                 row.insert(
                     "source_address".to_string(),
-                    IpAddr::from(ping.get_source_address()).into(),
+                    IpAddr::from(tcp.get_source_address()).into(),
                 );
                 row.insert(
                     "destination_address".to_string(),
-                    IpAddr::from(ping.get_destination_address()).into(),
+                    IpAddr::from(tcp.get_destination_address()).into(),
                 );
                 row.insert(
                     "meta_source_address".to_string(),
-                    IpAddr::from(ping.get_payload().get_source_address()).into(),
+                    IpAddr::from(tcp.get_destination_address()).into(),
                 );
                 row.insert(
                     "meta_destination_address".to_string(),
-                    IpAddr::from(ping.get_payload().get_destination_address()).into(),
+                    IpAddr::from(tcp.get_source_address()).into(),
                 );
-                row.insert("ttl".to_string(), ping.ttl.into());
+                insert_ttl(&mut row, tcp.ttl);
                 results.push(row);
             }
         }
@@ -127,10 +179,19 @@ impl Columnizable for TaskResult {
     fn get_headers() -> Vec<String> {
         vec![
             "task_id",
+            "campaign_id",
             "client_id",
+            "client_hostname",
+            "site",
+            "location",
+            "announced_prefix",
             "transmit_time",
             "receive_time",
             "send_receive_time_diff",
+            "transmit_clock_offset",
+            "receive_clock_offset",
+            "corrected_time_diff",
+            "clock_error",
             "source_address",
             "destination_address",
             "meta_source_address",
@@ -143,6 +204,71 @@ impl Columnizable for TaskResult {
     }
 }
 
+/// Adds the identity of the client that received a reply to its row
+fn insert_client(row: &mut HashMap<String, RowData>, client: &Metadata) {
+    row.insert("client_id".to_string(), client.id().into());
+    row.insert("client_hostname".to_string(), client.get_hostname().into());
+    row.insert("site".to_string(), client.get_site().into());
+    row.insert("location".to_string(), client.get_location().into());
+    row.insert(
+        "announced_prefix".to_string(),
+        client.get_announced_prefix().into(),
+    );
+}
+
+/// Adds the TTL (or hop limit) of a reply to its row, a TTL of 0 is unknown: replies are
+/// received on raw IPv6 sockets without their hop limit
+fn insert_ttl(row: &mut HashMap<String, RowData>, ttl: u32) {
+    if ttl != 0 {
+        row.insert("ttl".to_string(), ttl.into());
+    }
+}
+
+/// Nanoseconds to milliseconds
+fn millis(nanoseconds: i64) -> RowData {
+    (nanoseconds as f64 / 1_000_000f64).into()
+}
+
+/// Adds the time from transmitting a probe to receiving its reply in milliseconds, which is
+/// negative when the clock of the receiving client is behind that of the transmitting one.
+/// When the clock offsets of both clients are known, the time corrected for the difference
+/// between their clocks is added too, with the maximum error of that correction.
+fn insert_time_diffs(
+    row: &mut HashMap<String, RowData>,
+    task_result: &TaskResult,
+    transmit_time: u64,
+    receive_time: u64,
+) {
+    let diff = receive_time as i64 - transmit_time as i64;
+    row.insert("send_receive_time_diff".to_string(), millis(diff));
+    if !task_result.has_transmitter_clock() || !task_result.has_receiver_clock() {
+        return;
+    }
+
+    let transmitter = task_result.get_transmitter_clock();
+    let receiver = task_result.get_receiver_clock();
+    // Each offset is off by at most half the delay of its estimate, a client that received
+    // the replies to its own probes measured both with the same clock
+    let error = if transmitter.get_client_id() == receiver.get_client_id() {
+        0
+    } else {
+        (transmitter.get_delay() + receiver.get_delay()) / 2
+    };
+    row.insert(
+        "transmit_clock_offset".to_string(),
+        millis(transmitter.get_offset()),
+    );
+    row.insert(
+        "receive_clock_offset".to_string(),
+        millis(receiver.get_offset()),
+    );
+    row.insert(
+        "corrected_time_diff".to_string(),
+        millis(diff - receiver.get_offset() + transmitter.get_offset()),
+    );
+    row.insert("clock_error".to_string(), millis(error as i64));
+}
+
 pub trait Transformer {
     fn new(source: &str, destination: &str, data: &str) -> Box<Self>
     where
@@ -234,3 +360,58 @@ impl Transformer for IP2ASNTransformer {
         header.push(self.destination.to_string());
     }
 }
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod time_diffs {
+    use super::*;
+    use crate::schema::verfploeter::ClockOffset;
+
+    fn clock(client_id: &str, offset: i64, delay: u64) -> ClockOffset {
+        let mut clock = ClockOffset::new();
+        clock.set_client_id(client_id.to_string());
+        clock.set_offset(offset);
+        clock.set_delay(delay);
+        clock
+    }
+
+    fn column(row: &HashMap<String, RowData>, name: &str) -> Option<String> {
+        row.get(name).map(|value| value.to_string())
+    }
+
+    #[test]
+    fn receiving_clock_behind_gives_negative_time() {
+        let mut row = HashMap::new();
+        insert_time_diffs(&mut row, &TaskResult::new(), 5_000_000, 2_000_000);
+        assert_eq!(column(&row, "send_receive_time_diff"), Some("-3".to_string()));
+        assert_eq!(column(&row, "corrected_time_diff"), None);
+    }
+
+    #[test]
+    fn time_is_corrected_for_clock_offsets() {
+        // The receiving clock is 10ms behind the server, the transmitting clock 5ms ahead
+        let mut task_result = TaskResult::new();
+        task_result.set_transmitter_clock(clock("ams", 5_000_000, 2_000_000));
+        task_result.set_receiver_clock(clock("lax", -10_000_000, 4_000_000));
+
+        let mut row = HashMap::new();
+        insert_time_diffs(&mut row, &task_result, 100_000_000, 120_000_000);
+        assert_eq!(column(&row, "send_receive_time_diff"), Some("20".to_string()));
+        assert_eq!(column(&row, "transmit_clock_offset"), Some("5".to_string()));
+        assert_eq!(column(&row, "receive_clock_offset"), Some("-10".to_string()));
+        assert_eq!(column(&row, "corrected_time_diff"), Some("35".to_string()));
+        assert_eq!(column(&row, "clock_error"), Some("3".to_string()));
+    }
+
+    #[test]
+    fn one_clock_needs_no_correction() {
+        let mut task_result = TaskResult::new();
+        task_result.set_transmitter_clock(clock("ams", 5_000_000, 2_000_000));
+        task_result.set_receiver_clock(clock("ams", 5_000_000, 2_000_000));
+
+        let mut row = HashMap::new();
+        insert_time_diffs(&mut row, &task_result, 100_000_000, 112_000_000);
+        assert_eq!(column(&row, "corrected_time_diff"), Some("12".to_string()));
+        assert_eq!(column(&row, "clock_error"), Some("0".to_string()));
+    }
+}
@@ -0,0 +1,237 @@
+//! Loading of hitlists, the files with the destinations of a task
+//!
+//! Every line of a hitlist holds one destination, an address or a prefix in CIDR
+//! notation. Blank lines and everything after a '#' are ignored. Lines with more than
+//! one column follow the layout of the ISI/USC hitlists: a score followed by the
+//! address, which may be written as 8 hex digits. Files are decompressed if they are
+//! gzipped, which is recognized by their first bytes rather than by their name.
+
+use flate2::read::GzDecoder;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Magic bytes at the start of a gzip file
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Largest number of destinations a single prefix may turn into
+const MAX_PREFIX_DESTINATIONS: u128 = 1 << 24;
+
+/// Prefix length of the blocks of which one address is probed when sampling prefixes
+const SAMPLE_PREFIX_LENGTH_V4: u8 = 24;
+const SAMPLE_PREFIX_LENGTH_V6: u8 = 48;
+
+/// How the prefixes in a hitlist are turned into destinations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrefixMode {
+    /// Every address of the prefix
+    Expand,
+    /// The first host address of every /24 (IPv6: /48) of the prefix
+    Sample,
+}
+
+/// Destinations read from a hitlist, and the lines that could not be read
+pub struct Hitlist {
+    pub destinations: Vec<IpAddr>,
+    /// Line number (starting at 1) and reason of every malformed line
+    pub errors: Vec<(usize, String)>,
+}
+
+/// Reads the hitlist at path, which may be gzipped
+pub fn load(path: &str, mode: PrefixMode) -> std::io::Result<Hitlist> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let gzipped = reader.fill_buf()?.starts_with(&GZIP_MAGIC);
+    if gzipped {
+        parse(BufReader::new(GzDecoder::new(reader)), mode)
+    } else {
+        parse(reader, mode)
+    }
+}
+
+/// Reads a hitlist line by line
+pub fn parse<R: BufRead>(reader: R, mode: PrefixMode) -> std::io::Result<Hitlist> {
+    let mut hitlist = Hitlist {
+        destinations: Vec::new(),
+        errors: Vec::new(),
+    };
+    for (index, line) in reader.lines().enumerate() {
+        match parse_line(&line?, mode) {
+            Ok(destinations) => hitlist.destinations.extend(destinations),
+            Err(e) => hitlist.errors.push((index + 1, e)),
+        }
+    }
+    Ok(hitlist)
+}
+
+/// Destinations on a line, none for blank lines and comments
+fn parse_line(line: &str, mode: PrefixMode) -> Result<Vec<IpAddr>, String> {
+    let line = match line.find('#') {
+        Some(comment) => &line[..comment],
+        None => line,
+    };
+    let columns = line.split_whitespace().collect::<Vec<&str>>();
+    let destination = match columns.len() {
+        0 => return Ok(Vec::new()),
+        1 => columns[0],
+        2 => {
+            if i64::from_str(columns[0]).is_err() {
+                return Err(format!("score '{}' is not a number", columns[0]));
+            }
+            columns[1]
+        }
+        n => return Err(format!("expected 1 or 2 columns, found {}", n)),
+    };
+
+    let (address, length) = parse_prefix(destination)?;
+    prefix_destinations(address, length, mode)
+}
+
+/// Address and prefix length of an address or prefix, a single address has the full length
+fn parse_prefix(destination: &str) -> Result<(IpAddr, u8), String> {
+    let mut parts = destination.splitn(2, '/');
+    let address = parts.next().unwrap();
+    let address = match IpAddr::from_str(address) {
+        Ok(address) => address,
+        Err(_) if address.len() == 8 => u32::from_str_radix(address, 16)
+            .map(|a| IpAddr::V4(Ipv4Addr::from(a)))
+            .map_err(|_| format!("'{}' is not an IP address", address))?,
+        Err(_) => return Err(format!("'{}' is not an IP address", address)),
+    };
+    let bits = if address.is_ipv4() { 32 } else { 128 };
+    let length = match parts.next() {
+        Some(length) => match u8::from_str(length) {
+            Ok(length) if length <= bits => length,
+            _ => return Err(format!("'{}' is not a valid prefix length", length)),
+        },
+        None => bits,
+    };
+    Ok((address, length))
+}
+
+/// Destinations to probe for a prefix
+fn prefix_destinations(
+    address: IpAddr,
+    length: u8,
+    mode: PrefixMode,
+) -> Result<Vec<IpAddr>, String> {
+    let (bits, sample_length, base) = match address {
+        IpAddr::V4(a) => (32, SAMPLE_PREFIX_LENGTH_V4, u128::from(u32::from(a))),
+        IpAddr::V6(a) => (128, SAMPLE_PREFIX_LENGTH_V6, u128::from(a)),
+    };
+    let host_bits = u32::from(bits - length);
+    // Every address of the prefix, or one per block of the sample size
+    let (step_bits, offset) = match mode {
+        PrefixMode::Sample if length < bits => {
+            let block_bits = host_bits.min(u32::from(bits - sample_length));
+            (block_bits, if block_bits > 1 { 1 } else { 0 })
+        }
+        _ => (0, 0),
+    };
+    if host_bits - step_bits > 127 || 1u128 << (host_bits - step_bits) > MAX_PREFIX_DESTINATIONS {
+        return Err(format!(
+            "prefix /{} is too large, it holds more than {} destinations",
+            length, MAX_PREFIX_DESTINATIONS
+        ));
+    }
+
+    let network = if host_bits == 128 {
+        0
+    } else {
+        base >> host_bits << host_bits
+    };
+    Ok((0..1u128 << (host_bits - step_bits))
+        .map(|i| network + (i << step_bits) + offset)
+        .map(|a| match address {
+            IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(a as u32)),
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(a)),
+        })
+        .collect())
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod hitlist_formats {
+    use super::*;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::{Cursor, Write};
+
+    fn addresses(list: &[&str]) -> Vec<IpAddr> {
+        list.iter().map(|a| IpAddr::from_str(a).unwrap()).collect()
+    }
+
+    #[test]
+    fn comments_and_columns_are_parsed() {
+        let input = "# fsdb -F t score ip\n\
+                     192.0.2.1\n\
+                     \n\
+                     2001:db8::1 # a comment\n\
+                     99\tc6336401\n\
+                     -2 198.51.100.2\n";
+        let hitlist = parse(Cursor::new(input), PrefixMode::Expand).unwrap();
+
+        assert!(hitlist.errors.is_empty());
+        assert_eq!(
+            hitlist.destinations,
+            addresses(&["192.0.2.1", "2001:db8::1", "198.51.100.1", "198.51.100.2"])
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_reported() {
+        let input = "192.0.2.1\nnot-an-address\n192.0.2.0/33\nhigh 192.0.2.2\n1 2 3\n192.0.2.3";
+        let hitlist = parse(Cursor::new(input), PrefixMode::Expand).unwrap();
+
+        assert_eq!(hitlist.destinations, addresses(&["192.0.2.1", "192.0.2.3"]));
+        let lines = hitlist
+            .errors
+            .iter()
+            .map(|(l, _)| *l)
+            .collect::<Vec<usize>>();
+        assert_eq!(lines, vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn prefixes_are_expanded_or_sampled() {
+        let expanded = parse(Cursor::new("192.0.2.9/30"), PrefixMode::Expand).unwrap();
+        assert_eq!(
+            expanded.destinations,
+            addresses(&["192.0.2.8", "192.0.2.9", "192.0.2.10", "192.0.2.11"])
+        );
+
+        let sampled = parse(
+            Cursor::new("10.1.0.0/22\n192.0.2.0/28\n2001:db8::/47"),
+            PrefixMode::Sample,
+        )
+        .unwrap();
+        assert_eq!(
+            sampled.destinations,
+            addresses(&[
+                "10.1.0.1",
+                "10.1.1.1",
+                "10.1.2.1",
+                "10.1.3.1",
+                "192.0.2.1",
+                "2001:db8::1",
+                "2001:db8:1::1"
+            ])
+        );
+
+        let too_large = parse(Cursor::new("2001:db8::/64"), PrefixMode::Expand).unwrap();
+        assert!(too_large.destinations.is_empty());
+        assert_eq!(too_large.errors.len(), 1);
+    }
+
+    #[test]
+    fn gzipped_files_are_decompressed() {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"192.0.2.1\n192.0.2.2\n").unwrap();
+        let path = std::env::temp_dir().join(format!("hitlist-{}.gz", std::process::id()));
+        std::fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let hitlist = load(path.to_str().unwrap(), PrefixMode::Expand).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(hitlist.destinations, addresses(&["192.0.2.1", "192.0.2.2"]));
+    }
+}
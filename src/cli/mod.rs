@@ -1,38 +1,75 @@
 use super::schema::verfploeter::{
-    Address, Client, Empty, Metadata, Ping, ScheduleTask, TaskId, TaskResult,
+    Ack, Address, Client, Destinations, Dns, Empty, Metadata, Ping, ResultRequest,
+    ScheduleCampaign, ScheduleTask, TaskId, TaskResult, TaskStatus, TaskSummary, TcpSyn,
 };
 use super::schema::verfploeter_grpc::VerfploeterClient;
 use clap::ArgMatches;
-use futures::Stream;
-use grpcio::{ChannelBuilder, Environment};
+use crate::{DESTINATION_CHUNK_SIZE, RESULT_FLUSH_INTERVAL};
+use futures::{future, Future, Sink, Stream};
+use grpcio::{
+    ChannelBuilder, ChannelCredentialsBuilder, ClientCStreamReceiver, ClientCStreamSender,
+    Environment, WriteFlags,
+};
 use prettytable::{color, format, Attr, Cell, Row, Table};
 use protobuf::RepeatedField;
 // use std::error::Error;
-use std::fs::File;
-use std::io::prelude::*;
-use std::io::BufReader;
-use std::net::Ipv4Addr;
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::str::FromStr;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
+mod catchment;
+mod diff;
 mod enrichment;
+mod hitlist;
+mod schedule;
+use crate::cli::catchment::Catchment;
+use crate::cli::hitlist::PrefixMode;
 use crate::cli::enrichment::{
-    Columnizable, IP2ASNTransformer, IP2CountryTransformer, TransformPipeline, Transformer,
+    Columnizable, IP2ASNTransformer, IP2CountryTransformer, RowData, TransformPipeline,
+    Transformer,
 };
 //use protobuf::descriptor::FieldOptions_CType::STRING;
 
+/// Number of consecutive times a broken result stream is resumed before giving up
+const RESUME_ATTEMPTS: u32 = 10;
+
 pub fn execute(args: &ArgMatches) {
     debug!("execute({:?})",args);
     let server = args.value_of("server").unwrap();
     let env = Arc::new(Environment::new(1));
-    let channel = ChannelBuilder::new(env)
-        .max_receive_message_len(100 * 1024 * 1024).connect(server);
+    let channel = match args.value_of("certificate").and_then(crate::read_file_content) {
+        Some(certificate) => {
+            let mut credentials = ChannelCredentialsBuilder::new().root_cert(certificate);
+            if let Some((client_certificate, client_key)) = crate::read_identity(args) {
+                credentials = credentials.cert(client_certificate, client_key);
+            }
+            ChannelBuilder::new(env).secure_connect(server, credentials.build())
+        }
+        None => ChannelBuilder::new(env).connect(server),
+    };
     let grpc_client = VerfploeterClient::new(channel);
 
     if args.subcommand_matches("client-list").is_some() {
         print_client_list(&grpc_client)
     } else if let Some(matches) = args.subcommand_matches("start") {
         perform_verfploeter_measurement(matches, &grpc_client, matches)
+    } else if let Some(matches) = args.subcommand_matches("campaign") {
+        perform_campaign(matches, &grpc_client)
+    } else if let Some(matches) = args.subcommand_matches("results") {
+        stream_results(matches, &grpc_client, parse_task_id(matches))
+    } else if args.subcommand_matches("task-list").is_some() {
+        print_task_list(&grpc_client)
+    } else if let Some(matches) = args.subcommand_matches("task-status") {
+        print_task_status(&grpc_client, parse_task_id(matches))
+    } else if let Some(matches) = args.subcommand_matches("task-cancel") {
+        cancel_task(&grpc_client, parse_task_id(matches))
+    } else if let Some(matches) = args.subcommand_matches("schedule") {
+        schedule::execute(matches, &grpc_client)
+    } else if let Some(matches) = args.subcommand_matches("diff") {
+        diff::execute(matches)
     } else {
         unimplemented!();
     }
@@ -40,26 +77,49 @@ pub fn execute(args: &ArgMatches) {
 
 fn print_client_list(grpc_client: &VerfploeterClient) {
     debug!("print_client_list()");
-    match grpc_client.list_clients(&Empty::new()) {
+    match grpc_client.list_clients_opt(&Empty::new(), crate::call_option()) {
         Ok(client_list) => {
             let mut table = Table::new();
             table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
-            table.add_row(Row::new(vec![
-                Cell::new("Index")
-                    .with_style(Attr::Bold)
-                    .with_style(Attr::ForegroundColor(color::GREEN)),
-                Cell::new("Hostname")
-                    .with_style(Attr::Bold)
-                    .with_style(Attr::ForegroundColor(color::GREEN)),
-                Cell::new("Version")
-                    .with_style(Attr::Bold)
-                    .with_style(Attr::ForegroundColor(color::GREEN)),
-            ]));
+            table.add_row(Row::new(
+                vec![
+                    "Index",
+                    "Client id",
+                    "Hostname",
+                    "Site",
+                    "Location",
+                    "Announced prefix",
+                    "Clock offset (ms)",
+                    "Version",
+                ]
+                .into_iter()
+                .map(|title| {
+                    Cell::new(title)
+                        .with_style(Attr::Bold)
+                        .with_style(Attr::ForegroundColor(color::GREEN))
+                })
+                .collect(),
+            ));
             for client in client_list.get_clients() {
+                let metadata = client.get_metadata();
+                let clock_offset = if client.has_clock() {
+                    format!(
+                        "{:.3} (+/- {:.3})",
+                        client.get_clock().get_offset() as f64 / 1_000_000f64,
+                        client.get_clock().get_delay() as f64 / 2_000_000f64
+                    )
+                } else {
+                    "unknown".to_string()
+                };
                 table.add_row(row!(
                     client.index,
-                    client.get_metadata().hostname,
-                    client.get_metadata().version
+                    metadata.id(),
+                    metadata.hostname,
+                    metadata.site,
+                    metadata.location,
+                    metadata.announced_prefix,
+                    clock_offset,
+                    metadata.version
                 ));
             }
             table.printstd();
@@ -69,129 +129,513 @@ fn print_client_list(grpc_client: &VerfploeterClient) {
     }
 }
 
+fn parse_task_id(matches: &ArgMatches) -> u32 {
+    matches
+        .value_of("TASK_ID")
+        .unwrap()
+        .parse::<u32>()
+        .expect("TASK_ID should be a 32-bits integer")
+}
+
+/// Seed of the destination order, 0 lets the server pick one
+fn parse_seed(matches: &ArgMatches) -> u64 {
+    matches.value_of("seed").map_or(0, |seed| {
+        seed.parse::<u64>().expect("Seed should be a 64-bits integer")
+    })
+}
+
+/// Seconds to wait for the last replies of a task, 0 lets the client use its default.
+/// Clients send their results every RESULT_FLUSH_INTERVAL seconds, a shorter grace period
+/// would finish the task before the results of its last replies arrive.
+fn parse_grace_period(matches: &ArgMatches) -> u32 {
+    matches.value_of("grace-period").map_or(0, |seconds| {
+        let seconds = seconds
+            .parse::<u32>()
+            .expect("Grace period should be a 32-bits integer");
+        if u64::from(seconds) < RESULT_FLUSH_INTERVAL {
+            warn!(
+                "grace period of {} seconds is below the minimum, using {} seconds",
+                seconds, RESULT_FLUSH_INTERVAL
+            );
+            return RESULT_FLUSH_INTERVAL as u32;
+        }
+        seconds
+    })
+}
+
+/// Parses the prefix length of catchment blocks, rejecting lengths over 128, the cap per
+/// address family (32 for IPv4 blocks) is applied by `Catchment::prefix_length`
+fn parse_prefix_length(matches: &ArgMatches) -> Option<u8> {
+    matches.value_of("prefix-length").map(|length| {
+        length
+            .parse::<u8>()
+            .ok()
+            .filter(|length| *length <= 128)
+            .expect("Prefix length should be an integer of at most 128")
+    })
+}
+
+fn task_table() -> Table {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.add_row(Row::new(
+        vec![
+            "Task",
+            "Campaign",
+            "Client",
+            "State",
+            "Sent",
+            "Destinations",
+            "Rate (pps)",
+            "Seed",
+            "Started (unix time)",
+        ]
+        .into_iter()
+        .map(|title| {
+            Cell::new(title)
+                .with_style(Attr::Bold)
+                .with_style(Attr::ForegroundColor(color::GREEN))
+        })
+        .collect(),
+    ));
+    table
+}
+
+fn add_task_row(table: &mut Table, task: &TaskStatus) {
+    // A rate of 0 means the client uses its own maximum rate
+    let rate = if task.get_rate() == 0 {
+        "client max".to_string()
+    } else {
+        task.get_rate().to_string()
+    };
+    let campaign = if task.get_campaign_id() == 0 {
+        "-".to_string()
+    } else {
+        task.get_campaign_id().to_string()
+    };
+    table.add_row(row!(
+        task.get_task_id(),
+        campaign,
+        task.get_client().get_metadata().id(),
+        task.get_state(),
+        task.get_destinations_sent(),
+        task.get_destinations(),
+        rate,
+        task.get_seed(),
+        task.get_start_time()
+    ));
+}
+
+fn print_task_list(grpc_client: &VerfploeterClient) {
+    debug!("print_task_list()");
+    match grpc_client.list_tasks_opt(&Empty::new(), crate::call_option()) {
+        Ok(task_list) => {
+            let mut table = task_table();
+            for task in task_list.get_tasks() {
+                add_task_row(&mut table, task);
+            }
+            table.printstd();
+            println!("Tasks: {}", task_list.get_tasks().len());
+        }
+        Err(e) => println!("unable to obtain task list: {}", e),
+    }
+}
+
+fn print_task_status(grpc_client: &VerfploeterClient, task_id: u32) {
+    debug!("print_task_status()");
+    let mut request = TaskId::new();
+    request.set_task_id(task_id);
+    match grpc_client.get_task_status_opt(&request, crate::call_option()) {
+        Ok(task) => {
+            let mut table = task_table();
+            add_task_row(&mut table, &task);
+            table.printstd();
+            let remaining = task.get_destinations().saturating_sub(task.get_destinations_sent());
+            println!("Destinations remaining: {}", remaining);
+        }
+        Err(e) => println!("unable to obtain status of task {}: {}", task_id, e),
+    }
+}
+
+fn cancel_task(grpc_client: &VerfploeterClient, task_id: u32) {
+    debug!("cancel_task()");
+    let mut request = TaskId::new();
+    request.set_task_id(task_id);
+    match grpc_client.cancel_task_opt(&request, crate::call_option()) {
+        Ok(ref ack) if ack.get_success() => println!("cancelling task {}", task_id),
+        Ok(ack) => println!(
+            "unable to cancel task {}: {}",
+            task_id,
+            ack.get_error_message()
+        ),
+        Err(e) => println!("unable to cancel task {}: {}", task_id, e),
+    }
+}
+
 fn perform_verfploeter_measurement(
     args: &ArgMatches,
     grpc_client: &VerfploeterClient,
     matches: &ArgMatches,
 ) {
     debug!("perform_verfploeter_measurement()");
-    // Get parameters
-    let client_hostname = matches.value_of("CLIENT_HOSTNAME").unwrap();
-    let source_ip: u32 =
-        u32::from(Ipv4Addr::from_str(matches.value_of("SOURCE_IP").unwrap()).unwrap());
-    let ip_file = matches.value_of("IP_FILE").unwrap();
-    debug!("client_hostname:{} source_ip:{} ip_file:{}",
-           client_hostname,
-           Ipv4Addr::from(source_ip),
-           ip_file);
+    let (schedule_task, destinations) = match load_task(matches) {
+        Some(task) => task,
+        None => return,
+    };
 
-    // Read IP Addresses (vector) from given file
-    let file = File::open(ip_file).unwrap_or_else(|_| panic!("Unable to open file {}", ip_file));
-    let buf_reader = BufReader::new(file);
-    let ips = buf_reader
-        .lines()
-        .map(|l| {
-            let mut address = Address::new();
-            address.set_v4(u32::from(Ipv4Addr::from_str(&l.unwrap()).unwrap()));
-            address
-        })
-        .collect::<Vec<Address>>();
+    // Send task to server
+    debug!("Sending GRPC_CLIENT.DO_TASK to server-->IN client DO set_ping");
+    let call = grpc_client.do_task_opt(crate::call_option());
+    let ack = upload(call, schedule_task, destinations, |chunk| {
+        let mut schedule_task = ScheduleTask::new();
+        schedule_task.set_destinations(chunk);
+        schedule_task
+    });
+    if let Some(task_id) = handle_ack(ack) {
+        stream_results(args, grpc_client, task_id);
+    }
+}
 
-    debug!("Loaded [{}] IPAddresses on _ips vector",ips.len());
+/// Builds the task to perform on the client named by CLIENT_HOSTNAME, probing with ICMP
+/// echo requests, TCP SYN segments or DNS queries
+fn load_task(matches: &ArgMatches) -> Option<(ScheduleTask, Vec<Address>)> {
+    // Get parameters
+    let client_hostname = matches.value_of("CLIENT_HOSTNAME").unwrap();
+    let (ping, destinations) = load_ping(matches)?;
 
     // Construct appropriate structs
-    let mut ping = Ping::new();
-    let mut address = Address::new();
-    address.set_v4(source_ip);
-    ping.set_source_address(address);
-    ping.set_destination_addresses(RepeatedField::from(ips));
     let mut client = Client::new();
     let mut metadata = Metadata::new();
     metadata.hostname = client_hostname.to_string();
     client.set_metadata(metadata);
 
     let mut schedule_task = ScheduleTask::new();
-    schedule_task.set_ping(ping);
+    if let Some(port) = matches.value_of("tcp") {
+        // Probe with TCP SYN segments instead of echo requests
+        let mut tcp_syn = TcpSyn::new();
+        tcp_syn.set_source_address(ping.get_source_address().clone());
+        tcp_syn.set_rate(ping.get_rate());
+        tcp_syn.set_destination_port(
+            port.parse::<u16>().expect("TCP port should be a 16-bits integer") as u32,
+        );
+        schedule_task.set_tcp_syn(tcp_syn);
+    } else if let Some(query_domain) = matches.value_of("dns") {
+        // Probe with DNS queries for names under the query domain
+        let mut dns = Dns::new();
+        dns.set_source_address(ping.get_source_address().clone());
+        dns.set_rate(ping.get_rate());
+        dns.set_query_domain(query_domain.to_string());
+        schedule_task.set_dns(dns);
+    } else {
+        schedule_task.set_ping(ping);
+    }
     schedule_task.set_client(client);
-    let mut scheduled_task_id = None;
-    let mut error_message = None;
-    let mut success = false;
+    schedule_task.set_seed(parse_seed(matches));
+    schedule_task.set_grace_period(parse_grace_period(matches));
+    Some((schedule_task, destinations))
+}
 
-    // Send task to server
-    //debug!("Sending GRPC_CLIENT.DO_TASK to server-->IN client{:?} DO set_ping", schedule_task);
-    debug!("Sending GRPC_CLIENT.DO_TASK to server-->IN client DO set_ping");
-    match grpc_client.do_task(&schedule_task) {
+/// Performs a campaign, pinging from one client or from every connected client in turn,
+/// and prints the results of all clients for the whole campaign
+fn perform_campaign(args: &ArgMatches, grpc_client: &VerfploeterClient) {
+    debug!("perform_campaign()");
+    let (ping, destinations) = match load_ping(args) {
+        Some(ping) => ping,
+        None => return,
+    };
+
+    let mut schedule_campaign = ScheduleCampaign::new();
+    schedule_campaign.set_ping(ping);
+    if let Some(client_hostname) = args.value_of("client") {
+        let mut client = Client::new();
+        let mut metadata = Metadata::new();
+        metadata.hostname = client_hostname.to_string();
+        client.set_metadata(metadata);
+        schedule_campaign.set_client(client);
+    }
+    schedule_campaign.set_rotate(args.is_present("rotate"));
+    schedule_campaign.set_seed(parse_seed(args));
+    schedule_campaign.set_grace_period(parse_grace_period(args));
+
+    let call = grpc_client.do_campaign_opt(crate::call_option());
+    let ack = upload(call, schedule_campaign, destinations, |chunk| {
+        let mut schedule_campaign = ScheduleCampaign::new();
+        schedule_campaign.set_destinations(chunk);
+        schedule_campaign
+    });
+    if let Some(campaign_id) = handle_ack(ack) {
+        stream_results(args, grpc_client, campaign_id);
+    }
+}
+
+/// Builds the ping from the SOURCE_IP argument, and reads its destinations from IP_FILE
+fn load_ping(matches: &ArgMatches) -> Option<(Ping, Vec<Address>)> {
+    let source_ip = IpAddr::from_str(matches.value_of("SOURCE_IP").unwrap()).unwrap();
+    let ip_file = matches.value_of("IP_FILE").unwrap();
+    debug!("source_ip:{} ip_file:{}", source_ip, ip_file);
+
+    // Read IP Addresses (vector) from given file
+    let prefix_mode = if matches.is_present("sample-prefixes") {
+        PrefixMode::Sample
+    } else {
+        PrefixMode::Expand
+    };
+    let hitlist = match hitlist::load(ip_file, prefix_mode) {
+        Ok(hitlist) => hitlist,
+        Err(e) => {
+            error!("Unable to read {}: {}", ip_file, e);
+            return None;
+        }
+    };
+    if !hitlist.errors.is_empty() {
+        for (line, e) in &hitlist.errors {
+            error!("{} line {}: {}", ip_file, line, e);
+        }
+        return None;
+    }
+    let ips = hitlist.destinations;
+
+    // Probes can only be sent to addresses of the same family as the source address
+    if ips.iter().any(|ip| ip.is_ipv4() != source_ip.is_ipv4()) {
+        error!(
+            "{} contains addresses of a different address family than source {}",
+            ip_file, source_ip
+        );
+        return None;
+    }
+    let ips = ips.into_iter().map(Address::from).collect::<Vec<Address>>();
+
+    debug!("Loaded [{}] IPAddresses on _ips vector",ips.len());
+
+    let mut ping = Ping::new();
+    if let Some(rate) = matches.value_of("rate") {
+        ping.set_rate(rate.parse::<u32>().expect("Rate should be a 32-bits integer"));
+    }
+    ping.set_source_address(source_ip.into());
+    Some((ping, ips))
+}
+
+/// Uploads a task (or campaign) followed by its destinations in chunks, each made into a
+/// message by make_chunk, and returns the acknowledgement of the server.
+/// Chunk n holds every n-th destination, so every chunk spans the whole list and the
+/// client, which probes the chunks one after the other, spreads its probes over all networks.
+fn upload<T>(
+    call: grpcio::Result<(ClientCStreamSender<T>, ClientCStreamReceiver<Ack>)>,
+    first: T,
+    destinations: Vec<Address>,
+    make_chunk: impl Fn(Destinations) -> T,
+) -> grpcio::Result<Ack> {
+    let (mut sink, receiver) = call?;
+    sink = sink.send((first, WriteFlags::default())).wait()?;
+
+    let chunk_count = (destinations.len() + DESTINATION_CHUNK_SIZE - 1) / DESTINATION_CHUNK_SIZE;
+    for chunk_index in 0..chunk_count {
+        let addresses = destinations
+            .iter()
+            .skip(chunk_index)
+            .step_by(chunk_count)
+            .cloned()
+            .collect::<Vec<Address>>();
+        let mut chunk = Destinations::new();
+        chunk.set_addresses(RepeatedField::from_vec(addresses));
+        sink = sink.send((make_chunk(chunk), WriteFlags::default())).wait()?;
+    }
+    debug!("uploaded {} destinations in {} chunks", destinations.len(), chunk_count);
+
+    future::poll_fn(|| sink.close()).wait()?;
+    receiver.wait()
+}
+
+/// Returns the scheduled task id if the server accepted the task
+fn handle_ack(ack: grpcio::Result<Ack>) -> Option<u32> {
+    match ack {
         Ok(ack) => {
             info!("successfully connected, id: {}", ack.get_task_id());
-            success = ack.get_success();
-            scheduled_task_id = Some(ack.get_task_id());
-            if !ack.get_success() {
-                error_message = Some(ack.get_error_message().to_string());
+            if ack.get_success() {
+                return Some(ack.get_task_id());
             }
+            error!("failed to schedule task");
+            error!("Message: {}", ack.get_error_message());
         }
         Err(e) => error!("unable to connect: ({})", e),
     }
-    if success {
-        let mut transform_pipeline = TransformPipeline { pipeline: vec![] };
+    None
+}
 
-        if let Some(ip2country_db_path) = args.value_of("ip2country") {
-            transform_pipeline.pipeline.push(IP2CountryTransformer::new(
-                "source_address",
-                "source_address_country",
-                ip2country_db_path,
-            ));
-            info!("added ip2country transformer");
-        }
+/// Subscribes to the results of a task (or campaign) and prints them as CSV or JSON
+/// In catchment mode the replies are collected per block and printed when all results
+/// have been received
+fn stream_results(args: &ArgMatches, grpc_client: &VerfploeterClient, task_id: u32) {
+    let mut transform_pipeline = TransformPipeline { pipeline: vec![] };
+    let mut catchment = if args.is_present("catchment") {
+        Some(Catchment::new(parse_prefix_length(args)))
+    } else {
+        None
+    };
+    // The enrichment is of the replying address, or of the block in catchment mode
+    let source = if catchment.is_some() {
+        "block"
+    } else {
+        "source_address"
+    };
 
-        if let Some(ip2asn_db_path) = args.value_of("ip2asn") {
-            transform_pipeline.pipeline.push(IP2ASNTransformer::new(
-                "source_address",
-                "source_address_asn",
-                ip2asn_db_path,
-            ));
-            info!("added ip2asn transformer");
-        }
+    if let Some(ip2country_db_path) = args.value_of("ip2country") {
+        transform_pipeline.pipeline.push(IP2CountryTransformer::new(
+            source,
+            &format!("{}_country", source),
+            ip2country_db_path,
+        ));
+        info!("added ip2country transformer");
+    }
 
-        // Determine headers and print them if we are outputting CSV
-        let mut headers = TaskResult::get_headers();
-        transform_pipeline
-            .pipeline
-            .iter()
-            .for_each(|t| t.add_header(&mut headers));
-        if !matches.is_present("json") {
-            println!("{}", headers.join(","));
-        }
+    if let Some(ip2asn_db_path) = args.value_of("ip2asn") {
+        transform_pipeline.pipeline.push(IP2ASNTransformer::new(
+            source,
+            &format!("{}_asn", source),
+            ip2asn_db_path,
+        ));
+        info!("added ip2asn transformer");
+    }
 
-        let mut request_task_id = TaskId::new();
-        request_task_id.set_task_id(scheduled_task_id.unwrap());
-        let result = grpc_client.subscribe_result(&request_task_id).unwrap();
-        result
-            .map(move |i| {
-                let data = i.get_data();
-                for mut entry in data {
-                    for transformer in &transform_pipeline.pipeline {
-                        entry = transformer.transform(entry);
-                    }
-                    if matches.is_present("json") {
-                        println!("{}", serde_json::to_string(&entry).unwrap());
-                    } else {
-                        for (idx, header) in headers.iter().enumerate() {
-                            if idx != 0 {
-                                print!(",");
+    // Determine headers and print them if we are outputting CSV
+    let mut headers = if catchment.is_some() {
+        Catchment::get_headers()
+    } else {
+        TaskResult::get_headers()
+    };
+    transform_pipeline
+        .pipeline
+        .iter()
+        .for_each(|t| t.add_header(&mut headers));
+    let json = args.is_present("json");
+    if !json {
+        println!("{}", headers.join(","));
+    }
+
+    // Fetch the results from the server, resuming after the results already received
+    // when the stream breaks. The results end with a TaskResult that has is_finished set,
+    // a stream that ends before it broke as well.
+    let mut received = 0;
+    let mut attempts = 0;
+    loop {
+        let mut request = ResultRequest::new();
+        request.set_task_id(task_id);
+        request.set_offset(received);
+
+        let mut failed = false;
+        let mut finished = false;
+        match grpc_client.get_results_opt(&request, crate::call_option()) {
+            Ok(stream) => {
+                for result in stream.wait() {
+                    match result {
+                        Ok(task_result) => {
+                            received += 1;
+                            attempts = 0;
+                            if task_result.get_is_finished() {
+                                log_summary(task_id, task_result.get_summary());
+                                finished = true;
+                                break;
                             }
-                            if entry.contains_key(header) {
-                                print!("{}", entry[header]);
+                            for entry in task_result.get_data() {
+                                match catchment {
+                                    Some(ref mut catchment) => catchment.add(&entry),
+                                    None => print_row(json, &transform_pipeline, &headers, entry),
+                                }
                             }
                         }
-                        println!();
+                        Err(e) => {
+                            error!("stream failed: {}", e);
+                            failed = true;
+                            break;
+                        }
                     }
                 }
-            })
-            .map_err(|e| error!("stream failed: {}", e))
-            .wait()
-            .for_each(drop);
+            }
+            Err(e) => {
+                error!("unable to fetch results: {}", e);
+                failed = true;
+            }
+        }
+
+        if finished {
+            break;
+        }
+        if !failed {
+            warn!("results of task {} ended before the task finished", task_id);
+        }
+        attempts += 1;
+        if attempts > RESUME_ATTEMPTS {
+            error!("giving up on results of task {} after {} results", task_id, received);
+            break;
+        }
+        warn!("resuming results of task {} after {} results", task_id, received);
+        thread::sleep(Duration::from_secs(5));
+    }
+
+    if let Some(catchment) = catchment {
+        for entry in catchment.get_data() {
+            print_row(json, &transform_pipeline, &headers, entry);
+        }
+    }
+}
+
+/// Logs the totals the server sends when a task (or campaign) has finished
+fn log_summary(task_id: u32, summary: &TaskSummary) {
+    let replies = summary
+        .get_site_replies()
+        .iter()
+        .map(|s| s.get_replies())
+        .sum::<u32>();
+    let sites = summary
+        .get_site_replies()
+        .iter()
+        .map(|s| format!("{}: {}", s.get_site(), s.get_replies()))
+        .collect::<Vec<String>>();
+    info!(
+        "task {} finished: {} probes sent, {} replies ({}), {} invalid replies",
+        task_id,
+        summary.get_probes_sent(),
+        replies,
+        sites.join(", "),
+        summary.get_invalid_replies()
+    );
+}
+
+/// Prints task results as CSV or JSON, in the format of the results command without
+/// enrichment
+pub fn print_task_results(json: bool, task_results: &[TaskResult]) {
+    let transform_pipeline = TransformPipeline { pipeline: vec![] };
+    let headers = TaskResult::get_headers();
+    if !json {
+        println!("{}", headers.join(","));
+    }
+    for task_result in task_results {
+        for entry in task_result.get_data() {
+            print_row(json, &transform_pipeline, &headers, entry);
+        }
+    }
+}
+
+fn print_row(
+    json: bool,
+    transform_pipeline: &TransformPipeline,
+    headers: &[String],
+    mut entry: HashMap<String, RowData>,
+) {
+    for transformer in &transform_pipeline.pipeline {
+        entry = transformer.transform(entry);
+    }
+    if json {
+        println!("{}", serde_json::to_string(&entry).unwrap());
     } else {
-        error!("failed to schedule task");
-        error!("Message: {}", error_message.unwrap());
+        for (idx, header) in headers.iter().enumerate() {
+            if idx != 0 {
+                print!(",");
+            }
+            if entry.contains_key(header) {
+                print!("{}", entry[header]);
+            }
+        }
+        println!();
     }
 }
@@ -0,0 +1,204 @@
+//! Measurement schedules held by the server
+//!
+//! `schedule add` uploads a task like the start command does, with when to run it: a cron
+//! expression or an interval from the start time, or only the start time for a single run.
+//! The server starts every run as a task of its own, with its own results, which are
+//! fetched with the results command.
+
+use super::{handle_ack, load_task, upload};
+use crate::schema::verfploeter::{Empty, Schedule, ScheduleId, ScheduleTask};
+use crate::schema::verfploeter_grpc::VerfploeterClient;
+use crate::server::schedule::{format_time, parse_time};
+use clap::ArgMatches;
+use prettytable::{color, format, Attr, Cell, Row, Table};
+
+/// Units of intervals, largest first
+const UNITS: [(&str, u64); 4] = [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)];
+
+pub fn execute(args: &ArgMatches, grpc_client: &VerfploeterClient) {
+    if let Some(matches) = args.subcommand_matches("add") {
+        add_schedule(matches, grpc_client)
+    } else if args.subcommand_matches("list").is_some() {
+        print_schedule_list(grpc_client)
+    } else if let Some(matches) = args.subcommand_matches("remove") {
+        let schedule_id = matches
+            .value_of("SCHEDULE_ID")
+            .unwrap()
+            .parse::<u32>()
+            .expect("SCHEDULE_ID should be a 32-bits integer");
+        remove_schedule(grpc_client, schedule_id)
+    } else {
+        unimplemented!();
+    }
+}
+
+/// Parses an interval in seconds, or with a unit of d, h, m or s (e.g. `1d`)
+fn parse_interval(value: &str) -> Result<u64, String> {
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => value.split_at(idx),
+        None => (value, "s"),
+    };
+    let seconds = UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|(_, seconds)| *seconds);
+    match (number.parse::<u64>(), seconds) {
+        (Ok(number), Some(seconds)) if number > 0 => Ok(number * seconds),
+        _ => Err(format!(
+            "invalid interval {}, expected e.g. 90s, 30m, 6h or 1d",
+            value
+        )),
+    }
+}
+
+/// Formats an interval in the largest unit that divides it
+fn format_interval(interval: u64) -> String {
+    let (name, seconds) = UNITS
+        .iter()
+        .find(|(_, seconds)| interval % seconds == 0)
+        .unwrap();
+    format!("{}{}", interval / seconds, name)
+}
+
+fn add_schedule(matches: &ArgMatches, grpc_client: &VerfploeterClient) {
+    debug!("add_schedule()");
+    let mut schedule = Schedule::new();
+    if let Some(cron) = matches.value_of("cron") {
+        schedule.set_cron(cron.to_string());
+    }
+    let interval = matches.value_of("every").map(parse_interval).transpose();
+    let start_at = matches.value_of("at").map(parse_time).transpose();
+    match (interval, start_at) {
+        (Ok(interval), Ok(start_at)) => {
+            schedule.set_interval(interval.unwrap_or(0));
+            schedule.set_start_at(start_at.unwrap_or(0));
+        }
+        (Err(e), _) | (_, Err(e)) => {
+            error!("{}", e);
+            return;
+        }
+    }
+
+    let (task, destinations) = match load_task(matches) {
+        Some(task) => task,
+        None => return,
+    };
+    schedule.set_task(task);
+
+    let ack = upload(
+        grpc_client.add_schedule_opt(crate::call_option()),
+        schedule,
+        destinations,
+        |chunk| {
+            let mut task = ScheduleTask::new();
+            task.set_destinations(chunk);
+            let mut schedule = Schedule::new();
+            schedule.set_task(task);
+            schedule
+        },
+    );
+    if let Some(schedule_id) = handle_ack(ack) {
+        println!("added schedule {}", schedule_id);
+    }
+}
+
+fn print_schedule_list(grpc_client: &VerfploeterClient) {
+    debug!("print_schedule_list()");
+    let schedule_list = match grpc_client.list_schedules_opt(&Empty::new(), crate::call_option()) {
+        Ok(schedule_list) => schedule_list,
+        Err(e) => {
+            println!("unable to obtain schedule list: {}", e);
+            return;
+        }
+    };
+
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.add_row(Row::new(
+        vec![
+            "Schedule",
+            "Client",
+            "When (UTC)",
+            "Start",
+            "Next run",
+            "Destinations",
+            "Owner",
+            "Runs",
+            "Last run",
+        ]
+        .into_iter()
+        .map(|title| {
+            Cell::new(title)
+                .with_style(Attr::Bold)
+                .with_style(Attr::ForegroundColor(color::GREEN))
+        })
+        .collect(),
+    ));
+    for schedule in schedule_list.get_schedules() {
+        let when = if !schedule.get_cron().is_empty() {
+            format!("cron {}", schedule.get_cron())
+        } else if schedule.get_interval() > 0 {
+            format!("every {}", format_interval(schedule.get_interval()))
+        } else {
+            "once".to_string()
+        };
+        let next_run = match schedule.get_next_run() {
+            0 => "-".to_string(),
+            time => format_time(time),
+        };
+        let last_run = match schedule.get_runs().last() {
+            Some(run) if run.get_task_id() != 0 => format!("task {}", run.get_task_id()),
+            Some(run) => run.get_error_message().to_string(),
+            None => "-".to_string(),
+        };
+        table.add_row(row!(
+            schedule.get_schedule_id(),
+            schedule.get_task().get_client().get_metadata().hostname,
+            when,
+            format_time(schedule.get_start_at()),
+            next_run,
+            schedule.get_destinations(),
+            schedule.get_owner(),
+            schedule.get_runs().len(),
+            last_run
+        ));
+    }
+    table.printstd();
+    println!("Schedules: {}", schedule_list.get_schedules().len());
+}
+
+fn remove_schedule(grpc_client: &VerfploeterClient, schedule_id: u32) {
+    debug!("remove_schedule()");
+    let mut request = ScheduleId::new();
+    request.set_schedule_id(schedule_id);
+    match grpc_client.remove_schedule_opt(&request, crate::call_option()) {
+        Ok(ref ack) if ack.get_success() => println!("removed schedule {}", schedule_id),
+        Ok(ack) => println!(
+            "unable to remove schedule {}: {}",
+            schedule_id,
+            ack.get_error_message()
+        ),
+        Err(e) => println!("unable to remove schedule {}: {}", schedule_id, e),
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod intervals {
+    use super::*;
+
+    #[test]
+    fn intervals_have_units() {
+        assert_eq!(parse_interval("90"), Ok(90));
+        assert_eq!(parse_interval("90s"), Ok(90));
+        assert_eq!(parse_interval("30m"), Ok(1800));
+        assert_eq!(parse_interval("1d"), Ok(86400));
+        assert!(parse_interval("0h").is_err());
+        assert!(parse_interval("1w").is_err());
+        assert!(parse_interval("h").is_err());
+
+        assert_eq!(format_interval(86400), "1d");
+        assert_eq!(format_interval(5400), "90m");
+        assert_eq!(format_interval(61), "61s");
+    }
+}
@@ -0,0 +1,135 @@
+//! Estimation of the offset of the clock of this client to the clock of the server
+//!
+//! Probes are timestamped by the client that sends them, and replies by the client that
+//! receives them, so the time between the two mixes in the difference between their clocks.
+//! Like NTP, the client exchanges timestamps with the server a few times and keeps the offset
+//! measured by the exchange with the lowest delay, which is off by at most half that delay.
+//! The offset is reported to the server, which adds the offsets of the transmitting and the
+//! receiving client to every TaskResult, so the CLI can correct for the difference.
+
+use super::handlers::current_timestamp;
+use crate::schema::verfploeter::{ClockOffset, ClockSync};
+use crate::schema::verfploeter_grpc::VerfploeterClient;
+
+use lazy_static::lazy_static;
+use prometheus::{__register_gauge, opts, register_int_gauge, IntGauge};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+// Define Prometheus metrics
+lazy_static! {
+    static ref CLOCK_OFFSET: IntGauge = register_int_gauge!(
+        "client_clock_offset_nanoseconds",
+        "Estimated offset of the clock of this client to the clock of the server"
+    )
+    .unwrap();
+    static ref CLOCK_DELAY: IntGauge = register_int_gauge!(
+        "client_clock_delay_nanoseconds",
+        "Round-trip delay of the exchange the clock offset was estimated with"
+    )
+    .unwrap();
+}
+
+/// Number of timestamp exchanges per estimate
+const SAMPLES: usize = 8;
+
+/// Time between estimates, as clocks drift apart
+const SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Offset of the client clock to the server clock, and the round-trip delay of the exchange,
+/// in nanoseconds. The client sent at t1 and received at t4, the server received at t2 and
+/// sent at t3.
+fn sample(t1: u64, t2: u64, t3: u64, t4: u64) -> (i64, u64) {
+    let (t1, t2, t3, t4) = (t1 as i64, t2 as i64, t3 as i64, t4 as i64);
+    let offset = ((t1 - t2) + (t4 - t3)) / 2;
+    let delay = (t4 - t1) - (t3 - t2);
+    (offset, delay.max(0) as u64)
+}
+
+/// The sample with the lowest delay, which is the most accurate one
+fn best_sample(samples: &[(i64, u64)]) -> Option<(i64, u64)> {
+    samples.iter().min_by_key(|(_, delay)| *delay).cloned()
+}
+
+/// Exchanges timestamps with the server, returns the estimated offset and its delay, or
+/// None if the server could not be reached
+fn estimate(grpc_client: &VerfploeterClient) -> Option<(i64, u64)> {
+    let mut samples = Vec::with_capacity(SAMPLES);
+    for _ in 0..SAMPLES {
+        let mut request = ClockSync::new();
+        request.set_client_transmit_time(current_timestamp());
+        match grpc_client.sync_clock_opt(&request, crate::call_option()) {
+            Ok(reply) => samples.push(sample(
+                request.get_client_transmit_time(),
+                reply.get_server_receive_time(),
+                reply.get_server_transmit_time(),
+                current_timestamp(),
+            )),
+            Err(e) => {
+                debug!("unable to exchange timestamps with server: {}", e);
+                break;
+            }
+        }
+    }
+    best_sample(&samples)
+}
+
+/// Estimates the clock offset and reports it to the server now and then, for as long as
+/// the client runs
+pub fn start_sync(grpc_client: Arc<VerfploeterClient>, client_id: String) {
+    thread::Builder::new()
+        .name("clock_sync".to_string())
+        .spawn(move || loop {
+            if let Some((offset, delay)) = estimate(&grpc_client) {
+                debug!(
+                    "clock offset to server is {} us (delay {} us)",
+                    offset / 1000,
+                    delay / 1000
+                );
+                CLOCK_OFFSET.set(offset);
+                CLOCK_DELAY.set(delay as i64);
+
+                let mut report = ClockOffset::new();
+                report.set_client_id(client_id.clone());
+                report.set_offset(offset);
+                report.set_delay(delay);
+                let result = grpc_client.report_clock_offset_opt(&report, crate::call_option());
+                if let Err(e) = result {
+                    debug!("unable to report clock offset to server: {}", e);
+                }
+            }
+            thread::sleep(SYNC_INTERVAL);
+        })
+        .expect("Unable to start clock synchronization");
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod clock_offset {
+    use super::*;
+
+    #[test]
+    fn offset_of_a_client_clock_that_is_ahead() {
+        // The client clock is 1000 ahead, 100 to the server, 10 at the server and 300 back,
+        // the asymmetry of the paths is an error of half their difference
+        let (offset, delay) = sample(5000, 4100, 4110, 5410);
+        assert_eq!(offset, 1000 + 100);
+        assert_eq!(delay, 400);
+
+        // Symmetric paths give the exact offset
+        assert_eq!(sample(5000, 4200, 4210, 5410), (1000, 400));
+    }
+
+    #[test]
+    fn offset_of_a_client_clock_that_is_behind() {
+        assert_eq!(sample(1000, 3200, 3210, 1410), (-2000, 400));
+    }
+
+    #[test]
+    fn lowest_delay_wins() {
+        assert_eq!(best_sample(&[]), None);
+        let samples = [(900, 400), (1010, 20), (-50, 2000)];
+        assert_eq!(best_sample(&samples), Some((1010, 20)));
+    }
+}
@@ -0,0 +1,266 @@
+use super::packet_io::{Family, IpProtocol, PacketIo, PacketSocket};
+use super::ping_inbound::ipv4_packet;
+use super::spool::Spool;
+use super::{
+    current_timestamp, invalid_payload_task_id, transmit_queue, udp_dns, verify_payload,
+    ChannelType, KeyMap, ResultQueue, TaskHandler,
+};
+use crate::net::{IPPacket, IPv6Packet, PacketPayload, UDPPacket};
+use crate::schema::verfploeter::{DnsResult, Metadata, Result};
+use crate::schema::verfploeter_grpc::VerfploeterClient;
+use crate::RESULT_FLUSH_INTERVAL;
+
+use futures::sync::oneshot;
+use lazy_static::lazy_static;
+use prometheus::{opts, register_counter, register_int_counter, IntCounter};
+use std::io;
+use std::net::{IpAddr, Ipv6Addr};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+// Define Prometheus metrics
+lazy_static! {
+    static ref PACKETS_RECEIVED: IntCounter = register_int_counter!(
+        "client_dns_inbound_packets_received",
+        "Number of packets received"
+    )
+    .unwrap();
+    static ref PACKETS_PROCESSED_VALID: IntCounter = register_int_counter!(
+        "client_dns_inbound_packets_processed_valid",
+        "Number of valid DNS responses processed"
+    )
+    .unwrap();
+    static ref PACKETS_PROCESSED_INVALID: IntCounter = register_int_counter!(
+        "client_dns_inbound_packets_processed_invalid",
+        "Number of invalid DNS responses processed"
+    )
+    .unwrap();
+    static ref PACKETS_TRANSMITTED: IntCounter = register_int_counter!(
+        "client_dns_inbound_packets_transmitted",
+        "Number of packets transmitted"
+    )
+    .unwrap();
+}
+
+/// Captures the responses to DNS probes, which are sent to the anycast address and
+/// can therefore arrive at any client
+pub struct DnsInbound {
+    handles: Vec<JoinHandle<()>>,
+    socket: Arc<dyn PacketSocket>,
+    socket_v6: Arc<dyn PacketSocket>,
+    grpc_client: Arc<VerfploeterClient>,
+    metadata: Metadata,
+    keys: KeyMap,
+    result_queue: Arc<Mutex<ResultQueue>>,
+    spool: Arc<Mutex<Spool>>,
+    poison_rx: oneshot::Receiver<()>,
+    poison_tx: Option<oneshot::Sender<()>>,
+}
+
+impl TaskHandler for DnsInbound {
+    fn start(&mut self) {
+        debug!("starting TaskHandler::DnsInbound::start()");
+
+        // The packet receiver threads take the packets from the sockets, and put the
+        // responses that carry a valid payload in the buffer for transmission to the server
+        let packet_receiver_handle = thread::spawn({
+            let socket = self.socket.clone();
+            let keys = self.keys.clone();
+            let result_queue = self.result_queue.clone();
+            move || {
+                let mut buffer: Vec<u8> = vec![0; 1500];
+                while let Ok((result, _)) = socket.recv_from(&mut buffer) {
+                    PACKETS_RECEIVED.inc();
+                    if result == 0 {
+                        break;
+                    }
+
+                    if let Some(packet) = ipv4_packet(&buffer[..result]) {
+                        process_packet(&keys, &result_queue, packet, current_timestamp());
+                    }
+                }
+            }
+        });
+
+        // Raw IPv6 sockets do not pass the IP header, so the source address is taken from
+        // the socket and the destination address from the payload
+        let packet_receiver_v6_handle = thread::spawn({
+            let socket = self.socket_v6.clone();
+            let keys = self.keys.clone();
+            let result_queue = self.result_queue.clone();
+            move || {
+                let mut buffer: Vec<u8> = vec![0; 1500];
+                while let Ok((result, address)) = socket.recv_from(&mut buffer) {
+                    PACKETS_RECEIVED.inc();
+                    if result == 0 {
+                        break;
+                    }
+                    if result < 8 {
+                        continue;
+                    }
+
+                    let source_address = match address {
+                        IpAddr::V6(address) => address,
+                        IpAddr::V4(_) => continue,
+                    };
+                    let packet = IPPacket::V6(IPv6Packet {
+                        hop_limit: None,
+                        source_address,
+                        destination_address: Ipv6Addr::UNSPECIFIED,
+                        payload: PacketPayload::UDP {
+                            value: UDPPacket::from(&buffer[..result]),
+                        },
+                    });
+                    process_packet(&keys, &result_queue, packet, current_timestamp());
+                }
+            }
+        });
+
+        // The packet transmitter thread periodically swaps out the buffer the packet receivers
+        // write their results to and starts transmitting the data
+        let packet_transmitter_handle = thread::spawn({
+            let grpc_client = self.grpc_client.clone();
+            let result_queue = self.result_queue.clone();
+            let spool = self.spool.clone();
+            let poison_tx = self.poison_tx.take().unwrap();
+            let metadata = self.metadata.clone();
+            move || loop {
+                thread::sleep(Duration::from_secs(RESULT_FLUSH_INTERVAL));
+
+                // Check if this thread is still supposed to be running
+                if poison_tx.is_canceled() {
+                    break;
+                }
+
+                let transmitted = transmit_queue(&grpc_client, &metadata, &result_queue, &spool);
+                PACKETS_TRANSMITTED.inc_by(transmitted as i64);
+            }
+        });
+        self.handles.push(packet_receiver_handle);
+        self.handles.push(packet_receiver_v6_handle);
+        self.handles.push(packet_transmitter_handle);
+    }
+
+    fn exit(&mut self) {
+        debug!("Existing DnsInbound::exit()");
+        self.socket.shutdown();
+        self.socket_v6.shutdown();
+        self.poison_rx.close();
+        for handle in self.handles.drain(..) {
+            handle.join().unwrap();
+        }
+    }
+
+    fn get_channel(&mut self) -> ChannelType {
+        ChannelType::None
+    }
+}
+
+impl DnsInbound {
+    pub fn new(
+        metadata: Metadata,
+        grpc_client: Arc<VerfploeterClient>,
+        keys: KeyMap,
+        spool: Arc<Mutex<Spool>>,
+        packet_io: &dyn PacketIo,
+    ) -> io::Result<DnsInbound> {
+        debug!("DnsInbound::new()");
+        let socket = packet_io.open(Family::V4, IpProtocol::Udp, None)?;
+        let socket_v6 = packet_io.open(Family::V6, IpProtocol::Udp, None)?;
+        let (poison_tx, poison_rx): (oneshot::Sender<()>, oneshot::Receiver<()>) =
+            oneshot::channel();
+
+        Ok(DnsInbound {
+            handles: Vec::new(),
+            socket,
+            socket_v6,
+            grpc_client,
+            metadata,
+            keys,
+            result_queue: Arc::new(Mutex::new(ResultQueue::default())),
+            spool,
+            poison_tx: Some(poison_tx),
+            poison_rx,
+        })
+    }
+}
+
+/// Puts the result of a DNS response in the transmission queue, if it is a response to
+/// one of our probes
+fn process_packet(
+    keys: &KeyMap,
+    result_queue: &Mutex<ResultQueue>,
+    packet: IPPacket,
+    receive_time: u64,
+) {
+    let datagram = match packet.payload() {
+        PacketPayload::UDP { value } => value,
+        _ => return,
+    };
+    // The raw sockets see all UDP traffic of the host
+    if datagram.source_port != udp_dns::DNS_PORT
+        || datagram.destination_port != udp_dns::SOURCE_PORT
+    {
+        return;
+    }
+
+    let result = match dns_result(keys, &packet, &datagram.body, receive_time) {
+        Some(result) => result,
+        None => {
+            PACKETS_PROCESSED_INVALID.inc();
+            if let Some(task_id) = invalid_task_id(keys, &datagram.body) {
+                result_queue.lock().unwrap().add_invalid_reply(task_id);
+            }
+            return;
+        }
+    };
+    PACKETS_PROCESSED_VALID.inc();
+
+    result_queue.lock().unwrap().push(result);
+}
+
+/// Task id of a DNS response to one of our queries whose payload fails verification
+fn invalid_task_id(keys: &KeyMap, message: &[u8]) -> Option<u32> {
+    let (_, name, _) = udp_dns::parse_response(message)?;
+    udp_dns::decode_query_name(&name)
+        .iter()
+        .filter_map(|candidate| invalid_payload_task_id(keys, candidate))
+        .next()
+}
+
+/// Creates the result of a DNS response, if its question carries a valid payload
+fn dns_result(
+    keys: &KeyMap,
+    packet: &IPPacket,
+    message: &[u8],
+    receive_time: u64,
+) -> Option<Result> {
+    let (_, name, rcode) = udp_dns::parse_response(message)?;
+    let payload = udp_dns::decode_query_name(&name)
+        .iter()
+        .filter_map(|candidate| verify_payload(keys, candidate))
+        .next()?;
+
+    // The IPv6 socket does not tell us where the response was sent to, which is
+    // the source address of the probe
+    let destination_address = match packet {
+        IPPacket::V6(_) => payload.get_source_address().clone(),
+        IPPacket::V4(_) => packet.destination_address().into(),
+    };
+
+    let mut dr = DnsResult::new();
+    dr.set_source_address(packet.source_address().into());
+    dr.set_destination_address(destination_address);
+    dr.set_receive_time(receive_time);
+    if let Some(ttl) = packet.ttl() {
+        dr.set_ttl(ttl.into());
+    }
+    dr.set_payload(payload);
+    dr.set_rcode(rcode.into());
+
+    let mut result = Result::new();
+    result.set_dns(dr);
+    Some(result)
+}
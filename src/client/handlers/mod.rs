@@ -1,8 +1,25 @@
+pub mod dns_inbound;
+pub mod packet_io;
+pub mod pcap;
+pub mod permutation;
 pub mod ping_inbound;
 pub mod ping_outbound;
+pub mod spool;
+pub mod tcp_syn;
+pub mod udp_dns;
+use crate::schema::verfploeter::{Client, Metadata, PingPayload, Result, TaskResult};
+use crate::schema::verfploeter_grpc::VerfploeterClient;
+use crate::schema::Signable;
+use self::spool::Spool;
 use super::{Receiver, Sender, Task};
+use std::collections::HashMap;
+use std::mem;
+use std::sync::{Arc, Mutex, RwLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Secrets to verify probe payloads with, by key id, as distributed by the server
+pub type KeyMap = Arc<RwLock<HashMap<u32, String>>>;
+
 pub enum ChannelType {
     Task {
         sender: Option<Sender<Task>>,
@@ -23,3 +40,228 @@ pub fn current_timestamp() -> u64 {
         .unwrap()
         .as_nanos() as u64
 }
+
+/// Verifies a signed payload with the secret of the key it names
+fn verify_payload(keys: &KeyMap, buffer: &[u8]) -> Option<PingPayload> {
+    let key_id = PingPayload::key_id_from_signed_bytes(buffer).ok()?;
+    let keys = keys.read().unwrap();
+    let secret = keys.get(&key_id)?;
+    PingPayload::from_signed_bytes(secret, buffer).ok()
+}
+
+/// Task id of a reply that names one of our keys, but whose payload fails verification,
+/// e.g. because it was corrupted on the way, or None if the reply is not ours at all
+fn invalid_payload_task_id(keys: &KeyMap, buffer: &[u8]) -> Option<u32> {
+    let key_id = PingPayload::key_id_from_signed_bytes(buffer).ok()?;
+    if !keys.read().unwrap().contains_key(&key_id) || verify_payload(keys, buffer).is_some() {
+        return None;
+    }
+    PingPayload::task_id_from_signed_bytes(buffer).ok()
+}
+
+/// Results waiting to be transmitted to the server, with the number of replies per task
+/// that carried an invalid payload
+#[derive(Default)]
+pub struct ResultQueue {
+    results: Vec<Result>,
+    invalid_replies: HashMap<u32, u32>,
+}
+
+impl ResultQueue {
+    pub fn push(&mut self, result: Result) {
+        self.results.push(result);
+    }
+
+    pub fn add_invalid_reply(&mut self, task_id: u32) {
+        *self.invalid_replies.entry(task_id).or_insert(0) += 1;
+    }
+}
+
+/// Task id of a result, results are transmitted to the server grouped by task id
+fn result_task_id(result: &Result) -> u32 {
+    if result.has_tcp() {
+        result.get_tcp().get_task_id()
+    } else if result.has_dns() {
+        result.get_dns().get_payload().get_task_id()
+    } else {
+        result.get_ping().get_payload().get_task_id()
+    }
+}
+
+/// Sends the results in the queue to the server, returns the number of results that were
+/// transmitted. Results are only sent once all spooled results have been replayed, batches
+/// that cannot be sent (e.g. while the client is reconnecting) are spooled.
+fn transmit_queue(
+    grpc_client: &VerfploeterClient,
+    metadata: &Metadata,
+    result_queue: &Mutex<ResultQueue>,
+    spool: &Mutex<Spool>,
+) -> usize {
+    // Get the current result queue, and replace it with an empty one
+    let queue = mem::replace(&mut *result_queue.lock().unwrap(), ResultQueue::default());
+
+    let mut spool = spool.lock().unwrap();
+    let mut transmitted = replay_spool(grpc_client, &mut spool);
+    for tr in task_results(metadata, queue) {
+        if spool.is_empty() {
+            match grpc_client.send_result_opt(&tr, crate::call_option()) {
+                Ok(ack) => {
+                    if ack.get_success() {
+                        transmitted += tr.get_result_list().len();
+                    } else {
+                        refused(&tr, ack.get_error_message());
+                    }
+                    continue;
+                }
+                Err(e) => error!("failed to send result to server: {}", e),
+            }
+        }
+        if let Err(e) = spool.push(&tr) {
+            error!(
+                "unable to spool {} results, dropping them: {}",
+                tr.get_result_list().len(),
+                e
+            );
+        }
+    }
+    transmitted
+}
+
+/// Logs results the server refused, e.g. because their task has finished, which are dropped
+fn refused(tr: &TaskResult, reason: &str) {
+    warn!(
+        "server refused {} results of task {}: {}",
+        tr.get_result_list().len(),
+        tr.get_task_id(),
+        reason
+    );
+}
+
+/// Sends the spooled results to the server, oldest first, until one fails, returns the
+/// number of results that were transmitted
+fn replay_spool(grpc_client: &VerfploeterClient, spool: &mut Spool) -> usize {
+    let mut transmitted = 0;
+    loop {
+        let tr = match spool.front() {
+            Ok(Some(tr)) => tr,
+            Ok(None) => break,
+            Err(e) => {
+                error!("unable to read spool: {}", e);
+                break;
+            }
+        };
+        match grpc_client.send_result_opt(&tr, crate::call_option()) {
+            Ok(ref ack) if ack.get_success() => transmitted += tr.get_result_list().len(),
+            Ok(ack) => refused(&tr, ack.get_error_message()),
+            Err(e) => {
+                debug!("failed to replay spooled results: {}", e);
+                break;
+            }
+        }
+        if let Err(e) = spool.pop() {
+            error!("unable to remove replayed results from spool: {}", e);
+            break;
+        }
+    }
+    if transmitted > 0 {
+        info!("replayed {} spooled results", transmitted);
+    }
+    transmitted
+}
+
+/// Groups the queued results into one TaskResult per task, which also carries the number
+/// of invalid replies to the task
+pub fn task_results(metadata: &Metadata, queue: ResultQueue) -> Vec<TaskResult> {
+    let ResultQueue {
+        mut results,
+        mut invalid_replies,
+    } = queue;
+    // Sort the results by task id
+    results.sort_by_key(result_task_id);
+
+    let new_task_result = |task_id: u32| {
+        let mut tr = TaskResult::new();
+        tr.set_task_id(task_id);
+        let mut client = Client::new();
+        client.set_metadata(metadata.clone());
+        tr.set_client(client);
+        tr
+    };
+    let mut task_results: Vec<TaskResult> = Vec::new();
+    for result in results {
+        let task_id = result_task_id(&result);
+        if task_results.last().map(|tr| tr.get_task_id()) != Some(task_id) {
+            let mut tr = new_task_result(task_id);
+            tr.set_invalid_replies(invalid_replies.remove(&task_id).unwrap_or(0));
+            task_results.push(tr);
+        }
+        task_results.last_mut().unwrap().mut_result_list().push(result);
+    }
+
+    // Tasks with only invalid replies
+    let mut invalid_replies = invalid_replies.into_iter().collect::<Vec<(u32, u32)>>();
+    invalid_replies.sort();
+    for (task_id, invalid) in invalid_replies {
+        let mut tr = new_task_result(task_id);
+        tr.set_invalid_replies(invalid);
+        task_results.push(tr);
+    }
+    task_results
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod result_queue {
+    use super::*;
+    use crate::schema::verfploeter::PingResult;
+
+    fn ping_result(task_id: u32) -> Result {
+        let mut ping = PingResult::new();
+        ping.mut_payload().set_task_id(task_id);
+        let mut result = Result::new();
+        result.set_ping(ping);
+        result
+    }
+
+    #[test]
+    fn invalid_replies_are_sent_with_their_task() {
+        let mut queue = ResultQueue::default();
+        queue.push(ping_result(2));
+        queue.push(ping_result(1));
+        queue.push(ping_result(2));
+        queue.add_invalid_reply(2);
+        queue.add_invalid_reply(3);
+        queue.add_invalid_reply(3);
+
+        let task_results = task_results(&Metadata::new(), queue)
+            .iter()
+            .map(|tr| {
+                (
+                    tr.get_task_id(),
+                    tr.get_result_list().len(),
+                    tr.get_invalid_replies(),
+                )
+            })
+            .collect::<Vec<(u32, usize, u32)>>();
+        assert_eq!(task_results, vec![(1, 1, 0), (2, 2, 1), (3, 0, 2)]);
+    }
+
+    #[test]
+    fn invalid_payloads_name_their_task() {
+        let keys: KeyMap = Arc::new(RwLock::new(HashMap::new()));
+        keys.write().unwrap().insert(7, "abc123".to_string());
+        let mut payload = PingPayload::new();
+        payload.set_task_id(12);
+        payload.set_key_id(7);
+
+        let valid = payload.to_signed_bytes("abc123").unwrap();
+        assert_eq!(invalid_payload_task_id(&keys, &valid), None);
+        let forged = payload.to_signed_bytes("abc124").unwrap();
+        assert_eq!(invalid_payload_task_id(&keys, &forged), Some(12));
+
+        // Payloads signed with keys of other servers are not ours
+        payload.set_key_id(8);
+        let other = payload.to_signed_bytes("abc124").unwrap();
+        assert_eq!(invalid_payload_task_id(&keys, &other), None);
+    }
+}
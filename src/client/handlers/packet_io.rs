@@ -0,0 +1,279 @@
+//! Sending and receiving of packets by the handlers
+//!
+//! PingOutbound, PingInbound and DnsInbound open their sockets through a PacketIo, which is
+//! backed by raw sockets when the client runs, and by an in-memory network in tests, so the
+//! handlers can be tested without CAP_NET_RAW. The sockets behave like raw sockets either way:
+//! packets are sent without an IP header, IPv4 packets are received with their IP header and
+//! IPv6 packets without it.
+
+use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+use std::io;
+use std::net::{IpAddr, Shutdown, SocketAddr};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Family {
+    V4,
+    V6,
+}
+
+impl From<IpAddr> for Family {
+    fn from(address: IpAddr) -> Family {
+        match address {
+            IpAddr::V4(_) => Family::V4,
+            IpAddr::V6(_) => Family::V6,
+        }
+    }
+}
+
+/// Protocol of a socket, ICMP is ICMPv6 on IPv6 sockets
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpProtocol {
+    Icmp,
+    Tcp,
+    Udp,
+}
+
+pub trait PacketSocket: Send + Sync {
+    /// Sends a packet (without IP header) to destination
+    fn send_to(&self, packet: &[u8], destination: IpAddr) -> io::Result<usize>;
+
+    /// Receives a packet into buffer, returns its length and source address, a length of 0
+    /// once the socket has been shut down
+    fn recv_from(&self, buffer: &mut [u8]) -> io::Result<(usize, IpAddr)>;
+
+    /// Stops the socket, which ends a recv_from that is waiting for a packet
+    fn shutdown(&self);
+}
+
+pub trait PacketIo: Send + Sync {
+    /// Opens a socket for the packets of a protocol, bound to a source address if given
+    fn open(
+        &self,
+        family: Family,
+        protocol: IpProtocol,
+        bind: Option<IpAddr>,
+    ) -> io::Result<Arc<dyn PacketSocket>>;
+}
+
+/// Packet I/O on raw sockets, which requires root or CAP_NET_RAW
+pub struct RawPacketIo;
+
+impl PacketIo for RawPacketIo {
+    fn open(
+        &self,
+        family: Family,
+        protocol: IpProtocol,
+        bind: Option<IpAddr>,
+    ) -> io::Result<Arc<dyn PacketSocket>> {
+        let domain = match family {
+            Family::V4 => Domain::ipv4(),
+            Family::V6 => Domain::ipv6(),
+        };
+        let protocol = match (family, protocol) {
+            (Family::V4, IpProtocol::Icmp) => Protocol::icmpv4(),
+            (Family::V6, IpProtocol::Icmp) => Protocol::icmpv6(),
+            (_, IpProtocol::Tcp) => Protocol::tcp(),
+            (_, IpProtocol::Udp) => Protocol::udp(),
+        };
+        let socket = Socket::new(domain, Type::raw(), Some(protocol))?;
+        if let Some(address) = bind {
+            socket.bind(&SocketAddr::new(address, 0).into())?;
+        }
+        debug!("socket [{:?}]", socket);
+        Ok(Arc::new(socket))
+    }
+}
+
+impl PacketSocket for Socket {
+    fn send_to(&self, packet: &[u8], destination: IpAddr) -> io::Result<usize> {
+        Socket::send_to(self, packet, &SocketAddr::new(destination, 0).into())
+    }
+
+    fn recv_from(&self, buffer: &mut [u8]) -> io::Result<(usize, IpAddr)> {
+        let (length, address) = Socket::recv_from(self, buffer)?;
+        Ok((length, ip_address(&address)?))
+    }
+
+    fn shutdown(&self) {
+        // Raw sockets are not connected, so this fails, but it does end recv_from
+        Socket::shutdown(self, Shutdown::Both).ok();
+    }
+}
+
+fn ip_address(address: &SockAddr) -> io::Result<IpAddr> {
+    if let Some(address) = address.as_inet() {
+        Ok(IpAddr::V4(*address.ip()))
+    } else if let Some(address) = address.as_inet6() {
+        Ok(IpAddr::V6(*address.ip()))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "packet from a non-IP address",
+        ))
+    }
+}
+
+/// Packet I/O in memory, for tests
+#[cfg(test)]
+pub mod memory {
+    use super::*;
+    use crate::net::{TCP_ACK, TCP_SYN};
+    use byteorder::{ByteOrder, NetworkEndian};
+    use std::collections::VecDeque;
+    use std::net::Ipv4Addr;
+    use std::sync::{Condvar, Mutex, Weak};
+    use std::time::{Duration, Instant};
+
+    /// Time recv_from waits for a packet, so a test that misses a reply fails instead of
+    /// waiting forever
+    const RECEIVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// A network in which every destination answers every probe: echo requests with an echo
+    /// reply, and TCP SYN segments with a SYN-ACK. The replies are received by all other open
+    /// sockets of the same family and protocol. Checksums are not updated, as the inbound
+    /// handler does not check them.
+    #[derive(Clone, Default)]
+    pub struct MemoryPacketIo {
+        sockets: Arc<Mutex<Vec<Weak<MemorySocket>>>>,
+    }
+
+    impl PacketIo for MemoryPacketIo {
+        fn open(
+            &self,
+            family: Family,
+            protocol: IpProtocol,
+            bind: Option<IpAddr>,
+        ) -> io::Result<Arc<dyn PacketSocket>> {
+            let socket = Arc::new(MemorySocket {
+                network: self.clone(),
+                family,
+                protocol,
+                bind,
+                received: Mutex::new(Received::default()),
+                ready: Condvar::new(),
+            });
+            self.sockets.lock().unwrap().push(Arc::downgrade(&socket));
+            Ok(socket)
+        }
+    }
+
+    impl MemoryPacketIo {
+        /// Delivers the reply of source to the other open sockets of its family and protocol
+        fn deliver(&self, source: &MemorySocket, reply: &[u8], from: IpAddr) {
+            let packet = match from {
+                IpAddr::V4(from) => {
+                    let to = match source.bind {
+                        Some(IpAddr::V4(to)) => to,
+                        _ => Ipv4Addr::UNSPECIFIED,
+                    };
+                    let protocol = match source.protocol {
+                        IpProtocol::Icmp => 1,
+                        IpProtocol::Tcp => 6,
+                        IpProtocol::Udp => 17,
+                    };
+                    let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, protocol, 0, 0];
+                    NetworkEndian::write_u16(&mut packet[2..4], 20 + reply.len() as u16);
+                    packet.extend_from_slice(&from.octets());
+                    packet.extend_from_slice(&to.octets());
+                    packet.extend_from_slice(reply);
+                    packet
+                }
+                IpAddr::V6(_) => reply.to_vec(),
+            };
+
+            let mut sockets = self.sockets.lock().unwrap();
+            sockets.retain(|socket| socket.upgrade().is_some());
+            for socket in sockets.iter().filter_map(Weak::upgrade) {
+                if std::ptr::eq(&*socket, source)
+                    || socket.family != source.family
+                    || socket.protocol != source.protocol
+                {
+                    continue;
+                }
+                let mut received = socket.received.lock().unwrap();
+                received.packets.push_back((packet.clone(), from));
+                socket.ready.notify_one();
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Received {
+        packets: VecDeque<(Vec<u8>, IpAddr)>,
+        shutdown: bool,
+    }
+
+    pub struct MemorySocket {
+        network: MemoryPacketIo,
+        family: Family,
+        protocol: IpProtocol,
+        bind: Option<IpAddr>,
+        received: Mutex<Received>,
+        ready: Condvar,
+    }
+
+    impl MemorySocket {
+        /// The reply of a destination to a probe, if it answers it
+        fn reply(&self, packet: &[u8]) -> Option<Vec<u8>> {
+            let mut reply = packet.to_vec();
+            match (self.protocol, self.family) {
+                (IpProtocol::Icmp, Family::V4) if packet.first() == Some(&8) => reply[0] = 0,
+                (IpProtocol::Icmp, Family::V6) if packet.first() == Some(&128) => reply[0] = 129,
+                (IpProtocol::Tcp, _) if packet.len() >= 20 && packet[13] == TCP_SYN => {
+                    let sequence_number = NetworkEndian::read_u32(&packet[4..8]);
+                    reply[..2].copy_from_slice(&packet[2..4]);
+                    reply[2..4].copy_from_slice(&packet[..2]);
+                    NetworkEndian::write_u32(&mut reply[4..8], 0);
+                    NetworkEndian::write_u32(&mut reply[8..12], sequence_number.wrapping_add(1));
+                    reply[13] = TCP_SYN | TCP_ACK;
+                }
+                _ => return None,
+            }
+            Some(reply)
+        }
+    }
+
+    impl PacketSocket for MemorySocket {
+        fn send_to(&self, packet: &[u8], destination: IpAddr) -> io::Result<usize> {
+            if Family::from(destination) != self.family {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "destination of another address family",
+                ));
+            }
+            if let Some(reply) = self.reply(packet) {
+                self.network.deliver(self, &reply, destination);
+            }
+            Ok(packet.len())
+        }
+
+        fn recv_from(&self, buffer: &mut [u8]) -> io::Result<(usize, IpAddr)> {
+            let deadline = Instant::now() + RECEIVE_TIMEOUT;
+            let mut received = self.received.lock().unwrap();
+            loop {
+                if let Some((packet, source)) = received.packets.pop_front() {
+                    let length = packet.len().min(buffer.len());
+                    buffer[..length].copy_from_slice(&packet[..length]);
+                    return Ok((length, source));
+                }
+                if received.shutdown {
+                    return Ok((0, IpAddr::from([0, 0, 0, 0])));
+                }
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        "no packet received",
+                    ));
+                }
+                received = self.ready.wait_timeout(received, deadline - now).unwrap().0;
+            }
+        }
+
+        fn shutdown(&self) {
+            self.received.lock().unwrap().shutdown = true;
+            self.ready.notify_all();
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! Recording of received packets in pcap files
+//!
+//! The IPv4 packets read from the raw sockets of PingInbound are written as they were
+//! received, with their receive time, in the pcap format (nanosecond timestamps, raw IP
+//! link type) that Wireshark and tcpdump read. Either every task gets a file of its own,
+//! holding the replies that carry a payload of that task, or all packets are written to
+//! a file that is replaced by a new one when it reaches its maximum size. IPv6 packets are
+//! not recorded, as the raw IPv6 sockets do not pass their IP header.
+//!
+//! The reader takes these files back, as well as captures made with tcpdump on an Ethernet
+//! or Linux cooked interface, and returns the IPv4 packets in them for replay.
+
+use byteorder::{BigEndian, ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Magic numbers of pcap files with nanosecond and microsecond timestamps
+const MAGIC_NANOSECONDS: u32 = 0xa1b2_3c4d;
+const MAGIC_MICROSECONDS: u32 = 0xa1b2_c3d4;
+
+/// Link type of packets that start with their IP header
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+
+const SNAPLEN: u32 = 65535;
+
+/// Largest snapshot length of files that are read, as in libpcap
+const MAX_SNAPLEN: u32 = 262_144;
+
+/// Maximum number of per task files that are kept open, the file written to least
+/// recently is closed when another task needs a file
+const MAX_OPEN_FILES: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PcapMode {
+    /// One file per task, packets that do not belong to a task are not recorded
+    PerTask,
+    /// All packets, in files of at most this many bytes
+    Rotate(u64),
+}
+
+struct PcapFile {
+    writer: BufWriter<File>,
+    bytes: u64,
+    last_write: Instant,
+}
+
+impl PcapFile {
+    /// Opens a file to append packets to, writing the file header if the file is new
+    fn open(path: &Path) -> io::Result<PcapFile> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let bytes = file.metadata()?.len();
+        let mut pcap_file = PcapFile {
+            writer: BufWriter::new(file),
+            bytes,
+            last_write: Instant::now(),
+        };
+        if bytes == 0 {
+            let writer = &mut pcap_file.writer;
+            writer.write_u32::<LittleEndian>(MAGIC_NANOSECONDS)?;
+            writer.write_u16::<LittleEndian>(2)?;
+            writer.write_u16::<LittleEndian>(4)?;
+            writer.write_i32::<LittleEndian>(0)?;
+            writer.write_u32::<LittleEndian>(0)?;
+            writer.write_u32::<LittleEndian>(SNAPLEN)?;
+            writer.write_u32::<LittleEndian>(LINKTYPE_RAW)?;
+            pcap_file.bytes = 24;
+        }
+        Ok(pcap_file)
+    }
+
+    /// Appends a packet received at timestamp (in nanoseconds), flushing it right away so
+    /// the file can be inspected while the client runs
+    fn write(&mut self, timestamp: u64, packet: &[u8]) -> io::Result<()> {
+        let length = packet.len().min(SNAPLEN as usize);
+        self.writer
+            .write_u32::<LittleEndian>((timestamp / 1_000_000_000) as u32)?;
+        self.writer
+            .write_u32::<LittleEndian>((timestamp % 1_000_000_000) as u32)?;
+        self.writer.write_u32::<LittleEndian>(length as u32)?;
+        self.writer.write_u32::<LittleEndian>(packet.len() as u32)?;
+        self.writer.write_all(&packet[..length])?;
+        self.writer.flush()?;
+        self.bytes += 16 + length as u64;
+        self.last_write = Instant::now();
+        Ok(())
+    }
+}
+
+pub struct PcapWriter {
+    directory: PathBuf,
+    mode: PcapMode,
+    /// Open files, by task id in per task mode, and under 0 in rotate mode
+    files: HashMap<u32, PcapFile>,
+}
+
+impl PcapWriter {
+    /// Creates a writer that records packets in files in directory, creating it if needed
+    pub fn new(directory: &Path, mode: PcapMode) -> io::Result<PcapWriter> {
+        fs::create_dir_all(directory)?;
+        Ok(PcapWriter {
+            directory: directory.to_path_buf(),
+            mode,
+            files: HashMap::new(),
+        })
+    }
+
+    /// Records a packet received at timestamp (in nanoseconds), task_id is the task the
+    /// payload of the packet belongs to, if any
+    pub fn write(&mut self, task_id: Option<u32>, timestamp: u64, packet: &[u8]) -> io::Result<()> {
+        let key = match (self.mode, task_id) {
+            (PcapMode::PerTask, Some(task_id)) => task_id,
+            (PcapMode::PerTask, None) => return Ok(()),
+            (PcapMode::Rotate(max_bytes), _) => {
+                let full = self.files.get(&0).map_or(false, |file| {
+                    file.bytes + 16 + packet.len() as u64 > max_bytes
+                });
+                if full {
+                    self.files.remove(&0);
+                }
+                0
+            }
+        };
+
+        if !self.files.contains_key(&key) {
+            if self.files.len() >= MAX_OPEN_FILES {
+                let least_recent = self
+                    .files
+                    .iter()
+                    .min_by_key(|(_, file)| file.last_write)
+                    .map(|(key, _)| *key);
+                if let Some(least_recent) = least_recent {
+                    self.files.remove(&least_recent);
+                }
+            }
+            let name = match self.mode {
+                PcapMode::PerTask => format!("task-{}.pcap", key),
+                // Files are named after the first packet in them, so they sort by time
+                PcapMode::Rotate(_) => format!("inbound-{}.pcap", timestamp),
+            };
+            let path = self.directory.join(name);
+            debug!("recording received packets in {}", path.display());
+            self.files.insert(key, PcapFile::open(&path)?);
+        }
+        self.files.get_mut(&key).unwrap().write(timestamp, packet)
+    }
+}
+
+/// Reads the IPv4 packets of a pcap file, with their timestamps in nanoseconds, packets of
+/// other protocols are skipped
+pub struct PcapReader<R> {
+    reader: R,
+    big_endian: bool,
+    nanoseconds: bool,
+    /// Maximum length of the packets in the file
+    snaplen: u32,
+    link_type: u32,
+}
+
+impl PcapReader<BufReader<File>> {
+    pub fn open(path: &Path) -> io::Result<PcapReader<BufReader<File>>> {
+        PcapReader::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Reads the file header, files written on machines of either byte order are accepted
+    pub fn new(mut reader: R) -> io::Result<PcapReader<R>> {
+        let mut header = [0; 24];
+        reader.read_exact(&mut header)?;
+        let (big_endian, nanoseconds) = match LittleEndian::read_u32(&header[..4]) {
+            MAGIC_NANOSECONDS => (false, true),
+            MAGIC_MICROSECONDS => (false, false),
+            magic if magic.swap_bytes() == MAGIC_NANOSECONDS => (true, true),
+            magic if magic.swap_bytes() == MAGIC_MICROSECONDS => (true, false),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "not a pcap file (pcapng is not supported)",
+                ))
+            }
+        };
+        let read_u32 = if big_endian {
+            BigEndian::read_u32
+        } else {
+            LittleEndian::read_u32
+        };
+        let snaplen = read_u32(&header[16..20]).min(MAX_SNAPLEN);
+        let link_type = read_u32(&header[20..]);
+        if ![LINKTYPE_RAW, LINKTYPE_ETHERNET, LINKTYPE_LINUX_SLL].contains(&link_type) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported link type {}", link_type),
+            ));
+        }
+        Ok(PcapReader {
+            reader,
+            big_endian,
+            nanoseconds,
+            snaplen,
+            link_type,
+        })
+    }
+
+    /// Reads the next packet record, or None at the end of the file
+    fn read_record(&mut self) -> io::Result<Option<(u64, Vec<u8>)>> {
+        let mut header = [0; 16];
+        let read = self.reader.read(&mut header)?;
+        if read == 0 {
+            return Ok(None);
+        }
+        self.reader.read_exact(&mut header[read..])?;
+        let mut fields = [0; 4];
+        if self.big_endian {
+            BigEndian::read_u32_into(&header, &mut fields);
+        } else {
+            LittleEndian::read_u32_into(&header, &mut fields);
+        }
+        let fraction = if self.nanoseconds { 1 } else { 1000 };
+        let timestamp = u64::from(fields[0]) * 1_000_000_000 + u64::from(fields[1]) * fraction;
+        // A corrupt length would otherwise allocate up to 4 GB
+        if fields[2] > self.snaplen {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "packet of {} bytes exceeds the snapshot length of {} bytes",
+                    fields[2], self.snaplen
+                ),
+            ));
+        }
+        let mut packet = vec![0; fields[2] as usize];
+        self.reader.read_exact(&mut packet)?;
+        Ok(Some((timestamp, packet)))
+    }
+
+    /// Offset of the IPv4 header in a packet of the link type of the file, or None if the
+    /// packet is not an IPv4 packet
+    fn ipv4_offset(&self, packet: &[u8]) -> Option<usize> {
+        let ethertype = |offset: usize| {
+            packet
+                .get(offset..offset + 2)
+                .map(|mut bytes| bytes.read_u16::<BigEndian>().unwrap())
+        };
+        match self.link_type {
+            LINKTYPE_RAW => Some(0),
+            LINKTYPE_ETHERNET => match ethertype(12)? {
+                ETHERTYPE_IPV4 => Some(14),
+                ETHERTYPE_VLAN if ethertype(16)? == ETHERTYPE_IPV4 => Some(18),
+                _ => None,
+            },
+            _ => match ethertype(14)? {
+                ETHERTYPE_IPV4 => Some(16),
+                _ => None,
+            },
+        }
+        .filter(|offset| packet.get(*offset).map_or(false, |byte| byte >> 4 == 4))
+    }
+}
+
+impl<R: Read> Iterator for PcapReader<R> {
+    type Item = io::Result<(u64, Vec<u8>)>;
+
+    fn next(&mut self) -> Option<io::Result<(u64, Vec<u8>)>> {
+        loop {
+            match self.read_record() {
+                Ok(Some((timestamp, mut packet))) => {
+                    if let Some(offset) = self.ipv4_offset(&packet) {
+                        packet.drain(..offset);
+                        return Some(Ok((timestamp, packet)));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod pcap_files {
+    use super::*;
+
+    fn pcap_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("pcap-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&directory).ok();
+        directory
+    }
+
+    /// File names in directory, in order
+    fn file_names(directory: &Path) -> Vec<String> {
+        let mut names = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<String>>();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn packets_are_recorded_per_task() {
+        let directory = pcap_directory("task");
+        let mut writer = PcapWriter::new(&directory, PcapMode::PerTask).unwrap();
+        writer
+            .write(Some(3), 1_500_000_000_123_456_789, &[0x45; 28])
+            .unwrap();
+        writer
+            .write(None, 1_500_000_001_000_000_000, &[0x45; 28])
+            .unwrap();
+        writer
+            .write(Some(3), 1_500_000_002_000_000_000, &[0x45; 40])
+            .unwrap();
+        writer
+            .write(Some(4), 1_500_000_003_000_000_000, &[0x45; 28])
+            .unwrap();
+        drop(writer);
+
+        assert_eq!(file_names(&directory), vec!["task-3.pcap", "task-4.pcap"]);
+        let bytes = fs::read(directory.join("task-3.pcap")).unwrap();
+        assert_eq!(bytes.len(), 24 + 16 + 28 + 16 + 40);
+        assert_eq!(&bytes[..4], &[0x4d, 0x3c, 0xb2, 0xa1]);
+        assert_eq!(&bytes[20..24], &[101, 0, 0, 0]);
+        // Seconds and nanoseconds of the first packet, and its length
+        assert_eq!(&bytes[24..28], &1_500_000_000u32.to_le_bytes());
+        assert_eq!(&bytes[28..32], &123_456_789u32.to_le_bytes());
+        assert_eq!(&bytes[32..36], &28u32.to_le_bytes());
+
+        // Packets are appended to an existing file without a second header
+        let mut writer = PcapWriter::new(&directory, PcapMode::PerTask).unwrap();
+        writer
+            .write(Some(4), 1_500_000_004_000_000_000, &[0x45; 28])
+            .unwrap();
+        let bytes = fs::read(directory.join("task-4.pcap")).unwrap();
+        assert_eq!(bytes.len(), 24 + 2 * (16 + 28));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn files_are_rotated_by_size() {
+        let directory = pcap_directory("rotate");
+        let mut writer = PcapWriter::new(&directory, PcapMode::Rotate(24 + 2 * (16 + 28))).unwrap();
+        for timestamp in 1..=5 {
+            writer.write(None, timestamp, &[0x45; 28]).unwrap();
+        }
+        drop(writer);
+
+        assert_eq!(
+            file_names(&directory),
+            vec!["inbound-1.pcap", "inbound-3.pcap", "inbound-5.pcap"]
+        );
+        let sizes = file_names(&directory)
+            .iter()
+            .map(|name| fs::metadata(directory.join(name)).unwrap().len())
+            .collect::<Vec<u64>>();
+        assert_eq!(sizes, vec![24 + 2 * 44, 24 + 2 * 44, 24 + 44]);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn recorded_packets_are_read_back() {
+        let directory = pcap_directory("read");
+        let mut writer = PcapWriter::new(&directory, PcapMode::PerTask).unwrap();
+        writer.write(Some(3), 1_500_000_000_123_456_789, &[0x45; 28]).unwrap();
+        writer.write(Some(3), 1_500_000_001_000_000_000, &[0x45; 40]).unwrap();
+        drop(writer);
+
+        let reader = PcapReader::open(&directory.join("task-3.pcap")).unwrap();
+        let packets = reader.collect::<io::Result<Vec<(u64, Vec<u8>)>>>().unwrap();
+        assert_eq!(
+            packets,
+            vec![
+                (1_500_000_000_123_456_789, vec![0x45; 28]),
+                (1_500_000_001_000_000_000, vec![0x45; 40]),
+            ]
+        );
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn ethernet_captures_are_read() {
+        // Big-endian file header with microsecond timestamps and the Ethernet link type
+        let mut capture = vec![0xa1, 0xb2, 0xc3, 0xd4, 0, 2, 0, 4];
+        capture.extend_from_slice(&[0; 8]);
+        capture.extend_from_slice(&[0, 0, 0xff, 0xff, 0, 0, 0, 1]);
+        let mut record = |seconds: u8, ethertype: [u8; 2], first: u8| {
+            capture.extend_from_slice(&[0, 0, 0, seconds, 0, 0, 0, 7, 0, 0, 0, 34, 0, 0, 0, 34]);
+            capture.extend_from_slice(&[0; 12]);
+            capture.extend_from_slice(&ethertype);
+            capture.extend_from_slice(&[first; 20]);
+        };
+        record(1, [0x08, 0x00], 0x45);
+        record(2, [0x86, 0xdd], 0x60);
+        record(3, [0x08, 0x00], 0x45);
+
+        let reader = PcapReader::new(&capture[..]).unwrap();
+        let packets = reader.collect::<io::Result<Vec<(u64, Vec<u8>)>>>().unwrap();
+        assert_eq!(
+            packets,
+            vec![
+                (1_000_007_000, vec![0x45; 20]),
+                (3_000_007_000, vec![0x45; 20]),
+            ]
+        );
+
+        // A record cut short is an error
+        let reader = PcapReader::new(&capture[..capture.len() - 1]).unwrap();
+        assert_eq!(reader.filter(Result::is_err).count(), 1);
+
+        // And so is a record longer than the snapshot length
+        capture[16..20].copy_from_slice(&[0, 0, 0, 33]);
+        let mut reader = PcapReader::new(&capture[..]).unwrap();
+        let error = reader.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
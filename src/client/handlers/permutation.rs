@@ -0,0 +1,160 @@
+//! Pseudo-random order of the destinations of a task
+//!
+//! Hitlists are usually sorted, probing them in order sends all probes for a network
+//! within a short time. The destinations are instead visited by walking the cyclic group
+//! of integers modulo a prime just above the number of destinations: multiplying by a
+//! generator of the group visits every element exactly once. The seed of the task picks
+//! the generator and the starting element, so the same seed gives the same order.
+
+/// Iterator over the indices 0..size in pseudo-random order
+pub struct Permutation {
+    size: u64,
+    prime: u64,
+    generator: u64,
+    first: u64,
+    current: u64,
+    started: bool,
+}
+
+impl Permutation {
+    pub fn new(size: usize, seed: u64) -> Permutation {
+        let size = size as u64;
+        let prime = next_prime(size);
+        let generator = find_generator(prime, seed);
+        // Elements of the group are 1..prime, an element e is the index e - 1
+        let first = 1 + (seed.rotate_left(32) % (prime - 1));
+        Permutation {
+            size,
+            prime,
+            generator,
+            first,
+            current: first,
+            started: false,
+        }
+    }
+}
+
+impl Iterator for Permutation {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            if self.started && self.current == self.first {
+                return None;
+            }
+            self.started = true;
+            let element = self.current;
+            self.current = mul_mod(self.current, self.generator, self.prime);
+
+            // Elements beyond the number of destinations are skipped
+            if element <= self.size {
+                return Some((element - 1) as usize);
+            }
+        }
+    }
+}
+
+fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((u128::from(a) * u128::from(b)) % u128::from(modulus)) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        if n % divisor == 0 {
+            return false;
+        }
+        divisor += 1;
+    }
+    true
+}
+
+/// Smallest prime larger than n
+fn next_prime(n: u64) -> u64 {
+    let mut candidate = n + 1;
+    while !is_prime(candidate) {
+        candidate += 1;
+    }
+    candidate
+}
+
+/// Distinct prime factors of n
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        if n % divisor == 0 {
+            factors.push(divisor);
+            while n % divisor == 0 {
+                n /= divisor;
+            }
+        }
+        divisor += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// A generator of the multiplicative group modulo prime, the first one from a
+/// starting point picked by the seed
+fn find_generator(prime: u64, seed: u64) -> u64 {
+    if prime == 2 {
+        return 1;
+    }
+    let order = prime - 1;
+    let factors = prime_factors(order);
+    let mut candidate = 2 + seed % (prime - 2);
+    loop {
+        // An element generates the group if its order is not a proper divisor of prime - 1
+        if factors
+            .iter()
+            .all(|factor| pow_mod(candidate, order / factor, prime) != 1)
+        {
+            return candidate;
+        }
+        candidate = if candidate + 1 < prime { candidate + 1 } else { 2 };
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod destination_order {
+    use super::*;
+
+    #[test]
+    fn every_index_is_visited_once() {
+        for size in &[0, 1, 2, 3, 10, 1000, 65_537] {
+            for seed in &[0, 1, 0xdead_beef_cafe_f00d] {
+                let mut order = Permutation::new(*size, *seed).collect::<Vec<usize>>();
+                assert_eq!(order.len(), *size);
+                order.sort();
+                assert!(order.iter().enumerate().all(|(i, index)| i == *index));
+            }
+        }
+    }
+
+    #[test]
+    fn seed_determines_order() {
+        let order = Permutation::new(1000, 42).collect::<Vec<usize>>();
+        assert_eq!(order, Permutation::new(1000, 42).collect::<Vec<usize>>());
+        assert_ne!(order, Permutation::new(1000, 43).collect::<Vec<usize>>());
+        assert_ne!(order, (0..1000).collect::<Vec<usize>>());
+    }
+}
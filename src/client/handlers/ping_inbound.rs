@@ -2,11 +2,20 @@
 #![allow(unused_variables)]
 #![allow(unused_imports)]
 
-use super::{current_timestamp, ChannelType, TaskHandler};
-use crate::net::{IPv4Packet, PacketPayload};
-use crate::schema::verfploeter::{Client, Metadata, PingPayload, PingResult, Result, TaskResult};
+use super::packet_io::{Family, IpProtocol, PacketIo, PacketSocket};
+use super::pcap::PcapWriter;
+use super::spool::Spool;
+use super::{
+    current_timestamp, invalid_payload_task_id, result_task_id, tcp_syn, transmit_queue,
+    verify_payload, ChannelType, KeyMap, ResultQueue, TaskHandler,
+};
+use crate::net::{
+    IPPacket, IPv4Packet, IPv6Packet, ICMP6Packet, PacketPayload, TCPPacket, TCP_ACK, TCP_RST,
+    TCP_SYN,
+};
+use crate::schema::verfploeter::{Metadata, PingResult, Result, TcpResult};
 use crate::schema::verfploeter_grpc::VerfploeterClient;
-use crate::schema::Signable;
+use crate::{INFO_URL, RESULT_FLUSH_INTERVAL};
 
 use futures::sync::mpsc::{channel, Receiver, Sender};
 use futures::sync::oneshot;
@@ -15,8 +24,8 @@ use futures::Sink;
 use futures::Stream;
 use lazy_static::lazy_static;
 use prometheus::{opts, register_counter, register_int_counter, IntCounter};
-use socket2::{Domain, Protocol, Socket, Type};
-use std::net::Shutdown;
+use std::io;
+use std::net::{IpAddr, Ipv6Addr};
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::thread;
@@ -48,12 +57,18 @@ lazy_static! {
     .unwrap();
 }
 
+/// Turns the bytes received on a socket into a packet, or None if the packet is of no interest
+type PacketParser = fn(&[u8], IpAddr) -> Option<IPPacket>;
+
 pub struct PingInbound {
     handles: Vec<JoinHandle<()>>,
-    socket: Arc<Socket>,
+    sockets: Vec<(Arc<dyn PacketSocket>, PacketParser)>,
     grpc_client: Arc<VerfploeterClient>,
     metadata: Metadata,
-    result_queue: Arc<Mutex<Option<Vec<Result>>>>,
+    keys: KeyMap,
+    result_queue: Arc<Mutex<ResultQueue>>,
+    spool: Arc<Mutex<Spool>>,
+    pcap: Option<PcapWriter>,
     poison_rx: oneshot::Receiver<()>,
     poison_tx: Option<oneshot::Sender<()>>,
 }
@@ -62,70 +77,80 @@ impl TaskHandler for PingInbound {
     fn start(&mut self) {
         debug!("starting TaskHandler::PingInbound::start()");
         let (tx, rx) = channel(1024);
+        let mut pcap = self.pcap.take();
 
-        // The packet receiver thread takes the packets from the actual socket
-        // and puts them in a channel to be processed
-        let packet_receiver_handle = thread::spawn({
-            let socket = self.socket.clone();
-            move || {
-                let mut buffer: Vec<u8> = vec![0; 1500];
-                while let Ok(result) = socket.recv(&mut buffer) {
-                    PACKETS_RECEIVED.inc();
-                    if result == 0 {
-                        break;
-                    }
+        // The packet receiver threads take the packets from the actual sockets
+        // and put them in a channel to be processed
+        for (socket, parse) in &self.sockets {
+            let handle = thread::spawn({
+                let socket = socket.clone();
+                let parse = *parse;
+                let tx = tx.clone();
+                let record = pcap.is_some();
+                move || {
+                    let mut buffer: Vec<u8> = vec![0; 1500];
+                    while let Ok((result, address)) = socket.recv_from(&mut buffer) {
+                        PACKETS_RECEIVED.inc();
+                        if result == 0 {
+                            break;
+                        }
 
-                    let packet = IPv4Packet::from(&buffer[..result]);
-                    tx.clone()
-                        .send((current_timestamp(), packet))
-                        .wait()
-                        .expect("unable to send packet to tx channel");
+                        if let Some(packet) = parse(&buffer[..result], address) {
+                            // Keep the bytes of IPv4 packets to record them, the IPv6
+                            // sockets do not pass the IP header
+                            let bytes = match packet {
+                                IPPacket::V4(_) if record => Some(buffer[..result].to_vec()),
+                                _ => None,
+                            };
+                            tx.clone()
+                                .send((current_timestamp(), packet, bytes))
+                                .wait()
+                                .expect("unable to send packet to tx channel");
+                        }
+                    }
                 }
-            }
-        });
+            });
+            self.handles.push(handle);
+        }
 
         // The packet processor thread takes the packets from the packet receiver thread channel
         // processes them (check the payload, create the protobuf struct) and puts them in a
         // buffer for transmission to the server
         let packet_processor_handle = thread::spawn({
             let result_queue = self.result_queue.clone();
+            let keys = self.keys.clone();
             move || {
-                rx.for_each(|(receive_time, packet)| {
-                    // Extract payload
-                    let mut ping_payload = None;
-                    if let PacketPayload::ICMPv4 { value } = packet.payload {
-                        // Todo: make the secret configurable
-                        let payload = PingPayload::from_signed_bytes("test-secret", &value.body);
-                        if let Ok(payload) = payload {
-                            ping_payload = Some(payload);
+                rx.for_each(|(receive_time, packet, bytes)| {
+                    let result = packet_result(&keys, &packet, receive_time);
+
+                    // Replies to our probes that fail verification are counted for the task
+                    // summary
+                    let invalid_task_id = if result.is_none() {
+                        invalid_reply_task_id(&keys, &packet)
+                    } else {
+                        None
+                    };
+
+                    if let (Some(pcap), Some(bytes)) = (pcap.as_mut(), bytes) {
+                        let task_id = result.as_ref().map(result_task_id).or(invalid_task_id);
+                        if let Err(e) = pcap.write(task_id, receive_time, &bytes) {
+                            error!("unable to record packet: {}", e);
                         }
                     }
 
-                    // Don't do anything if we don't have a proper payload
-                    if ping_payload.is_none() {
+                    // Don't do anything else if we don't have a proper reply
+                    if result.is_none() {
                         PACKETS_PROCESSED_INVALID.inc();
+                        if let Some(task_id) = invalid_task_id {
+                            result_queue.lock().unwrap().add_invalid_reply(task_id);
+                        }
                         return futures::future::ok(());
                     }
                     PACKETS_PROCESSED_VALID.inc();
-                    let ping_payload = ping_payload.unwrap();
-
-                    let mut result = Result::new();
-                    let mut pr = PingResult::new();
-
-                    pr.set_payload(ping_payload);
-                    pr.set_source_address(packet.source_address.into());
-                    pr.set_destination_address(packet.destination_address.into());
-                    pr.set_receive_time(receive_time);
-                    pr.set_ttl(packet.ttl.into());
-                    result.set_ping(pr);
+                    let result = result.unwrap();
 
                     // Put result in transmission queue
-                    {
-                        let mut rq_opt = result_queue.lock().unwrap();
-                        if let Some(ref mut x) = *rq_opt {
-                            x.push(result);
-                        }
-                    }
+                    result_queue.lock().unwrap().push(result);
 
                     futures::future::ok(())
                 })
@@ -140,68 +165,32 @@ impl TaskHandler for PingInbound {
         let packet_transmitter_handle = thread::spawn({
             let grpc_client = self.grpc_client.clone();
             let result_queue = self.result_queue.clone();
+            let spool = self.spool.clone();
             let poison_tx = self.poison_tx.take().unwrap();
             let metadata = self.metadata.clone();
             move || {
                 loop {
-                    thread::sleep(Duration::from_secs(5));
+                    thread::sleep(Duration::from_secs(RESULT_FLUSH_INTERVAL));
 
                     // Check if this thread is still supposed to be running
                     if poison_tx.is_canceled() {
                         break;
                     }
 
-                    // Get the current result queue, and replace it with an empty one
-                    let mut rq;
-                    {
-                        let mut result_queue = result_queue.lock().unwrap();
-                        rq = result_queue.replace(Vec::new()).unwrap();
-                    }
-
-                    // Sort the result queue by task id
-                    let mut rq_ping = rq.drain_filter(|x| x.has_ping()).collect::<Vec<Result>>();
-                    rq_ping.sort_by_key(|x| x.get_ping().get_payload().get_task_id());
-
-                    // Transmit the results, grouped by task id
-                    let mut tr = TaskResult::new();
-                    tr.set_task_id(u32::MAX);
-                    for result in rq_ping {
-                        let result_taskid = result.get_ping().get_payload().get_task_id();
-                        if tr.get_task_id() != result_taskid {
-                            // If the current 'result container' has some results, send it
-                            if !tr.get_result_list().is_empty() {
-                                if let Err(e) = grpc_client.send_result(&tr) {
-                                    error!("failed to send result to server: {}", e);
-                                } else {
-                                    PACKETS_TRANSMITTED.inc_by(tr.get_result_list().len() as i64);
-                                }
-                            }
-                            tr = TaskResult::new();
-                            tr.set_task_id(result_taskid);
-                            let mut client = Client::new();
-                            client.set_metadata(metadata.clone());
-                            tr.set_client(client);
-                        }
-                        tr.mut_result_list().push(result);
-                    }
-                    if !tr.get_result_list().is_empty() {
-                        if let Err(e) = grpc_client.send_result(&tr) {
-                            error!("failed to send result to server: {}", e);
-                        } else {
-                            PACKETS_TRANSMITTED.inc_by(tr.get_result_list().len() as i64);
-                        }
-                    }
+                    let transmitted = transmit_queue(&grpc_client, &metadata, &result_queue, &spool);
+                    PACKETS_TRANSMITTED.inc_by(transmitted as i64);
                 }
             }
         });
-        self.handles.push(packet_receiver_handle);
         self.handles.push(packet_processor_handle);
         self.handles.push(packet_transmitter_handle);
     }
 
     fn exit(&mut self) {
         debug!("Existing PingInbound::exit()");
-        self.socket.shutdown(Shutdown::Both).unwrap_err();
+        for (socket, _) in &self.sockets {
+            socket.shutdown();
+        }
         self.poison_rx.close();
         for handle in self.handles.drain(..) {
             handle.join().unwrap();
@@ -214,22 +203,365 @@ impl TaskHandler for PingInbound {
 }
 
 impl PingInbound {
-    pub fn new(metadata: Metadata, grpc_client: Arc<VerfploeterClient>) -> PingInbound {
+    pub fn new(
+        metadata: Metadata,
+        grpc_client: Arc<VerfploeterClient>,
+        keys: KeyMap,
+        spool: Arc<Mutex<Spool>>,
+        pcap: Option<PcapWriter>,
+        packet_io: &dyn PacketIo,
+    ) -> io::Result<PingInbound> {
         debug!("PingInbound::new()");
-        let socket =
-            Arc::new(Socket::new(Domain::ipv4(), Type::raw(), Some(Protocol::icmpv4())).unwrap());
-        debug!("socket [{:?}]",socket);
+        let sockets: Vec<(Arc<dyn PacketSocket>, PacketParser)> = vec![
+            (packet_io.open(Family::V4, IpProtocol::Icmp, None)?, parse_ipv4),
+            (packet_io.open(Family::V6, IpProtocol::Icmp, None)?, parse_icmpv6),
+            (packet_io.open(Family::V4, IpProtocol::Tcp, None)?, parse_ipv4),
+            (packet_io.open(Family::V6, IpProtocol::Tcp, None)?, parse_tcpv6),
+        ];
         let (poison_tx, poison_rx): (oneshot::Sender<()>, oneshot::Receiver<()>) =
             oneshot::channel();
 
-        PingInbound {
+        Ok(PingInbound {
             handles: Vec::new(),
-            socket,
+            sockets,
             grpc_client,
             metadata,
-            result_queue: Arc::new(Mutex::new(Some(Vec::new()))),
+            keys,
+            result_queue: Arc::new(Mutex::new(ResultQueue::default())),
+            spool,
+            pcap,
             poison_tx: Some(poison_tx),
             poison_rx,
+        })
+    }
+}
+
+/// Echo requests carry the INFO_URL behind the signed payload, which is echoed back
+/// in the reply and has to be removed before the signature can be verified
+fn strip_info_url(body: &[u8]) -> &[u8] {
+    if body.ends_with(INFO_URL.as_bytes()) {
+        &body[..body.len() - INFO_URL.len()]
+    } else {
+        body
+    }
+}
+
+/// Raw IPv4 sockets pass the IP header
+fn parse_ipv4(buffer: &[u8], _address: IpAddr) -> Option<IPPacket> {
+    ipv4_packet(buffer)
+}
+
+/// Parses an IPv4 packet, or None if it is too short to hold its IP header and the header
+/// of its ICMP, UDP or TCP payload, e.g. because a capture cut it off
+pub fn ipv4_packet(buffer: &[u8]) -> Option<IPPacket> {
+    if buffer.len() < 20 {
+        return None;
+    }
+    let header_length = usize::from(buffer[0] & 0xf) * 4;
+    let minimum_payload = match buffer[9] {
+        1 | 17 => 8,
+        6 => 20,
+        _ => 0,
+    };
+    if header_length < 20 || buffer.len() < header_length + minimum_payload {
+        return None;
+    }
+    Some(IPPacket::V4(IPv4Packet::from(buffer)))
+}
+
+/// Raw IPv6 sockets do not pass the IP header, so the source address is taken from the
+/// socket, the destination address is filled in by the processor from the payload
+fn parse_icmpv6(buffer: &[u8], address: IpAddr) -> Option<IPPacket> {
+    // Only echo replies can carry our payload
+    if buffer.len() < 8 || buffer[0] != 129 {
+        return None;
+    }
+    Some(IPPacket::V6(IPv6Packet {
+        hop_limit: None,
+        source_address: ipv6_address(address)?,
+        destination_address: Ipv6Addr::UNSPECIFIED,
+        payload: PacketPayload::ICMPv6 {
+            value: ICMP6Packet::from(buffer),
+        },
+    }))
+}
+
+/// Same as parse_icmpv6, for TCP segments
+fn parse_tcpv6(buffer: &[u8], address: IpAddr) -> Option<IPPacket> {
+    if buffer.len() < 20 {
+        return None;
+    }
+    Some(IPPacket::V6(IPv6Packet {
+        hop_limit: None,
+        source_address: ipv6_address(address)?,
+        destination_address: Ipv6Addr::UNSPECIFIED,
+        payload: PacketPayload::TCP {
+            value: TCPPacket::from(buffer),
+        },
+    }))
+}
+
+fn ipv6_address(address: IpAddr) -> Option<Ipv6Addr> {
+    match address {
+        IpAddr::V6(address) => Some(address),
+        IpAddr::V4(_) => None,
+    }
+}
+
+/// Creates the result of a received packet, if it is a reply to one of our probes
+pub fn packet_result(keys: &KeyMap, packet: &IPPacket, receive_time: u64) -> Option<Result> {
+    match packet.payload() {
+        PacketPayload::ICMPv4 { value } => ping_result(keys, packet, &value.body, receive_time),
+        PacketPayload::ICMPv6 { value } => ping_result(keys, packet, &value.body, receive_time),
+        PacketPayload::TCP { value } => tcp_result(keys, packet, value, receive_time),
+        // DNS responses are handled by DnsInbound
+        PacketPayload::UDP { .. } | PacketPayload::Unimplemented => None,
+    }
+}
+
+/// Task id of an echo reply that names one of our keys, but fails verification
+pub fn invalid_reply_task_id(keys: &KeyMap, packet: &IPPacket) -> Option<u32> {
+    let body = match packet.payload() {
+        PacketPayload::ICMPv4 { value } => &value.body,
+        PacketPayload::ICMPv6 { value } => &value.body,
+        _ => return None,
+    };
+    invalid_payload_task_id(keys, strip_info_url(body))
+}
+
+/// Creates the result of an echo reply, if it carries a valid payload
+fn ping_result(keys: &KeyMap, packet: &IPPacket, body: &[u8], receive_time: u64) -> Option<Result> {
+    let ping_payload = verify_payload(keys, strip_info_url(body))?;
+
+    // The IPv6 socket does not tell us where the reply was sent to, which is
+    // the source address of the probe
+    let destination_address = match packet {
+        IPPacket::V6(_) => ping_payload.get_source_address().clone(),
+        IPPacket::V4(_) => packet.destination_address().into(),
+    };
+
+    let mut pr = PingResult::new();
+    pr.set_source_address(packet.source_address().into());
+    pr.set_destination_address(destination_address);
+    pr.set_receive_time(receive_time);
+    if let Some(ttl) = packet.ttl() {
+        pr.set_ttl(ttl.into());
+    }
+    pr.set_payload(ping_payload);
+
+    let mut result = Result::new();
+    result.set_ping(pr);
+    Some(result)
+}
+
+/// Creates the result of a SYN-ACK or RST reply to one of our SYN probes, which is
+/// recognized by the destination port matching the source port of the probe
+fn tcp_result(
+    keys: &KeyMap,
+    packet: &IPPacket,
+    segment: &TCPPacket,
+    receive_time: u64,
+) -> Option<Result> {
+    let is_syn_ack = segment.flags & (TCP_SYN | TCP_ACK) == TCP_SYN | TCP_ACK;
+    let is_rst = segment.flags & TCP_RST != 0;
+    if !is_syn_ack && !is_rst {
+        return None;
+    }
+
+    let sequence_number = segment.acknowledgement_number.wrapping_sub(1);
+    let source_address = packet.source_address();
+    let keys = keys.read().unwrap();
+    if !keys.values().any(|secret| {
+        tcp_syn::source_port(secret, &source_address, sequence_number) == segment.destination_port
+    }) {
+        return None;
+    }
+    let (task_id, transmit_time) = tcp_syn::decode_sequence_number(sequence_number, receive_time);
+
+    let mut tr = TcpResult::new();
+    tr.set_source_address(source_address.into());
+    tr.set_destination_address(packet.destination_address().into());
+    tr.set_receive_time(receive_time);
+    if let Some(ttl) = packet.ttl() {
+        tr.set_ttl(ttl.into());
+    }
+    tr.set_task_id(task_id);
+    tr.set_transmit_time(transmit_time);
+    tr.set_source_port(segment.source_port.into());
+    tr.set_flags(segment.flags.into());
+
+    let mut result = Result::new();
+    result.set_tcp(tr);
+    Some(result)
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod memory_replies {
+    use super::super::packet_io::memory::MemoryPacketIo;
+    use super::super::ping_outbound::PingOutbound;
+    use super::*;
+    use crate::net::ICMP6Packet;
+    use crate::schema::verfploeter::{Address, Key, Ping, PingPayload, Task};
+    use crate::schema::Signable;
+    use grpcio::{ChannelBuilder, Environment};
+    use std::collections::HashMap;
+    use std::net::Ipv4Addr;
+    use std::path::Path;
+    use std::sync::RwLock;
+    use std::time::Instant;
+
+    const SECRET: &str = "reply-secret";
+
+    /// A client of a server that is not there, results are spooled
+    fn grpc_client() -> Arc<VerfploeterClient> {
+        let env = Arc::new(Environment::new(1));
+        Arc::new(VerfploeterClient::new(
+            ChannelBuilder::new(env).connect("127.0.0.1:1"),
+        ))
+    }
+
+    /// Starts an inbound handler with a spool in directory
+    fn start_inbound(packet_io: &MemoryPacketIo, directory: &Path) -> PingInbound {
+        let mut keys = HashMap::new();
+        keys.insert(1, SECRET.to_string());
+        let spool = Spool::open(directory, 1024 * 1024).unwrap();
+        let mut inbound = PingInbound::new(
+            Metadata::new(),
+            grpc_client(),
+            Arc::new(RwLock::new(keys)),
+            Arc::new(Mutex::new(spool)),
+            None,
+            packet_io,
+        )
+        .unwrap();
+        inbound.start();
+        inbound
+    }
+
+    #[test]
+    fn replies_are_queued() {
+        let source = "2001:db8::1".parse::<Ipv6Addr>().unwrap();
+        let destination = "2001:db8::2".parse::<Ipv6Addr>().unwrap();
+        let directory = std::env::temp_dir().join(format!("inbound-{}", std::process::id()));
+        let packet_io = MemoryPacketIo::default();
+        let mut inbound = start_inbound(&packet_io, &directory);
+
+        // An echo request of task 7 and one with a payload signed with another secret, which
+        // the network answers with echo replies to the IPv6 socket of the inbound handler
+        let outbound = packet_io
+            .open(Family::V6, IpProtocol::Icmp, Some(source.into()))
+            .unwrap();
+        for (task_id, secret) in &[(7, SECRET), (8, "other-secret")] {
+            let mut payload = PingPayload::new();
+            payload.set_source_address(source.into());
+            payload.set_destination_address(destination.into());
+            payload.set_task_id(*task_id);
+            payload.set_key_id(1);
+            let body = payload.to_signed_bytes(secret).unwrap();
+            let request = ICMP6Packet::echo_request(1, 2, body, source, destination);
+            outbound.send_to(&request, destination.into()).unwrap();
+        }
+
+        let start = Instant::now();
+        while inbound.result_queue.lock().unwrap().invalid_replies.is_empty()
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            thread::sleep(Duration::from_millis(10));
         }
+        {
+            let queue = inbound.result_queue.lock().unwrap();
+            assert_eq!(queue.results.len(), 1);
+            let ping = queue.results[0].get_ping();
+            assert_eq!(ping.get_payload().get_task_id(), 7);
+            assert_eq!(IpAddr::from(ping.get_source_address()), IpAddr::from(destination));
+            // The IPv6 socket does not pass the destination, it comes from the payload
+            assert_eq!(IpAddr::from(ping.get_destination_address()), IpAddr::from(source));
+            // Nor the hop limit, which is left unset
+            assert_eq!(ping.get_ttl(), 0);
+            assert_eq!(queue.invalid_replies.get(&8), Some(&1));
+        }
+
+        inbound.exit();
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn probes_of_the_outbound_handler_are_received() {
+        let destinations = [Ipv4Addr::new(198, 51, 100, 1), Ipv4Addr::new(198, 51, 100, 2)];
+        let directory = std::env::temp_dir().join(format!("outbound-{}", std::process::id()));
+        let packet_io = MemoryPacketIo::default();
+        let mut inbound = start_inbound(&packet_io, &directory);
+        let mut outbound = PingOutbound::new(grpc_client(), 1000, Arc::new(packet_io.clone()));
+        outbound.start();
+
+        // A ping task of which all destinations arrive in a single chunk
+        let mut key = Key::new();
+        key.set_key_id(1);
+        key.set_secret(SECRET.to_string());
+        let mut ping = Ping::new();
+        ping.set_source_address(Ipv4Addr::new(192, 0, 2, 1).into());
+        ping.set_key(key);
+        let mut task = Task::new();
+        task.set_task_id(9);
+        task.set_ping(ping);
+        let mut chunk = Task::new();
+        chunk.set_task_id(9);
+        chunk
+            .mut_destinations()
+            .set_addresses(destinations.iter().map(|d| Address::from(*d)).collect());
+        chunk.mut_destinations().set_last(true);
+        let tx = match outbound.get_channel() {
+            ChannelType::Task {
+                sender: Some(tx), ..
+            } => tx,
+            _ => unreachable!(),
+        };
+        tx.send(task).and_then(|tx| tx.send(chunk)).wait().unwrap();
+
+        let start = Instant::now();
+        while inbound.result_queue.lock().unwrap().results.len() < destinations.len()
+            && start.elapsed() < Duration::from_secs(5)
+        {
+            thread::sleep(Duration::from_millis(10));
+        }
+        {
+            let queue = inbound.result_queue.lock().unwrap();
+            let mut sources = queue
+                .results
+                .iter()
+                .map(|result| {
+                    assert_eq!(result_task_id(result), 9);
+                    IpAddr::from(result.get_ping().get_source_address())
+                })
+                .collect::<Vec<IpAddr>>();
+            sources.sort();
+            let expected = destinations.iter().map(|d| IpAddr::from(*d)).collect::<Vec<_>>();
+            assert_eq!(sources, expected);
+            assert!(queue.invalid_replies.is_empty());
+        }
+
+        outbound.exit();
+        inbound.exit();
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn short_packets_are_dropped() {
+        let address = IpAddr::from([192, 0, 2, 1]);
+        // An IPv4 header with an echo reply, a UDP header and a TCP header
+        let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 192, 0, 2, 1, 192, 0, 2, 2];
+        packet.extend_from_slice(&[0; 20]);
+        assert!(parse_ipv4(&packet[..28], address).is_some());
+        assert!(parse_ipv4(&packet[..27], address).is_none());
+        assert!(parse_ipv4(&packet[..19], address).is_none());
+        packet[9] = 17;
+        assert!(parse_ipv4(&packet[..28], address).is_some());
+        packet[9] = 6;
+        assert!(parse_ipv4(&packet[..39], address).is_none());
+        assert!(parse_ipv4(&packet, address).is_some());
+        // A header length beyond the packet
+        packet[0] = 0x4f;
+        assert!(parse_ipv4(&packet, address).is_none());
     }
 }
@@ -2,12 +2,15 @@
 #![allow(unused_variables)]
 #![allow(unused_must_use)]
 
-use super::{current_timestamp, ChannelType, TaskHandler};
-use crate::net::ICMP4Packet;
-use crate::schema::verfploeter::{PingPayload, Task, TaskId};
+use super::packet_io::{IpProtocol, PacketIo};
+use super::permutation::Permutation;
+use super::super::{RECONNECT_DELAY_MAX, RECONNECT_DELAY_MIN};
+use super::{current_timestamp, tcp_syn, udp_dns, ChannelType, TaskHandler};
+use crate::net::{ICMP4Packet, ICMP6Packet, TCPPacket, UDPPacket};
+use crate::schema::verfploeter::{Address, PingPayload, Task, TaskId, TaskProgress};
 use crate::schema::verfploeter_grpc::VerfploeterClient;
 use crate::schema::Signable;
-use crate::PING_OUT_RATE_LIMIT;
+use crate::RESULT_FLUSH_INTERVAL;
 
 use futures::sync::mpsc::{channel, Receiver, Sender};
 use futures::sync::oneshot;
@@ -15,15 +18,22 @@ use futures::{Future, Stream};
 use lazy_static::lazy_static;
 use prometheus::{opts, register_counter, register_int_counter, IntCounter};
 use ratelimit_meter::{DirectRateLimiter, LeakyBucket};
-use socket2::{Domain, Protocol, Socket, Type};
-use std::net::{Ipv4Addr, SocketAddr};
+use std::net::IpAddr;
+use protobuf::RepeatedField;
+use std::collections::{HashMap, HashSet};
 use std::num::NonZeroU32;
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::u32;
 
+/// Seconds between progress reports to the server
+const PROGRESS_INTERVAL: u64 = 5;
+
+/// Seconds to wait for the last replies of a task that does not set a grace period
+const DEFAULT_GRACE_PERIOD: u64 = 10;
+
 // Define Prometheus metrics
 lazy_static! {
     static ref PACKETS_TRANSMITTED_OK: IntCounter = register_int_counter!(
@@ -46,6 +56,9 @@ pub struct PingOutbound {
     handle: Option<JoinHandle<()>>,
     grpc_client: Arc<VerfploeterClient>,
     outbound_mutex: Arc<Mutex<u32>>,
+    cancelled_tasks: Arc<Mutex<HashSet<u32>>>,
+    max_rate: u32,
+    packet_io: Arc<dyn PacketIo>,
 }
 
 impl TaskHandler for PingOutbound {
@@ -56,15 +69,64 @@ impl TaskHandler for PingOutbound {
             let rx = self.rx.take().unwrap();
             let shutdown_rx = self.shutdown_rx.take().unwrap();
             let outbound_mutex = Arc::clone(&self.outbound_mutex);
+            let cancelled_tasks = Arc::clone(&self.cancelled_tasks);
+            let max_rate = self.max_rate;
+            let packet_io = Arc::clone(&self.packet_io);
             move || {
+                // Destinations of a task arrive in chunks after the task itself, they are
+                // passed on to the thread probing them until the last chunk arrives
+                let mut destination_channels: HashMap<u32, mpsc::Sender<RepeatedField<Address>>> =
+                    HashMap::new();
                 let handler = rx
-                    .for_each(|i| {
+                    .for_each(|mut i| {
+                        if i.has_cancel() {
+                            info!("cancelling task {}", i.get_task_id());
+                            cancelled_tasks.lock().unwrap().insert(i.get_task_id());
+                            // Ends the destinations of the task, in case it is still waiting
+                            destination_channels.remove(&i.get_task_id());
+                            return futures::future::ok(());
+                        }
+
+                        // Sent by the client when its connection to the server is lost, the
+                        // remaining destinations of tasks still being uploaded never arrive
+                        if i.has_shutdown() {
+                            if !destination_channels.is_empty() {
+                                warn!(
+                                    "connection lost, {} tasks get no more destinations",
+                                    destination_channels.len()
+                                );
+                            }
+                            destination_channels.clear();
+                            return futures::future::ok(());
+                        }
+
+                        if i.has_destinations() {
+                            let mut destinations = i.take_destinations();
+                            match destination_channels.get(&i.get_task_id()) {
+                                Some(tx) => {
+                                    // The probing thread is gone if the task was cancelled
+                                    tx.send(destinations.take_addresses()).ok();
+                                }
+                                None => warn!("destinations for unknown task {}", i.get_task_id()),
+                            }
+                            if destinations.get_last() {
+                                destination_channels.remove(&i.get_task_id());
+                            }
+                            return futures::future::ok(());
+                        }
+
                         // Start the actual pinging process in a different thread
                         // otherwise the GRPC stream will die if it takes too long
+                        let (destinations_tx, destinations_rx) = mpsc::channel();
+                        destination_channels.insert(i.get_task_id(), destinations_tx);
                         PingOutbound::start_ping_thread(
                             Arc::clone(&grpc_client),
                             Arc::clone(&outbound_mutex),
+                            Arc::clone(&cancelled_tasks),
+                            max_rate,
+                            Arc::clone(&packet_io),
                             i,
+                            destinations_rx,
                         );
 
                         futures::future::ok(())
@@ -100,7 +162,12 @@ impl TaskHandler for PingOutbound {
 }
 
 impl PingOutbound {
-    pub fn new(grpc_client: Arc<VerfploeterClient>) -> PingOutbound {
+    /// max_rate caps the probing rate of every task (packets per second)
+    pub fn new(
+        grpc_client: Arc<VerfploeterClient>,
+        max_rate: u32,
+        packet_io: Arc<dyn PacketIo>,
+    ) -> PingOutbound {
         debug!("PingOutbound::new()");
         let (tx, rx): (Sender<Task>, Receiver<Task>) = channel(10);
         let (shutdown_tx, shutdown_rx) = oneshot::channel();
@@ -112,47 +179,159 @@ impl PingOutbound {
             handle: None,
             grpc_client,
             outbound_mutex: Arc::new(Mutex::new(0)),
+            cancelled_tasks: Arc::new(Mutex::new(HashSet::new())),
+            max_rate,
+            packet_io,
         }
     }
 
-    fn perform_ping(task: &Task) {
+    fn perform_ping(
+        task: &Task,
+        destinations: &mpsc::Receiver<RepeatedField<Address>>,
+        grpc_client: &VerfploeterClient,
+        cancelled_tasks: &Mutex<HashSet<u32>>,
+        max_rate: u32,
+        packet_io: &dyn PacketIo,
+    ) {
         debug!("PingOutbound::perform_ping()");
+        // Echo requests, TCP SYN and DNS probes share everything except the packet itself
+        let (source, key, requested_rate) = if task.has_tcp_syn() {
+            let tcp_syn = task.get_tcp_syn();
+            (tcp_syn.get_source_address(), tcp_syn.get_key(), tcp_syn.get_rate())
+        } else if task.has_dns() {
+            let dns = task.get_dns();
+            (dns.get_source_address(), dns.get_key(), dns.get_rate())
+        } else {
+            let ping = task.get_ping();
+            (ping.get_source_address(), ping.get_key(), ping.get_rate())
+        };
+        let source_address = IpAddr::from(source);
         info!(
-            "performing outbound ping from {}, to {} addresses, task id: {}",
-            Ipv4Addr::from(task.get_ping().get_source_address().get_v4()),
-            task.get_ping().get_destination_addresses().len(),
-            task.get_task_id()
-        );
-        let bindaddress = format!(
-            "{}:0",
-            Ipv4Addr::from(task.get_ping().get_source_address().get_v4()).to_string()
+            "performing outbound {} from {}, task id: {}, seed: {}",
+            if task.has_tcp_syn() {
+                "tcp syn"
+            } else if task.has_dns() {
+                "dns"
+            } else {
+                "ping"
+            },
+            source_address,
+            task.get_task_id(),
+            task.get_seed()
         );
-        let socket = Socket::new(Domain::ipv4(), Type::raw(), Some(Protocol::icmpv4())).unwrap();
-        socket
-            .bind(
-                &bindaddress
-                    .to_string()
-                    .parse::<SocketAddr>()
-                    .unwrap()
-                    .into(),
-            )
-            .unwrap();
+        let protocol = if task.has_tcp_syn() {
+            IpProtocol::Tcp
+        } else if task.has_dns() {
+            IpProtocol::Udp
+        } else {
+            IpProtocol::Icmp
+        };
+        let socket = match packet_io.open(source_address.into(), protocol, Some(source_address)) {
+            Ok(socket) => socket,
+            Err(e) => {
+                error!(
+                    "unable to open socket for task {} from {}: {}",
+                    task.get_task_id(),
+                    source_address,
+                    e
+                );
+                return;
+            }
+        };
 
-        let mut lb = DirectRateLimiter::<LeakyBucket>::per_second(NonZeroU32::new(PING_OUT_RATE_LIMIT).unwrap());
-        for ip in task.get_ping().get_destination_addresses() {
-            // Create payload that will be transmitted inside the ICMP echo request
-            let mut payload = PingPayload::new();
-            payload.set_source_address(task.get_ping().get_source_address().clone());
-            payload.set_destination_address(ip.clone());
-            payload.set_task_id(task.get_task_id());
+        // Use the rate requested by the task, within the limit of this client
+        let rate = if requested_rate == 0 {
+            max_rate
+        } else if requested_rate > max_rate {
+            warn!(
+                "task {} requested {} pps, limiting to {} pps",
+                task.get_task_id(),
+                requested_rate,
+                max_rate
+            );
+            max_rate
+        } else {
+            requested_rate
+        };
+        let mut lb = DirectRateLimiter::<LeakyBucket>::per_second(NonZeroU32::new(rate).unwrap());
+        let mut destinations_sent = 0;
+        let mut last_progress = Instant::now();
+        // Probe every chunk of destinations in a pseudo-random order, so the probes to any
+        // network are spread over the run
+        let chunks = destinations.iter().enumerate();
+        let order = chunks.flat_map(|(chunk_index, chunk)| {
+            let seed = task.get_seed().wrapping_add(chunk_index as u64);
+            Permutation::new(chunk.len(), seed).map(move |index| chunk[index].clone())
+        });
+        for ip in order {
+            if cancelled_tasks.lock().unwrap().contains(&task.get_task_id()) {
+                info!(
+                    "task {} cancelled after {} destinations",
+                    task.get_task_id(),
+                    destinations_sent
+                );
+                break;
+            }
 
-            // Get the current time
-            payload.set_transmit_time(current_timestamp());
+            // Let the server know how far along we are
+            if last_progress.elapsed() >= Duration::from_secs(PROGRESS_INTERVAL) {
+                PingOutbound::report_progress(grpc_client, task.get_task_id(), destinations_sent, rate);
+                last_progress = Instant::now();
+            }
+            destinations_sent += 1;
 
-            let bindaddress = format!("{}:0", Ipv4Addr::from(ip.get_v4()).to_string());
-            // Todo: make the secret configurable
-            let icmp =
-                ICMP4Packet::echo_request(1, 2, payload.to_signed_bytes("test-secret").unwrap());
+            let destination_address = IpAddr::from(&ip);
+            if source_address.is_ipv4() != destination_address.is_ipv4() {
+                warn!(
+                    "skipping {}, address family does not match source {}",
+                    destination_address, source_address
+                );
+                PACKETS_TRANSMITTED_ERROR.inc();
+                continue;
+            }
+
+            let packet = if task.has_tcp_syn() {
+                // The reply does not carry a payload, the probe is encoded in the
+                // sequence number and source port instead
+                let sequence_number = tcp_syn::sequence_number(task.get_task_id(), current_timestamp());
+                TCPPacket::syn(
+                    source_address,
+                    destination_address,
+                    tcp_syn::source_port(key.get_secret(), &destination_address, sequence_number),
+                    task.get_tcp_syn().get_destination_port() as u16,
+                    sequence_number,
+                )
+            } else {
+                // Create payload that will be transmitted inside the ICMP echo request
+                // or the DNS query name
+                let mut payload = PingPayload::new();
+                payload.set_source_address(source.clone());
+                payload.set_destination_address(ip.clone());
+                payload.set_task_id(task.get_task_id());
+                payload.set_key_id(key.get_key_id());
+
+                // Get the current time
+                payload.set_transmit_time(current_timestamp());
+
+                let body = payload.to_signed_bytes(key.get_secret()).unwrap();
+                if task.has_dns() {
+                    let name = udp_dns::encode_query_name(&body, task.get_dns().get_query_domain());
+                    UDPPacket::datagram(
+                        source_address,
+                        destination_address,
+                        udp_dns::SOURCE_PORT,
+                        udp_dns::DNS_PORT,
+                        udp_dns::query(task.get_task_id() as u16, &name),
+                    )
+                } else {
+                    match (source_address, destination_address) {
+                        (IpAddr::V6(source), IpAddr::V6(destination)) => {
+                            ICMP6Packet::echo_request(1, 2, body, source, destination)
+                        }
+                        _ => ICMP4Packet::echo_request(1, 2, body),
+                    }
+                }
+            };
 
             // Rate limiting
             while let Err(_) = lb.check() {
@@ -160,27 +339,40 @@ impl PingOutbound {
                 //thread::sleep(v.wait_time_from(Instant::now()));
             }
 
-            if let Err(e) = socket.send_to(
-                &icmp,
-                &bindaddress
-                    .to_string()
-                    .parse::<SocketAddr>()
-                    .unwrap()
-                    .into(),
-            ) {
+            if let Err(e) = socket.send_to(&packet, destination_address) {
                 error!("Failed to send packet to socket: {:?}", e);
                 PACKETS_TRANSMITTED_ERROR.inc();
             } else {
                 PACKETS_TRANSMITTED_OK.inc();
             }
         }
+        PingOutbound::report_progress(grpc_client, task.get_task_id(), destinations_sent, rate);
         debug!("finished ping");
     }
 
+    fn report_progress(
+        grpc_client: &VerfploeterClient,
+        task_id: u32,
+        destinations_sent: u32,
+        rate: u32,
+    ) {
+        let mut progress = TaskProgress::new();
+        progress.set_task_id(task_id);
+        progress.set_destinations_sent(destinations_sent);
+        progress.set_rate(rate);
+        if let Err(e) = grpc_client.task_progress_opt(&progress, crate::call_option()) {
+            warn!("unable to report progress of task {}: {}", task_id, e);
+        }
+    }
+
     fn start_ping_thread(
         grpc_client: Arc<VerfploeterClient>,
         outbound_mutex: Arc<Mutex<u32>>,
+        cancelled_tasks: Arc<Mutex<HashSet<u32>>>,
+        max_rate: u32,
+        packet_io: Arc<dyn PacketIo>,
         task: Task,
+        destinations: mpsc::Receiver<RepeatedField<Address>>,
     ) {
         debug!("PingOutbound::start_ping_thread()");
         thread::spawn({
@@ -190,24 +382,242 @@ impl PingOutbound {
                 // want one outbound ping action going at a given time
                 let guard = outbound_mutex.lock().unwrap();
                 debug!("start pinging (task: {})", task.task_id);
-                PingOutbound::perform_ping(&task);
+                PingOutbound::perform_ping(
+                    &task,
+                    &destinations,
+                    &grpc_client,
+                    &cancelled_tasks,
+                    max_rate,
+                    packet_io.as_ref(),
+                );
                 debug!("stop pinging (task: {})", task.task_id);
                 drop(guard);
 
-                // Wait for a timeout
-                debug!("sleeping for duration to wait for final packets");
-                thread::sleep(Duration::from_secs(10));
-                debug!("slept for duration to wait for final packets");
+                // Wait for the final replies during the grace period of the task, which lasts
+                // until their results have been sent to the server
+                let grace_period = match task.get_grace_period() {
+                    0 => DEFAULT_GRACE_PERIOD,
+                    seconds => (seconds as u64).max(RESULT_FLUSH_INTERVAL),
+                };
+                debug!("waiting {} seconds for final packets", grace_period);
+                thread::sleep(Duration::from_secs(grace_period));
+                debug!("waited for final packets");
+
+                cancelled_tasks.lock().unwrap().remove(&task.task_id);
 
-                // After finishing notify the server that the task is finished
+                // After finishing notify the server that the task is finished, retrying like
+                // the client reconnects while the server cannot be reached
                 let mut task_id = TaskId::new();
                 task_id.task_id = task.task_id;
-                grpc_client
-                    .task_finished(&task_id.clone())
-                    .expect("Could not deliver task finished notification");
+                let mut delay = RECONNECT_DELAY_MIN;
+                loop {
+                    match grpc_client.task_finished_opt(&task_id, crate::call_option()) {
+                        Ok(ack) => {
+                            if !ack.get_error_message().is_empty() {
+                                warn!(
+                                    "server refused to finish task {}: {}",
+                                    task.task_id,
+                                    ack.get_error_message()
+                                );
+                            }
+                            break;
+                        }
+                        Err(e) => {
+                            warn!(
+                                "unable to report task {} finished, retrying in {} seconds: {}",
+                                task.task_id,
+                                delay.as_secs(),
+                                e
+                            );
+                            thread::sleep(delay);
+                            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+                        }
+                    }
+                }
 
                 debug!("finished entire ping process");
             }
         });
     }
 }
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod memory_probes {
+    use super::super::packet_io::memory::MemoryPacketIo;
+    use super::super::packet_io::{Family, PacketSocket};
+    use super::super::ping_inbound::packet_result;
+    use super::super::{result_task_id, KeyMap};
+    use super::*;
+    use crate::net::{IPPacket, IPv4Packet};
+    use crate::schema::verfploeter::{Destinations, Empty, Key, Ping, TcpSyn};
+    use futures::Sink;
+    use grpcio::{ChannelBuilder, Environment};
+    use std::net::Ipv4Addr;
+    use std::sync::RwLock;
+
+    const SECRET: &str = "probe-secret";
+
+    /// A client of a server that is not there, progress reports fail
+    fn grpc_client() -> VerfploeterClient {
+        let env = Arc::new(Environment::new(1));
+        VerfploeterClient::new(ChannelBuilder::new(env).connect("127.0.0.1:1"))
+    }
+
+    fn key() -> Key {
+        let mut key = Key::new();
+        key.set_key_id(1);
+        key.set_secret(SECRET.to_string());
+        key
+    }
+
+    fn keys() -> KeyMap {
+        let mut keys = HashMap::new();
+        keys.insert(1, SECRET.to_string());
+        Arc::new(RwLock::new(keys))
+    }
+
+    /// Probes the destinations in the task from 192.0.2.1
+    fn probe(mut task: Task, destinations: &[Ipv4Addr], packet_io: &dyn PacketIo) {
+        task.set_task_id(7);
+        let (tx, rx) = mpsc::channel();
+        tx.send(destinations.iter().map(|d| Address::from(*d)).collect())
+            .unwrap();
+        drop(tx);
+        PingOutbound::perform_ping(
+            &task,
+            &rx,
+            &grpc_client(),
+            &Mutex::new(HashSet::new()),
+            1000,
+            packet_io,
+        );
+    }
+
+    /// Echo request task from 192.0.2.1, without its destinations
+    fn ping_task(task_id: u32) -> Task {
+        let mut ping = Ping::new();
+        ping.set_source_address(Ipv4Addr::new(192, 0, 2, 1).into());
+        ping.set_key(key());
+        let mut task = Task::new();
+        task.set_task_id(task_id);
+        task.set_ping(ping);
+        task
+    }
+
+    /// Sends tasks to a running outbound handler, like the client does
+    fn handle(tasks: Vec<Task>, packet_io: Arc<MemoryPacketIo>) -> PingOutbound {
+        let mut outbound = PingOutbound::new(Arc::new(grpc_client()), 1000, packet_io);
+        let tx = match outbound.get_channel() {
+            ChannelType::Task { sender, .. } => sender.unwrap(),
+            ChannelType::None => unreachable!(),
+        };
+        outbound.start();
+        for task in tasks {
+            tx.clone().send(task).wait().unwrap();
+        }
+        outbound
+    }
+
+    /// Last chunk of the destinations of task 7
+    fn last_chunk() -> Task {
+        let mut chunk = Destinations::new();
+        chunk.set_addresses(destinations().into_iter().map(Address::from).collect());
+        chunk.set_last(true);
+        let mut task = Task::new();
+        task.set_task_id(7);
+        task.set_destinations(chunk);
+        task
+    }
+
+    fn destinations() -> Vec<Ipv4Addr> {
+        vec![Ipv4Addr::new(198, 51, 100, 1), Ipv4Addr::new(198, 51, 100, 2)]
+    }
+
+    /// Source addresses of the packets received on socket that are results of task 7
+    fn results(socket: &dyn PacketSocket, packets: usize) -> Vec<IpAddr> {
+        let mut buffer = vec![0; 1500];
+        let mut sources = (0..packets)
+            .filter_map(|_| {
+                let (length, _) = socket.recv_from(&mut buffer).unwrap();
+                let packet = IPPacket::V4(IPv4Packet::from(&buffer[..length]));
+                packet_result(&keys(), &packet, current_timestamp())
+            })
+            .filter(|result| result_task_id(result) == 7)
+            .map(|result| {
+                if result.has_tcp() {
+                    IpAddr::from(result.get_tcp().get_source_address())
+                } else {
+                    IpAddr::from(result.get_ping().get_source_address())
+                }
+            })
+            .collect::<Vec<IpAddr>>();
+        sources.sort();
+        sources
+    }
+
+    #[test]
+    fn echo_requests_are_answered() {
+        let packet_io = MemoryPacketIo::default();
+        let inbound = packet_io.open(Family::V4, IpProtocol::Icmp, None).unwrap();
+
+        let mut ping = Ping::new();
+        ping.set_source_address(Ipv4Addr::new(192, 0, 2, 1).into());
+        ping.set_key(key());
+        let mut task = Task::new();
+        task.set_ping(ping);
+        probe(task, &destinations(), &packet_io);
+
+        let expected = destinations().into_iter().map(IpAddr::from).collect::<Vec<_>>();
+        assert_eq!(results(inbound.as_ref(), 2), expected);
+    }
+
+    #[test]
+    fn tcp_syn_probes_are_answered() {
+        let packet_io = MemoryPacketIo::default();
+        let inbound = packet_io.open(Family::V4, IpProtocol::Tcp, None).unwrap();
+
+        let mut tcp_syn = TcpSyn::new();
+        tcp_syn.set_source_address(Ipv4Addr::new(192, 0, 2, 1).into());
+        tcp_syn.set_key(key());
+        tcp_syn.set_destination_port(443);
+        let mut task = Task::new();
+        task.set_tcp_syn(tcp_syn);
+        probe(task, &destinations(), &packet_io);
+
+        let expected = destinations().into_iter().map(IpAddr::from).collect::<Vec<_>>();
+        assert_eq!(results(inbound.as_ref(), 2), expected);
+    }
+
+    #[test]
+    fn lost_uploads_do_not_block_later_tasks() {
+        let packet_io = Arc::new(MemoryPacketIo::default());
+        let inbound = packet_io.open(Family::V4, IpProtocol::Icmp, None).unwrap();
+
+        // The destinations of task 6 never arrive, as the connection to the server is lost
+        let mut reset = Task::new();
+        reset.set_shutdown(Empty::new());
+        let tasks = vec![ping_task(6), reset, ping_task(7), last_chunk()];
+        let mut outbound = handle(tasks, packet_io);
+
+        let expected = destinations().into_iter().map(IpAddr::from).collect::<Vec<_>>();
+        assert_eq!(results(inbound.as_ref(), 2), expected);
+        outbound.exit();
+    }
+
+    #[test]
+    fn cancelled_uploads_do_not_block_later_tasks() {
+        let packet_io = Arc::new(MemoryPacketIo::default());
+        let inbound = packet_io.open(Family::V4, IpProtocol::Icmp, None).unwrap();
+
+        let mut cancel = Task::new();
+        cancel.set_task_id(6);
+        cancel.set_cancel(Empty::new());
+        let tasks = vec![ping_task(6), cancel, ping_task(7), last_chunk()];
+        let mut outbound = handle(tasks, packet_io);
+
+        let expected = destinations().into_iter().map(IpAddr::from).collect::<Vec<_>>();
+        assert_eq!(results(inbound.as_ref(), 2), expected);
+        outbound.exit();
+    }
+}
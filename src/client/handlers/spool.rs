@@ -0,0 +1,210 @@
+//! Spool of results that could not be sent to the server
+//!
+//! Every TaskResult that fails to transmit is written to its own file in the spool
+//! directory, named after a sequence number so the files sort in the order they were
+//! written. As long as the spool is not empty, new results are spooled behind the older
+//! ones, and the spool is replayed in order once the server can be reached again. The
+//! spool survives a restart of the client. When it exceeds its maximum size the oldest
+//! results are dropped.
+
+use crate::schema::verfploeter::TaskResult;
+
+use lazy_static::lazy_static;
+use prometheus::{
+    __register_gauge, opts, register_counter, register_int_counter, register_int_gauge, IntCounter,
+    IntGauge,
+};
+use protobuf::Message;
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Define Prometheus metrics
+lazy_static! {
+    static ref SPOOL_BATCHES: IntGauge = register_int_gauge!(
+        "client_spool_batches",
+        "Number of result batches in the spool"
+    )
+    .unwrap();
+    static ref SPOOL_BYTES: IntGauge =
+        register_int_gauge!("client_spool_bytes", "Size of the spool in bytes").unwrap();
+    static ref SPOOL_RESULTS_DROPPED: IntCounter = register_int_counter!(
+        "client_spool_results_dropped",
+        "Number of results dropped because the spool was full"
+    )
+    .unwrap();
+}
+
+const EXTENSION: &str = "batch";
+
+pub struct Spool {
+    directory: PathBuf,
+    max_bytes: u64,
+    /// Spooled batches, oldest first, with their size in bytes
+    batches: VecDeque<(PathBuf, u64)>,
+    bytes: u64,
+    next_sequence: u64,
+}
+
+impl Spool {
+    /// Opens the spool in directory, creating it if needed, picking up the batches
+    /// spooled before a restart
+    pub fn open(directory: &Path, max_bytes: u64) -> io::Result<Spool> {
+        fs::create_dir_all(directory)?;
+        let mut batches = Vec::new();
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+            let sequence = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse::<u64>().ok());
+            if let Some(sequence) = sequence {
+                let size = fs::metadata(&path)?.len();
+                batches.push((sequence, path, size));
+            }
+        }
+        batches.sort_by_key(|(sequence, _, _)| *sequence);
+
+        let spool = Spool {
+            directory: directory.to_path_buf(),
+            max_bytes,
+            next_sequence: batches.last().map_or(0, |(sequence, _, _)| sequence + 1),
+            bytes: batches.iter().map(|(_, _, size)| size).sum(),
+            batches: batches
+                .into_iter()
+                .map(|(_, path, size)| (path, size))
+                .collect(),
+        };
+        if !spool.is_empty() {
+            info!(
+                "found {} spooled result batches in {}",
+                spool.batches.len(),
+                directory.display()
+            );
+        }
+        spool.update_metrics();
+        Ok(spool)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.batches.is_empty()
+    }
+
+    /// Appends a batch, dropping the oldest batches if the spool becomes too large
+    pub fn push(&mut self, task_result: &TaskResult) -> io::Result<()> {
+        let bytes = task_result
+            .write_to_bytes()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let path = self
+            .directory
+            .join(format!("{:020}.{}", self.next_sequence, EXTENSION));
+        fs::write(&path, &bytes)?;
+        self.next_sequence += 1;
+        self.batches.push_back((path, bytes.len() as u64));
+        self.bytes += bytes.len() as u64;
+
+        while self.bytes > self.max_bytes && self.batches.len() > 1 {
+            if let Some(oldest) = self.front()? {
+                warn!(
+                    "spool is full, dropping {} results of task {}",
+                    oldest.get_result_list().len(),
+                    oldest.get_task_id()
+                );
+                SPOOL_RESULTS_DROPPED.inc_by(oldest.get_result_list().len() as i64);
+            }
+            self.pop()?;
+        }
+        self.update_metrics();
+        Ok(())
+    }
+
+    /// The oldest batch, or None if the spool is empty. A batch that cannot be read is
+    /// removed from the spool.
+    pub fn front(&mut self) -> io::Result<Option<TaskResult>> {
+        while let Some(path) = self.batches.front().map(|(path, _)| path.clone()) {
+            let bytes = fs::read(&path)?;
+            match protobuf::parse_from_bytes::<TaskResult>(&bytes) {
+                Ok(task_result) => return Ok(Some(task_result)),
+                Err(e) => {
+                    error!("removing unreadable spool file {}: {}", path.display(), e);
+                    self.pop()?;
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Removes the oldest batch
+    pub fn pop(&mut self) -> io::Result<()> {
+        if let Some((path, size)) = self.batches.pop_front() {
+            self.bytes -= size;
+            self.update_metrics();
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+
+    fn update_metrics(&self) {
+        SPOOL_BATCHES.set(self.batches.len() as i64);
+        SPOOL_BYTES.set(self.bytes as i64);
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod result_spool {
+    use super::*;
+
+    fn task_result(task_id: u32) -> TaskResult {
+        let mut task_result = TaskResult::new();
+        task_result.set_task_id(task_id);
+        task_result
+    }
+
+    fn spool_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("spool-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&directory).ok();
+        directory
+    }
+
+    #[test]
+    fn batches_are_replayed_in_order_after_restart() {
+        let directory = spool_directory("order");
+        let mut spool = Spool::open(&directory, 1 << 20).unwrap();
+        for task_id in 1..=3 {
+            spool.push(&task_result(task_id)).unwrap();
+        }
+        assert_eq!(spool.front().unwrap().unwrap().get_task_id(), 1);
+        spool.pop().unwrap();
+        drop(spool);
+
+        let mut spool = Spool::open(&directory, 1 << 20).unwrap();
+        spool.push(&task_result(4)).unwrap();
+        let mut replayed = Vec::new();
+        while let Some(task_result) = spool.front().unwrap() {
+            replayed.push(task_result.get_task_id());
+            spool.pop().unwrap();
+        }
+        assert_eq!(replayed, vec![2, 3, 4]);
+        assert!(spool.is_empty());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn oldest_batches_are_dropped_when_full() {
+        let directory = spool_directory("full");
+        let size = task_result(1).write_to_bytes().unwrap().len() as u64;
+        let mut spool = Spool::open(&directory, 2 * size).unwrap();
+        for task_id in 1..=3 {
+            spool.push(&task_result(task_id)).unwrap();
+        }
+        assert_eq!(spool.batches.len(), 2);
+        assert_eq!(spool.front().unwrap().unwrap().get_task_id(), 2);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,79 @@
+//! Encoding of TCP SYN probes
+//!
+//! TCP has no body that is echoed back like the ICMP echo payload, the only values the
+//! reply (SYN-ACK or RST) carries back are the sequence number (as acknowledgement
+//! number + 1) and the ports. The sequence number holds the lower 16 bits of the task id
+//! and the lower 16 bits of the transmit time in milliseconds, which is enough for round
+//! trip times up to 65 seconds. The source port is derived from a keyed hash over the
+//! destination address and sequence number, so replies can be told apart from other
+//! TCP traffic.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::net::IpAddr;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Sequence number of a probe, transmit time is in nanoseconds
+pub fn sequence_number(task_id: u32, transmit_time: u64) -> u32 {
+    ((task_id & 0xFFFF) << 16) | ((transmit_time / 1_000_000) & 0xFFFF) as u32
+}
+
+/// Returns the (16 bits) task id and the transmit time of a probe, reconstructed
+/// from the time its reply was received
+pub fn decode_sequence_number(sequence_number: u32, receive_time: u64) -> (u32, u64) {
+    let task_id = sequence_number >> 16;
+    let transmit_ms = u64::from(sequence_number & 0xFFFF);
+    let receive_ms = receive_time / 1_000_000;
+    let elapsed_ms = receive_ms.wrapping_sub(transmit_ms) & 0xFFFF;
+    (task_id, (receive_ms - elapsed_ms) * 1_000_000)
+}
+
+/// Source port of a probe, always in the dynamic port range
+pub fn source_port(secret: &str, destination: &IpAddr, sequence_number: u32) -> u16 {
+    let mut mac = HmacSha256::new_varkey(secret.as_bytes()).expect("HMAC accepts any key length");
+    match destination {
+        IpAddr::V4(address) => mac.input(&address.octets()),
+        IpAddr::V6(address) => mac.input(&address.octets()),
+    }
+    mac.input(&sequence_number.to_be_bytes());
+    let code = mac.result().code();
+    0x8000 | (u16::from(code[0]) << 8) | u16::from(code[1])
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod sequence_numbers {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn transmit_time_is_reconstructed() {
+        let transmit_time = 1_554_000_123_456_789_000;
+        let seq = sequence_number(42, transmit_time);
+
+        let (task_id, decoded) = decode_sequence_number(seq, transmit_time + 250_000_000);
+        assert_eq!(task_id, 42);
+        assert_eq!(decoded, transmit_time / 1_000_000 * 1_000_000);
+    }
+
+    #[test]
+    fn transmit_time_is_reconstructed_across_wrap() {
+        // Transmit time just below a multiple of 65536 ms, received after it
+        let transmit_time = (65_536 * 1_000 - 10) * 1_000_000;
+        let seq = sequence_number(1, transmit_time);
+
+        let (_, decoded) = decode_sequence_number(seq, transmit_time + 20_000_000);
+        assert_eq!(decoded, transmit_time);
+    }
+
+    #[test]
+    fn source_port_depends_on_secret() {
+        let destination = IpAddr::from_str("192.0.2.1").unwrap();
+        let port = source_port("abc123", &destination, 1234);
+
+        assert!(port >= 0x8000);
+        assert_eq!(port, source_port("abc123", &destination, 1234));
+        assert_ne!(port, source_port("abc124", &destination, 1234));
+    }
+}
@@ -0,0 +1,142 @@
+//! Encoding of DNS probes
+//!
+//! A DNS probe is an A query for `<payload>.<query domain>`, where the payload is the
+//! signed PingPayload of the probe, hex encoded and split into labels. Resolvers and
+//! authoritative servers copy the question into their response, so the payload comes
+//! back just like with an ICMP echo request, whatever the response itself contains.
+
+use std::io::Cursor;
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+
+/// Source port of the queries, responses are recognized by being sent to this port
+pub const SOURCE_PORT: u16 = 61_853;
+pub const DNS_PORT: u16 = 53;
+
+/// Labels are at most 63 characters, keep the hex digits of a byte in the same label
+const MAX_LABEL_LENGTH: usize = 62;
+
+/// Creates a query (recursion desired) for the A record of name
+pub fn query(id: u16, name: &str) -> Vec<u8> {
+    let mut wtr = vec![];
+    wtr.write_u16::<NetworkEndian>(id).unwrap();
+    wtr.write_u16::<NetworkEndian>(0x0100).unwrap(); // Standard query, recursion desired
+    wtr.write_u16::<NetworkEndian>(1).unwrap(); // One question
+    wtr.write_u16::<NetworkEndian>(0).unwrap(); // No answer,
+    wtr.write_u16::<NetworkEndian>(0).unwrap(); // authority,
+    wtr.write_u16::<NetworkEndian>(0).unwrap(); // or additional records
+    for label in name.split('.').filter(|l| !l.is_empty()) {
+        wtr.write_u8(label.len() as u8).unwrap();
+        wtr.extend_from_slice(label.as_bytes());
+    }
+    wtr.write_u8(0).unwrap();
+    wtr.write_u16::<NetworkEndian>(1).unwrap(); // Type A
+    wtr.write_u16::<NetworkEndian>(1).unwrap(); // Class IN
+    wtr
+}
+
+/// The query id, name of the first question and response code of a DNS response,
+/// or None if the message is not a response
+pub fn parse_response(message: &[u8]) -> Option<(u16, String, u8)> {
+    let mut cursor = Cursor::new(message);
+    let id = cursor.read_u16::<NetworkEndian>().ok()?;
+    let flags = cursor.read_u16::<NetworkEndian>().ok()?;
+    let question_count = cursor.read_u16::<NetworkEndian>().ok()?;
+    if flags & 0x8000 == 0 || question_count == 0 {
+        return None;
+    }
+
+    cursor.set_position(12);
+    let mut labels = Vec::new();
+    loop {
+        let length = cursor.read_u8().ok()? as usize;
+        if length == 0 {
+            break;
+        }
+        // Compression pointers do not occur in the first question
+        if length > 63 {
+            return None;
+        }
+        let start = cursor.position() as usize;
+        let label = message.get(start..start + length)?;
+        labels.push(String::from_utf8(label.to_vec()).ok()?);
+        cursor.set_position((start + length) as u64);
+    }
+    Some((id, labels.join("."), (flags & 0x000F) as u8))
+}
+
+/// Query name carrying the payload
+pub fn encode_query_name(payload: &[u8], query_domain: &str) -> String {
+    let hex = payload
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    let mut labels = hex
+        .as_bytes()
+        .chunks(MAX_LABEL_LENGTH)
+        .map(|c| String::from_utf8(c.to_vec()).unwrap())
+        .collect::<Vec<String>>();
+    labels.push(query_domain.trim_matches('.').to_string());
+    labels.join(".")
+}
+
+/// Possible payloads carried in a query name, one for every number of leading hex labels.
+/// Responses arrive at the site in whose catchment the resolver is, which does not know the
+/// query domain of the task, so the signature tells which candidate is the payload.
+pub fn decode_query_name(name: &str) -> Vec<Vec<u8>> {
+    let mut candidates = Vec::new();
+    // Resolvers may randomize the case of the name they forward
+    let mut hex = String::new();
+    for label in name.to_lowercase().split('.') {
+        if label.is_empty() || label.len() % 2 != 0 {
+            break;
+        }
+        hex.push_str(label);
+        let payload = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<u8>>>();
+        match payload {
+            Some(payload) => candidates.push(payload),
+            None => break,
+        }
+    }
+    candidates
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod dns_messages {
+    use super::*;
+
+    #[test]
+    fn query_name_round_trip() {
+        let payload = (0..80).collect::<Vec<u8>>();
+        let name = encode_query_name(&payload, "probe.example.");
+
+        assert!(name.split('.').all(|l| l.len() <= 63));
+        assert!(name.ends_with(".probe.example"));
+        assert_eq!(decode_query_name(&name).last(), Some(&payload));
+        assert_eq!(decode_query_name(&name.to_uppercase()).last(), Some(&payload));
+        assert_eq!(decode_query_name(&name).len(), 3);
+
+        // A domain that looks like hex yields an extra candidate
+        let name = encode_query_name(&payload, "cafe.example");
+        assert_eq!(decode_query_name(&name)[2], payload);
+        assert_eq!(decode_query_name(&name).len(), 4);
+    }
+
+    #[test]
+    fn response_question_is_parsed() {
+        let mut response = query(4321, "abc.probe.example");
+        response[2] |= 0x80; // Response
+        response[3] |= 0x03; // NXDOMAIN
+
+        assert_eq!(
+            parse_response(&response),
+            Some((4321, "abc.probe.example".to_string(), 3))
+        );
+        assert_eq!(parse_response(&query(1, "abc.probe.example")), None);
+        assert_eq!(parse_response(&response[..14]), None);
+    }
+}
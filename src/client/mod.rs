@@ -1,70 +1,147 @@
 //#![allow(unused_imports)]
 
-use super::schema::verfploeter::{Metadata, Task};
+use super::schema::verfploeter::{Empty, Metadata, Task};
 use super::schema::verfploeter_grpc::VerfploeterClient;
 
 use futures::sync::mpsc::{Receiver, Sender};
-use futures::sync::oneshot;
 use futures::*;
-use grpcio::{ChannelBuilder, Environment};
+use grpcio::{ChannelBuilder, Environment, RpcStatusCode};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
 
+mod clock;
 mod handlers;
+mod replay;
+use self::handlers::dns_inbound::DnsInbound;
+use self::handlers::packet_io::{PacketIo, RawPacketIo};
+pub use self::handlers::pcap::PcapMode;
+use self::handlers::pcap::PcapWriter;
 use self::handlers::ping_inbound::PingInbound;
 use self::handlers::ping_outbound::PingOutbound;
-use self::handlers::{ChannelType, TaskHandler};
+use self::handlers::spool::Spool;
+use self::handlers::{ChannelType, KeyMap, TaskHandler};
+pub use self::replay::{replay, ReplayConfig};
 use grpcio::ChannelCredentialsBuilder;
+use std::thread;
 use std::time::Duration;
-use tokio::runtime::Runtime;
+
+/// Delay before reconnecting to the server, doubled after every failed attempt
+const RECONNECT_DELAY_MIN: Duration = Duration::from_secs(1);
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(64);
 
 pub struct Client {
     grpc_client: Arc<VerfploeterClient>,
     task_handlers: HashMap<String, Box<dyn TaskHandler>>,
     metadata: Metadata,
-    runtime: Arc<Runtime>,
+    keys: KeyMap,
 }
 
 pub struct ClientConfig<'a> {
     pub grpc_host: &'a str,
     pub client_hostname: &'a str,
+    /// Identity of the client, registered with the server and recorded with every result
+    pub client_id: &'a str,
+    pub site: &'a str,
+    pub location: &'a str,
+    pub announced_prefix: &'a str,
     pub certificate: Option<Vec<u8>>,
+    /// Certificate and private key presented to the server
+    pub identity: Option<(Vec<u8>, Vec<u8>)>,
+    pub max_rate: u32,
+    /// Directory and maximum size in bytes of the spool of results that could not be sent
+    pub spool_directory: PathBuf,
+    pub spool_size: u64,
+    /// Directory to record received packets in, and how to split them over files
+    pub pcap: Option<(PathBuf, PcapMode)>,
 }
 
 impl Client {
     pub fn new(config: &ClientConfig) -> Client {
         debug!("Client::new()");
-        // Setup GRPC client
-        let grpc_client = if config.certificate.is_some() {
-            Client::create_secure_grpc_client(config.grpc_host, config.certificate.clone().unwrap())
-        } else {
-            Client::create_insecure_grpc_client(config.grpc_host)
-        };
+        let grpc_client = Client::create_grpc_client(config);
+        let metadata = Client::create_metadata(config);
 
-        // Setup metadata
-        let mut metadata = Metadata::new();
-        metadata.set_hostname(config.client_hostname.to_string());
-        metadata.set_version(env!("CARGO_PKG_VERSION").to_string());
+        // Keys are received from the server after connecting
+        let keys: KeyMap = Arc::new(RwLock::new(HashMap::new()));
+        let spool = Arc::new(Mutex::new(
+            Spool::open(&config.spool_directory, config.spool_size)
+                .expect("Unable to open spool directory"),
+        ));
+        let pcap = config.pcap.as_ref().map(|(directory, mode)| {
+            PcapWriter::new(directory, *mode).expect("Unable to create pcap directory")
+        });
 
         // Setup task_handlers
+        let packet_io: Arc<dyn PacketIo> = Arc::new(RawPacketIo);
         let mut task_handlers: HashMap<String, Box<dyn TaskHandler>> = HashMap::new();
         task_handlers.insert(
             "ping_outbound".to_string(),
-            Box::new(PingOutbound::new(grpc_client.clone())),
+            Box::new(PingOutbound::new(
+                grpc_client.clone(),
+                config.max_rate,
+                packet_io.clone(),
+            )),
         );
         task_handlers.insert(
             "ping_inbound".to_string(),
-            Box::new(PingInbound::new(metadata.clone(), grpc_client.clone())),
+            Box::new(
+                PingInbound::new(
+                    metadata.clone(),
+                    grpc_client.clone(),
+                    keys.clone(),
+                    spool.clone(),
+                    pcap,
+                    packet_io.as_ref(),
+                )
+                .expect("Unable to open raw sockets, which requires root or CAP_NET_RAW"),
+            ),
+        );
+        task_handlers.insert(
+            "dns_inbound".to_string(),
+            Box::new(
+                DnsInbound::new(
+                    metadata.clone(),
+                    grpc_client.clone(),
+                    keys.clone(),
+                    spool,
+                    packet_io.as_ref(),
+                )
+                .expect("Unable to open raw sockets, which requires root or CAP_NET_RAW"),
+            ),
         );
 
         Client {
             grpc_client,
             task_handlers,
             metadata,
-            runtime: Arc::new(Runtime::new().unwrap()),
+            keys,
         }
     }
 
+    fn create_grpc_client(config: &ClientConfig) -> Arc<VerfploeterClient> {
+        if config.certificate.is_some() {
+            Client::create_secure_grpc_client(
+                config.grpc_host,
+                config.certificate.clone().unwrap(),
+                config.identity.clone(),
+            )
+        } else {
+            Client::create_insecure_grpc_client(config.grpc_host)
+        }
+    }
+
+    fn create_metadata(config: &ClientConfig) -> Metadata {
+        let mut metadata = Metadata::new();
+        metadata.set_hostname(config.client_hostname.to_string());
+        metadata.set_client_id(config.client_id.to_string());
+        metadata.set_site(config.site.to_string());
+        metadata.set_location(config.location.to_string());
+        metadata.set_announced_prefix(config.announced_prefix.to_string());
+        metadata.set_version(env!("CARGO_PKG_VERSION").to_string());
+        metadata
+    }
+
     fn create_grpc_channel_builder() -> ChannelBuilder {
         debug!("Client::create_grpc_channel_builder()");
         let env = Arc::new(Environment::new(1));
@@ -72,17 +149,21 @@ impl Client {
         ChannelBuilder::new(env)
             .keepalive_time(Duration::from_secs(180))
             .keepalive_timeout(Duration::from_secs(180))
-            .max_send_message_len(100 * 1024 * 1024)
-            .max_receive_message_len(100 * 1024 * 1024)
     }
 
-    fn create_secure_grpc_client(host: &str, certificate: Vec<u8>) -> Arc<VerfploeterClient> {
+    fn create_secure_grpc_client(
+        host: &str,
+        certificate: Vec<u8>,
+        identity: Option<(Vec<u8>, Vec<u8>)>,
+    ) -> Arc<VerfploeterClient> {
         debug!("Client::create_secure_grpc_client()");
         info!("attempting to connect to server using a secure connection");
         // Setup credentials
-        let credentials = ChannelCredentialsBuilder::new()
-            .root_cert(certificate)
-            .build();
+        let mut credentials = ChannelCredentialsBuilder::new().root_cert(certificate);
+        if let Some((client_certificate, client_key)) = identity {
+            credentials = credentials.cert(client_certificate, client_key);
+        }
+        let credentials = credentials.build();
 
         // Create the channel (with all its parameters)
         let channel = Client::create_grpc_channel_builder().secure_connect(host, credentials);
@@ -101,62 +182,114 @@ impl Client {
 
     pub fn start(mut self) {
         debug!("Client::start()");
-        let res = self.grpc_client.connect(&self.metadata);
-        if let Ok(stream) = res {
-            // Get tx channel for ping_outbound
-            let tx = match self
-                .task_handlers
-                .get_mut("ping_outbound")
-                .unwrap()
-                .get_channel()
-            {
-                ChannelType::Task { sender, .. } => sender.unwrap(),
-                _ => panic!("ping_outbound has wrong tx channel type"),
-            };
+        // Get tx channel for ping_outbound
+        let tx = match self
+            .task_handlers
+            .get_mut("ping_outbound")
+            .unwrap()
+            .get_channel()
+        {
+            ChannelType::Task { sender, .. } => sender.unwrap(),
+            _ => panic!("ping_outbound has wrong tx channel type"),
+        };
+
+        // Start all task handlers, they keep running while the client reconnects, so
+        // replies are still captured and queued until the server is back
+        for (i, v) in &mut self.task_handlers {
+            v.start();
+            debug!("started {} task handler", i);
+        }
+        clock::start_sync(
+            self.grpc_client.clone(),
+            self.metadata.get_client_id().to_string(),
+        );
+
+        let mut delay = RECONNECT_DELAY_MIN;
+        loop {
+            let received = self.receive_tasks(&tx);
+            // Probing threads waiting for the destinations of a task would otherwise wait
+            // forever, blocking all later tasks
+            let mut reset = Task::new();
+            reset.set_shutdown(Empty::new());
+            tx.clone().send(reset).wait().unwrap();
+            match received {
+                Ok(true) => delay = RECONNECT_DELAY_MIN,
+                Ok(false) => {}
+                Err(e) => {
+                    error!("server refused this client: {}", e);
+                    break;
+                }
+            }
+            warn!(
+                "connection to server lost, reconnecting in {} seconds",
+                delay.as_secs()
+            );
+            thread::sleep(delay);
+            delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+        }
+
+        // Stop all task handlers
+        for (i, v) in &mut self.task_handlers {
+            debug!("signaling {} to exit", i);
+            v.exit();
+            debug!("exited {} task handler", i);
+        }
+        warn!("finished cleanup");
+    }
 
-            // Signal finish
-            let (finish_tx, finish_rx) = oneshot::channel();
-
-            // For now we only have a ping task, in the future we can have a match here
-            // that sends tasks to different threads for processing
-            let f = stream
-                .for_each({
-                    let tx = tx.clone();
-                    move |i| {
-                        if i.has_ping() {
-                            debug!("got ping task");
-                            tx.clone().send(i).wait().unwrap();
-                            debug!("sent to handler");
-                        }
-                        futures::future::ok(())
-                    }
-                })
-                .map(|_| {
-                    warn!("Task forwarder future (map)");
-                })
-                .map_err(|e| {
-                    warn!("Task forwarder future (map_err): {}", e);
-                })
-                .and_then(|_| finish_tx.send(()));
-
-            self.runtime.executor().spawn(f);
-
-            // Start all task handlers
-            for (i, v) in &mut self.task_handlers {
-                v.start();
-                debug!("started {} task handler", i);
+    /// Connects to the server and handles the tasks it sends until the connection ends,
+    /// returns whether anything was received from the server, or an error if the server
+    /// refuses this client, in which case reconnecting does not help
+    fn receive_tasks(&self, tx: &Sender<Task>) -> Result<bool, String> {
+        let stream = match self.grpc_client.connect_opt(&self.metadata, crate::call_option()) {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("unable to connect to server: {}", e);
+                return Ok(false);
             }
+        };
 
-            // Wait for process to finish
-            finish_rx.wait().ok();
+        let mut received = false;
+        for task in stream.wait() {
+            let i = match task {
+                Ok(i) => i,
+                Err(grpcio::Error::RpcFailure(ref status))
+                    if status.status == RpcStatusCode::Unauthenticated
+                        || status.status == RpcStatusCode::PermissionDenied =>
+                {
+                    return Err(status.details.clone().unwrap_or_default());
+                }
+                Err(e) => {
+                    warn!("task stream failed: {}", e);
+                    break;
+                }
+            };
+            if !received {
+                info!("connected to server");
+                received = true;
+            }
 
-            // Stop all task handlers
-            for (i, v) in &mut self.task_handlers {
-                debug!("signaling {} to exit", i);
-                v.exit();
-                debug!("exited {} task handler", i);
+            if i.has_keys() {
+                debug!("got {} keys", i.get_keys().get_keys().len());
+                let mut keys = self.keys.write().unwrap();
+                keys.clear();
+                for key in i.get_keys().get_keys() {
+                    keys.insert(key.get_key_id(), key.get_secret().to_string());
+                }
+            } else if i.has_ping()
+                || i.has_tcp_syn()
+                || i.has_dns()
+                || i.has_destinations()
+                || i.has_cancel()
+            {
+                debug!("got probing task");
+                tx.clone().send(i).wait().unwrap();
+                debug!("sent to handler");
+            } else if i.has_shutdown() {
+                // Running tasks continue, their results are spooled until the server is back
+                info!("server is shutting down");
             }
-            warn!("finished cleanup");
         }
+        Ok(received)
     }
 }
@@ -0,0 +1,227 @@
+//! Offline replay of captured packets through the inbound pipeline
+//!
+//! The IPv4 packets of a pcap file (as recorded with `--pcap`, or captured with tcpdump) are
+//! parsed and verified the way PingInbound does it for packets received from its sockets,
+//! with the capture time as receive time. The results are printed like the results command
+//! does, or sent to the server as results of this client, as long as their task has not
+//! finished. This re-processes old captures, e.g. after a parsing bug was fixed, and runs the
+//! inbound pipeline without root or a live network.
+
+use super::handlers::pcap::PcapReader;
+use super::handlers::ping_inbound::{invalid_reply_task_id, ipv4_packet, packet_result};
+use super::handlers::{task_results, KeyMap, ResultQueue};
+use super::{Client, ClientConfig};
+use crate::cli::print_task_results;
+use crate::schema::verfploeter::TaskResult;
+use crate::server::keys::load_keys;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// Maximum number of results sent to the server in one message
+const SEND_CHUNK_SIZE: usize = 10_000;
+
+pub struct ReplayConfig<'a> {
+    pub pcap: &'a Path,
+    /// File with the keys the payloads were signed with, in the format of the server key file
+    pub key_file: Option<&'a Path>,
+    /// Send the results to the server instead of printing them
+    pub send: bool,
+    pub json: bool,
+}
+
+/// Number of packets replayed, and of those that carried a result or an invalid payload
+#[derive(Debug, Default, PartialEq)]
+struct ReplayCounts {
+    packets: usize,
+    results: usize,
+    invalid_replies: usize,
+}
+
+pub fn replay(config: &ClientConfig, replay: &ReplayConfig) -> Result<(), String> {
+    debug!("replay()");
+    let keys = load_keys(replay.key_file)?;
+    let keys: KeyMap = Arc::new(RwLock::new(
+        keys.get_keys()
+            .iter()
+            .map(|key| (key.get_key_id(), key.get_secret().to_string()))
+            .collect::<HashMap<u32, String>>(),
+    ));
+
+    let reader = PcapReader::open(replay.pcap)
+        .map_err(|e| format!("unable to open {}: {}", replay.pcap.display(), e))?;
+    let mut queue = ResultQueue::default();
+    let counts = replay_packets(&keys, reader, &mut queue)
+        .map_err(|e| format!("unable to read {}: {}", replay.pcap.display(), e))?;
+    info!(
+        "replayed {} packets: {} results, {} invalid replies",
+        counts.packets, counts.results, counts.invalid_replies
+    );
+
+    let task_results = task_results(&Client::create_metadata(config), queue);
+    if !replay.send {
+        print_task_results(replay.json, &task_results);
+        return Ok(());
+    }
+
+    let grpc_client = Client::create_grpc_client(config);
+    for tr in task_results.into_iter().flat_map(split_task_result) {
+        let ack = grpc_client
+            .send_result_opt(&tr, crate::call_option())
+            .map_err(|e| format!("failed to send results of task {}: {}", tr.get_task_id(), e))?;
+        // The server refuses results of tasks that have finished
+        if !ack.get_success() {
+            return Err(format!(
+                "server refused results of task {}: {}",
+                tr.get_task_id(),
+                ack.get_error_message()
+            ));
+        }
+        info!(
+            "sent {} results of task {} to server",
+            tr.get_result_list().len(),
+            tr.get_task_id()
+        );
+    }
+    Ok(())
+}
+
+/// Feeds the packets through the inbound pipeline, queueing the results
+fn replay_packets<I>(keys: &KeyMap, packets: I, queue: &mut ResultQueue) -> io::Result<ReplayCounts>
+where
+    I: Iterator<Item = io::Result<(u64, Vec<u8>)>>,
+{
+    let mut counts = ReplayCounts::default();
+    for packet in packets {
+        let (receive_time, bytes) = packet?;
+        counts.packets += 1;
+        let packet = match ipv4_packet(&bytes) {
+            Some(packet) => packet,
+            None => continue,
+        };
+        match packet_result(keys, &packet, receive_time) {
+            Some(result) => {
+                counts.results += 1;
+                queue.push(result);
+            }
+            None => {
+                if let Some(task_id) = invalid_reply_task_id(keys, &packet) {
+                    counts.invalid_replies += 1;
+                    queue.add_invalid_reply(task_id);
+                }
+            }
+        }
+    }
+    Ok(counts)
+}
+
+/// Splits a task result in messages of at most SEND_CHUNK_SIZE results, the invalid replies
+/// are counted in the first one
+fn split_task_result(mut tr: TaskResult) -> Vec<TaskResult> {
+    let results = tr.take_result_list().into_vec();
+    if results.is_empty() {
+        return vec![tr];
+    }
+    results
+        .chunks(SEND_CHUNK_SIZE)
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let mut part = tr.clone();
+            if idx > 0 {
+                part.set_invalid_replies(0);
+            }
+            part.set_result_list(chunk.to_vec().into());
+            part
+        })
+        .collect()
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod captured_packets {
+    use super::*;
+    use crate::net::ICMP4Packet;
+    use crate::schema::verfploeter::{Metadata, PingPayload};
+    use crate::schema::Signable;
+    use std::net::{IpAddr, Ipv4Addr};
+
+    const SECRET: &str = "replay-secret";
+
+    /// An echo reply from 192.0.2.1 to 198.51.100.1, to a probe of task_id signed with secret
+    fn echo_reply(task_id: u32, secret: &str) -> Vec<u8> {
+        let mut payload = PingPayload::new();
+        payload.set_source_address(Ipv4Addr::new(198, 51, 100, 1).into());
+        payload.set_destination_address(Ipv4Addr::new(192, 0, 2, 1).into());
+        payload.set_task_id(task_id);
+        payload.set_key_id(1);
+        payload.set_transmit_time(1_000);
+        let mut icmp = ICMP4Packet::echo_request(1, 2, payload.to_signed_bytes(secret).unwrap());
+        icmp[0] = 0;
+
+        let mut packet = vec![0x45, 0, 0, 0, 0, 0, 0, 0, 57, 1, 0, 0];
+        packet.extend_from_slice(&[192, 0, 2, 1, 198, 51, 100, 1]);
+        packet.extend(icmp);
+        packet
+    }
+
+    fn keys() -> KeyMap {
+        let mut keys = HashMap::new();
+        keys.insert(1, SECRET.to_string());
+        Arc::new(RwLock::new(keys))
+    }
+
+    #[test]
+    fn captured_replies_become_results() {
+        let mut truncated = echo_reply(5, SECRET);
+        truncated.truncate(24);
+        let packets = vec![
+            Ok((2_000, echo_reply(5, SECRET))),
+            Ok((3_000, echo_reply(5, "other-secret"))),
+            Ok((4_000, truncated)),
+            Ok((5_000, echo_reply(6, SECRET))),
+        ];
+
+        let mut queue = ResultQueue::default();
+        let counts = replay_packets(&keys(), packets.into_iter(), &mut queue).unwrap();
+        assert_eq!(
+            counts,
+            ReplayCounts {
+                packets: 4,
+                results: 2,
+                invalid_replies: 1,
+            }
+        );
+
+        let task_results = task_results(&Metadata::new(), queue);
+        assert_eq!(task_results.len(), 2);
+        assert_eq!(task_results[0].get_task_id(), 5);
+        assert_eq!(task_results[0].get_invalid_replies(), 1);
+        let ping = task_results[0].get_result_list()[0].get_ping();
+        assert_eq!(ping.get_receive_time(), 2_000);
+        assert_eq!(ping.get_ttl(), 57);
+        assert_eq!(ping.get_payload().get_transmit_time(), 1_000);
+        assert_eq!(
+            IpAddr::from(ping.get_source_address()),
+            IpAddr::from(Ipv4Addr::new(192, 0, 2, 1))
+        );
+        assert_eq!(task_results[1].get_task_id(), 6);
+    }
+
+    #[test]
+    fn large_results_are_split() {
+        let mut tr = TaskResult::new();
+        tr.set_invalid_replies(3);
+        for _ in 0..SEND_CHUNK_SIZE + 1 {
+            tr.mut_result_list().push(Default::default());
+        }
+        let parts = split_task_result(tr);
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].get_result_list().len(), SEND_CHUNK_SIZE);
+        assert_eq!(parts[0].get_invalid_replies(), 3);
+        assert_eq!(parts[1].get_result_list().len(), 1);
+        assert_eq!(parts[1].get_invalid_replies(), 0);
+
+        assert_eq!(split_task_result(TaskResult::new()).len(), 1);
+    }
+}
@@ -37,6 +37,7 @@ extern crate maxminddb;
 extern crate serde_derive;
 extern crate serde_json;
 extern crate sha2;
+extern crate signal_hook;
 
 mod cli;
 mod client;
@@ -45,21 +46,38 @@ mod net;
 mod schema;
 mod server;
 
-use clap::{App, Arg, ArgMatches, SubCommand};
+use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
+use grpcio::{CallOption, MetadataBuilder};
+use lazy_static::lazy_static;
 
-use crate::client::ClientConfig;
+use crate::client::{ClientConfig, PcapMode, ReplayConfig};
 use crate::server::ServerConfig;
 use metrics::Prometheus;
+use signal_hook::iterator::Signals;
 use std::fs::File;
 use std::io::BufReader;
 use std::io::Read;
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::RwLock;
 use std::thread;
 use std::time::Duration;
 
 //static INFO_URL: &'static str = "anycast-testbed.nl/9qt8h";
 const INFO_URL: &'static str = "anycast-testbed.nl/9qt8h";
+/// Default maximum probing rate of a client (packets per second)
 const PING_OUT_RATE_LIMIT: u32 =5000;
+/// Number of destinations per message, from the CLI to the server and from the server to a client
+const DESTINATION_CHUNK_SIZE: usize = 10_000;
+/// Seconds between transmissions of the results queued by a client, which is also the minimum
+/// grace period of a task
+const RESULT_FLUSH_INTERVAL: u64 = 5;
+
+lazy_static! {
+    /// Access token that clients and CLI users send to the server with every call
+    static ref ACCESS_TOKEN: RwLock<Option<String>> = RwLock::new(None);
+}
 
 /// VerfPloeter:: main() - Treat command line and start VerfPloeter server/client or CLI 
 fn main() {
@@ -71,6 +89,7 @@ fn main() {
     let matches = parse_cmd();
 
     if let Some(cli_matches) = matches.subcommand_matches("cli") {
+        read_access_token(cli_matches);
         cli::execute(cli_matches);
         return;
     }
@@ -101,31 +120,52 @@ fn main() {
             certificate = read_file_content(certificate_path);
             private_key = read_file_content(private_key_path);
         }
+        let client_ca = server_matches
+            .value_of("client-ca")
+            .and_then(read_file_content);
+        let token_file = server_matches.value_of("tokens").map(PathBuf::from);
 
         // Create the config struct
         let config = ServerConfig {
             certificate,
             private_key,
+            client_ca,
+            token_file,
             port: server_matches
                 .value_of("port")
                 .unwrap_or("50001")
                 .parse::<u16>()
                 .expect("Port should be a 16-bits integer"),
+            results_directory: PathBuf::from(server_matches.value_of("results").unwrap()),
+            key_file: server_matches.value_of("key-file").map(PathBuf::from),
+            policy_file: server_matches.value_of("policy-file").map(PathBuf::from),
+            shutdown_timeout: Duration::from_secs(
+                server_matches
+                    .value_of("shutdown-timeout")
+                    .unwrap()
+                    .parse::<u64>()
+                    .expect("Shutdown timeout should be a 64-bits integer"),
+            ),
         };
 
         // Start the server
         let mut s = server::Server::new(&config);
         s.start();
 
-        // TODO: come up with a smarter way to keep the program alive
-        // MAYBE SOMETHING LIKE THIS: icmp listener 
-        //     for stream in listener.incoming() {
-        //         let stream = stream.unwrap();
-        //     }
-        loop {
-            debug!("Going into my eternal loop - until implement a better option");
-            thread::sleep(Duration::from_secs(1));
+        // Run until interrupted or terminated, a second signal skips the graceful shutdown
+        let signals = Signals::new(&[signal_hook::SIGINT, signal_hook::SIGTERM])
+            .expect("Unable to register signal handlers");
+        if let Some(signal) = signals.forever().next() {
+            info!("received signal {}, shutting down", signal);
         }
+        thread::spawn(move || {
+            if signals.forever().next().is_some() {
+                warn!("received second signal, exiting without finishing the shutdown");
+                process::exit(1);
+            }
+        });
+        s.shutdown();
+        info!("server stopped");
     } else if let Some(client_matches) = matches.subcommand_matches("client") {
         debug!("Selected CLIENT_MODE!");
         // Read certificate
@@ -133,20 +173,70 @@ fn main() {
         if let Some(certificate_path) = client_matches.value_of("certificate") {
             certificate = read_file_content(certificate_path);
         }
+        let identity = read_identity(client_matches);
+        read_access_token(client_matches);
 
         let grpc_host = client_matches.value_of("server").unwrap();
         let client_hostname = client_matches.value_of("hostname").unwrap();
+        let client_id = client_matches.value_of("id").unwrap_or(client_hostname);
+        let max_rate = client_matches
+            .value_of("max-rate")
+            .map_or(PING_OUT_RATE_LIMIT, |r| {
+                r.parse::<u32>()
+                    .ok()
+                    .filter(|r| *r > 0)
+                    .expect("Maximum rate should be a positive 32-bits integer")
+            });
 
         // Create the config struct
         let config = ClientConfig {
             grpc_host,
             client_hostname,
+            client_id,
+            site: client_matches.value_of("site").unwrap_or(""),
+            location: client_matches.value_of("location").unwrap_or(""),
+            announced_prefix: client_matches.value_of("prefix").unwrap_or(""),
             certificate,
+            identity,
+            max_rate,
+            spool_directory: PathBuf::from(client_matches.value_of("spool").unwrap()),
+            spool_size: client_matches
+                .value_of("spool-size")
+                .unwrap()
+                .parse::<u64>()
+                .expect("Spool size should be a 64-bits integer")
+                * 1024
+                * 1024,
+            pcap: client_matches.value_of("pcap").map(|directory| {
+                let mode = match client_matches.value_of("pcap-rotate") {
+                    Some(size) => PcapMode::Rotate(
+                        size.parse::<u64>()
+                            .expect("Pcap rotate size should be a 64-bits integer")
+                            * 1024
+                            * 1024,
+                    ),
+                    None => PcapMode::PerTask,
+                };
+                (PathBuf::from(directory), mode)
+            }),
         };
 
-        // Start the client
-        let c = client::Client::new(&config);
-        c.start();
+        // Replay a capture, or start the client
+        if let Some(replay_matches) = client_matches.subcommand_matches("replay") {
+            let replay = ReplayConfig {
+                pcap: Path::new(replay_matches.value_of("PCAP").unwrap()),
+                key_file: replay_matches.value_of("key-file").map(Path::new),
+                send: replay_matches.is_present("send"),
+                json: replay_matches.is_present("json"),
+            };
+            if let Err(e) = client::replay(&config, &replay) {
+                error!("{}", e);
+                process::exit(1);
+            }
+        } else {
+            let c = client::Client::new(&config);
+            c.start();
+        }
     } else {
         error!("run with --help to see options");
     }
@@ -162,6 +252,65 @@ fn read_file_content(path: &str) -> Option<Vec<u8>> {
     Some(buffer)
 }
 
+/// Read the certificate and private key a client or CLI user presents to the server
+fn read_identity(matches: &ArgMatches) -> Option<(Vec<u8>, Vec<u8>)> {
+    match (
+        matches.value_of("client-certificate"),
+        matches.value_of("client-key"),
+    ) {
+        (Some(certificate_path), Some(key_path)) => Some((
+            read_file_content(certificate_path)?,
+            read_file_content(key_path)?,
+        )),
+        _ => None,
+    }
+}
+
+/// Reads the access token a client or CLI user sends to the server
+fn read_access_token(matches: &ArgMatches) {
+    if let Some(token_path) = matches.value_of("token-file") {
+        let token = read_file_content(token_path)
+            .and_then(|token| String::from_utf8(token).ok())
+            .map(|token| token.trim().to_string())
+            .filter(|token| MetadataBuilder::new().add_str(server::TOKEN_HEADER, token).is_ok())
+            .expect("Access token should be a single line of printable ASCII characters");
+        *ACCESS_TOKEN.write().unwrap() = Some(token);
+    }
+}
+
+/// Options for calls to the server, which carry the access token if there is one
+fn call_option() -> CallOption {
+    match *ACCESS_TOKEN.read().unwrap() {
+        Some(ref token) => {
+            let mut headers = MetadataBuilder::new();
+            headers.add_str(server::TOKEN_HEADER, token).unwrap();
+            CallOption::default().headers(headers.build())
+        }
+        None => CallOption::default(),
+    }
+}
+
+/// Client labels end up in CSV output, so they cannot contain commas
+fn validate_label(label: String) -> Result<(), String> {
+    if label.contains(',') {
+        Err("should not contain commas".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// A prefix is written as address/length
+fn validate_prefix(prefix: String) -> Result<(), String> {
+    let mut parts = prefix.splitn(2, '/');
+    let address = parts.next().and_then(|a| a.parse::<IpAddr>().ok());
+    let length = parts.next().and_then(|l| l.parse::<u8>().ok());
+    match (address, length) {
+        (Some(IpAddr::V4(_)), Some(length)) if length <= 32 => Ok(()),
+        (Some(IpAddr::V6(_)), Some(length)) if length <= 128 => Ok(()),
+        _ => Err("should be a prefix like 192.0.2.0/24".to_string()),
+    }
+}
+
 /// Parse $ verfploter [OPTIONS][SUBCOMANDS}  to start server, client, CLI or help (--help)
 fn parse_cmd<'a>() -> ArgMatches<'a> {
     App::new("Verfploeter")
@@ -173,7 +322,13 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
         .subcommand(SubCommand::with_name("server").about("Launches the verfploeter server")
             .arg(Arg::with_name("certificate").short("c").takes_value(true).help("Certificate to use for SSL connection from clients (PEM-encoded file)").required(false))
             .arg(Arg::with_name("private-key").short("P").takes_value(true).help("Private key to use for SSL connection from clients (PEM-encoded file)").required(false))
+            .arg(Arg::with_name("client-ca").short("C").takes_value(true).requires_all(&["certificate", "private-key"]).help("Only accept clients and CLI users that present a certificate signed by this CA (PEM-encoded file)").required(false))
+            .arg(Arg::with_name("tokens").long("tokens").takes_value(true).value_name("FILE").requires_all(&["certificate", "private-key"]).help("File with the access tokens of clients and CLI users, one '<identity> <token>' per line, the identity of a client becomes its client id and hostname").required(false))
             .arg(Arg::with_name("port").short("p").takes_value(true).help("Port to listen on").required(false))
+            .arg(Arg::with_name("key-file").short("k").takes_value(true).help("File with the keys to sign probe payloads with, one '<key id> <secret>' per line, the last key signs new tasks").required(false))
+            .arg(Arg::with_name("policy-file").short("A").takes_value(true).help("File with the roles of CLI users, limiting the clients, rate and number of destinations of their tasks").required(false))
+            .arg(Arg::with_name("results").short("r").takes_value(true).help("Directory to store task results in").default_value("results"))
+            .arg(Arg::with_name("shutdown-timeout").long("shutdown-timeout").takes_value(true).help("Seconds running tasks get to finish when the server is stopped").default_value("60"))
         )
         .subcommand(
             SubCommand::with_name("client").about("Launches the verfploeter client")
@@ -183,7 +338,12 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
                         .takes_value(true)
                         .help("hostname for this client")
                         .required(true)
+                        .validator(validate_label)
                 )
+                .arg(Arg::with_name("id").long("id").takes_value(true).validator(validate_label).help("Stable identity of this client, which must be unique among the clients of the server [default: the hostname]"))
+                .arg(Arg::with_name("site").long("site").takes_value(true).validator(validate_label).help("Code of the anycast site this client is at, replies are counted per site in catchments"))
+                .arg(Arg::with_name("location").long("location").takes_value(true).validator(validate_label).help("Location of this client, e.g. a city or airport code"))
+                .arg(Arg::with_name("prefix").long("prefix").takes_value(true).validator(validate_prefix).help("Anycast prefix announced at the site of this client"))
                 .arg(
                     Arg::with_name("server")
                         .short("s")
@@ -192,6 +352,20 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
                         .default_value("127.0.0.1:50001")
                 )
                 .arg(Arg::with_name("certificate").short("c").takes_value(true).help("Certificate to use for SSL connection to server (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("client-certificate").long("client-certificate").takes_value(true).requires_all(&["certificate", "client-key"]).help("Certificate to present to the server (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("client-key").long("client-key").takes_value(true).requires("client-certificate").help("Private key of the client certificate (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("token-file").long("token-file").takes_value(true).value_name("FILE").requires("certificate").help("File with the access token to identify to the server with, its identity becomes the client id and hostname of this client").required(false))
+                .arg(Arg::with_name("spool").long("spool").takes_value(true).help("Directory to keep results in while they cannot be sent to the server").default_value("spool"))
+                .arg(Arg::with_name("spool-size").long("spool-size").takes_value(true).help("Maximum size of the spool in MB, the oldest results are dropped beyond this").default_value("1024"))
+                .arg(Arg::with_name("pcap").long("pcap").takes_value(true).value_name("DIR").help("Records the IPv4 packets received by the inbound handler in pcap files in DIR, one file per task"))
+                .arg(Arg::with_name("pcap-rotate").long("pcap-rotate").takes_value(true).value_name("MB").requires("pcap").help("Records all received IPv4 packets instead, in pcap files of at most MB each"))
+                .arg(Arg::with_name("max-rate").short("r").takes_value(true).help("Maximum probing rate of this client in packets per second, caps the rate requested by tasks [default: 5000]").required(false))
+                .subcommand(SubCommand::with_name("replay").about("Processes the replies in a pcap capture like the inbound handler, and prints their results")
+                    .arg(Arg::with_name("PCAP").help("The pcap file to replay, with raw IP, Ethernet or Linux cooked packets").required(true).index(1))
+                    .arg(Arg::with_name("key-file").short("k").takes_value(true).help("File with the keys the probe payloads were signed with, in the format of the server key file").required(false))
+                    .arg(Arg::with_name("send").long("send").help("Sends the results to the server as results of this client instead of printing them"))
+                    .arg(Arg::with_name("json").long("json").conflicts_with("send").help("Prints the results as JSON instead of CSV"))
+                )
         )
         .subcommand(
             SubCommand::with_name("cli").about("Verfploeter CLI")
@@ -202,17 +376,51 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
                         .help("hostname/ip address:port of the server")
                         .default_value("127.0.0.1:50001")
                 )
+                .arg(Arg::with_name("certificate").short("c").takes_value(true).help("Certificate to use for SSL connection to server (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("client-certificate").long("client-certificate").takes_value(true).requires_all(&["certificate", "client-key"]).help("Certificate to present to the server (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("client-key").long("client-key").takes_value(true).requires("client-certificate").help("Private key of the client certificate (PEM-encoded file)").required(false))
+                .arg(Arg::with_name("token-file").long("token-file").takes_value(true).value_name("FILE").requires("certificate").help("File with the access token to identify to the server with").required(false))
                 .subcommand(SubCommand::with_name("client-list").about("retrieves a list of currently connected clients from the server"))
                 .subcommand(SubCommand::with_name("start").about("performs verfploeter on the indicated client")
-                    .arg(Arg::with_name("CLIENT_HOSTNAME").help("Sets the client to run verfploeter from (i.e. the outbound ping)")
+                    .arg(Arg::with_name("CLIENT_HOSTNAME").help("Sets the client, by client id or hostname, to run verfploeter from (i.e. the outbound ping)")
                     .required(true)
                     .index(1))
-                    .arg(Arg::with_name("SOURCE_IP").help("The IP to send the pings from")
+                    .arg(Arg::with_name("SOURCE_IP").help("The IP (IPv4 or IPv6) to send the pings from")
                         .required(true)
                         .index(2))
-                    .arg(Arg::with_name("IP_FILE").help("A file that contains IP address to ping")
+                    .arg(Arg::with_name("IP_FILE").help("A file (optionally gzipped) that contains IP addresses or prefixes to ping, of the same family as SOURCE_IP")
                     .required(true)
                     .index(3))
+                    .arg(Arg::with_name("rate")
+                        .short("R")
+                        .long("rate")
+                        .takes_value(true)
+                        .help("Probing rate in packets per second, capped by the maximum rate of the client [default: client maximum]"))
+                    .arg(Arg::with_name("sample-prefixes")
+                        .long("sample-prefixes")
+                        .help("Pings one address in every /24 (IPv6: /48) of the prefixes in IP_FILE, instead of every address"))
+                    .arg(Arg::with_name("seed")
+                        .long("seed")
+                        .takes_value(true)
+                        .help("Seed of the pseudo-random order in which destinations are probed, to reproduce an earlier run [default: random]"))
+                    .arg(Arg::with_name("grace-period")
+                        .long("grace-period")
+                        .takes_value(true)
+                        .value_name("SECONDS")
+                        .help("Time to wait for the last replies after the final probe was sent, at least 5 seconds [default: 10]"))
+                    .arg(Arg::with_name("tcp")
+                        .short("t")
+                        .long("tcp")
+                        .takes_value(true)
+                        .value_name("PORT")
+                        .help("Probe with TCP SYN segments to the given port instead of ICMP echo requests"))
+                    .arg(Arg::with_name("dns")
+                        .short("d")
+                        .long("dns")
+                        .takes_value(true)
+                        .value_name("DOMAIN")
+                        .conflicts_with("tcp")
+                        .help("Probe with DNS queries for names under DOMAIN instead of ICMP echo requests, IP_FILE holds resolvers or authoritative servers"))
                     .arg(Arg::with_name("stream")
                         .short("s")
                         .multiple(false)
@@ -221,6 +429,102 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
                         .short("j")
                         .multiple(false)
                         .help("Output results in JSON format"))
+                    .arg(Arg::with_name("catchment")
+                        .long("catchment")
+                        .help("Output one row per /24 (IPv6: /48) with the site that received its replies, instead of one row per reply"))
+                    .arg(Arg::with_name("prefix-length")
+                        .long("prefix-length")
+                        .takes_value(true)
+                        .requires("catchment")
+                        .help("Prefix length of the blocks in catchment mode"))
+                    .arg(Arg::with_name("ip2country")
+                        .short("c")
+                        .takes_value(true)
+                        .help("Adds a column with IP2Country information. Needs a path to a IP2Country database (MaxMind binary format)"))
+                    .arg(Arg::with_name("ip2asn")
+                        .short("a")
+                        .takes_value(true)
+                        .help("Adds a column with IP2ASN information. Needs a path to a IP2ASN database (MaxMind binary format)"))
+                )
+                .subcommand(SubCommand::with_name("task-list").about("lists the tasks handed out by the server"))
+                .subcommand(SubCommand::with_name("task-status").about("shows the status of a task")
+                    .arg(Arg::with_name("TASK_ID").help("The id of the task")
+                        .required(true)
+                        .index(1)))
+                .subcommand(SubCommand::with_name("task-cancel").about("cancels a running task or campaign")
+                    .arg(Arg::with_name("TASK_ID").help("The id of the task or campaign")
+                        .required(true)
+                        .index(1)))
+                .subcommand(SubCommand::with_name("results").about("retrieves the (stored) results of a task or campaign, waiting for more results while it is running")
+                    .arg(Arg::with_name("TASK_ID").help("The id of the task or campaign")
+                        .required(true)
+                        .index(1))
+                    .arg(Arg::with_name("json")
+                        .short("j")
+                        .multiple(false)
+                        .help("Output results in JSON format"))
+                    .arg(Arg::with_name("catchment")
+                        .long("catchment")
+                        .help("Output one row per /24 (IPv6: /48) with the site that received its replies, instead of one row per reply"))
+                    .arg(Arg::with_name("prefix-length")
+                        .long("prefix-length")
+                        .takes_value(true)
+                        .requires("catchment")
+                        .help("Prefix length of the blocks in catchment mode"))
+                    .arg(Arg::with_name("ip2country")
+                        .short("c")
+                        .takes_value(true)
+                        .help("Adds a column with IP2Country information. Needs a path to a IP2Country database (MaxMind binary format)"))
+                    .arg(Arg::with_name("ip2asn")
+                        .short("a")
+                        .takes_value(true)
+                        .help("Adds a column with IP2ASN information. Needs a path to a IP2ASN database (MaxMind binary format)"))
+                )
+                .subcommand(SubCommand::with_name("campaign").about("performs verfploeter from one client, or from every connected client in turn, collecting the results of all clients under one campaign id")
+                    .arg(Arg::with_name("SOURCE_IP").help("The IP (IPv4 or IPv6) to send the pings from")
+                        .required(true)
+                        .index(1))
+                    .arg(Arg::with_name("IP_FILE").help("A file (optionally gzipped) that contains IP addresses or prefixes to ping, of the same family as SOURCE_IP")
+                        .required(true)
+                        .index(2))
+                    .arg(Arg::with_name("client")
+                        .short("C")
+                        .takes_value(true)
+                        .required_unless("rotate")
+                        .help("Sets the client, by client id or hostname, to run verfploeter from (i.e. the outbound ping)"))
+                    .arg(Arg::with_name("rotate")
+                        .short("r")
+                        .conflicts_with("client")
+                        .help("Runs the outbound ping from every connected client, one after the other"))
+                    .arg(Arg::with_name("rate")
+                        .short("R")
+                        .long("rate")
+                        .takes_value(true)
+                        .help("Probing rate in packets per second, capped by the maximum rate of the client [default: client maximum]"))
+                    .arg(Arg::with_name("sample-prefixes")
+                        .long("sample-prefixes")
+                        .help("Pings one address in every /24 (IPv6: /48) of the prefixes in IP_FILE, instead of every address"))
+                    .arg(Arg::with_name("seed")
+                        .long("seed")
+                        .takes_value(true)
+                        .help("Seed of the pseudo-random order in which destinations are probed, to reproduce an earlier run [default: random]"))
+                    .arg(Arg::with_name("grace-period")
+                        .long("grace-period")
+                        .takes_value(true)
+                        .value_name("SECONDS")
+                        .help("Time to wait for the last replies after the final probe was sent, at least 5 seconds [default: 10]"))
+                    .arg(Arg::with_name("json")
+                        .short("j")
+                        .multiple(false)
+                        .help("Output results in JSON format"))
+                    .arg(Arg::with_name("catchment")
+                        .long("catchment")
+                        .help("Output one row per /24 (IPv6: /48) with the site that received its replies, instead of one row per reply"))
+                    .arg(Arg::with_name("prefix-length")
+                        .long("prefix-length")
+                        .takes_value(true)
+                        .requires("catchment")
+                        .help("Prefix length of the blocks in catchment mode"))
                     .arg(Arg::with_name("ip2country")
                         .short("c")
                         .takes_value(true)
@@ -230,6 +534,99 @@ fn parse_cmd<'a>() -> ArgMatches<'a> {
                         .takes_value(true)
                         .help("Adds a column with IP2ASN information. Needs a path to a IP2ASN database (MaxMind binary format)"))
                 )
+                .subcommand(SubCommand::with_name("schedule").about("manages measurements the server runs by itself, once or repeatedly")
+                    .subcommand(SubCommand::with_name("add").about("schedules verfploeter on the indicated client, every run is a task with its own results")
+                        .arg(Arg::with_name("CLIENT_HOSTNAME").help("Sets the client, by client id or hostname, to run verfploeter from (i.e. the outbound ping)")
+                            .required(true)
+                            .index(1))
+                        .arg(Arg::with_name("SOURCE_IP").help("The IP (IPv4 or IPv6) to send the pings from")
+                            .required(true)
+                            .index(2))
+                        .arg(Arg::with_name("IP_FILE").help("A file (optionally gzipped) that contains IP addresses or prefixes to ping, of the same family as SOURCE_IP")
+                            .required(true)
+                            .index(3))
+                        .arg(Arg::with_name("cron")
+                            .long("cron")
+                            .takes_value(true)
+                            .value_name("EXPRESSION")
+                            .help("Runs at the times matching a cron expression (minute hour day-of-month month day-of-week, in UTC), e.g. \"0 2 * * *\" for daily at 02:00"))
+                        .arg(Arg::with_name("every")
+                            .long("every")
+                            .takes_value(true)
+                            .value_name("INTERVAL")
+                            .conflicts_with("cron")
+                            .help("Runs every interval from the start time, e.g. 30m, 6h or 1d"))
+                        .arg(Arg::with_name("at")
+                            .long("at")
+                            .takes_value(true)
+                            .value_name("TIME")
+                            .help("Start time as YYYY-MM-DD HH:MM (UTC), runs only once at that time without --cron or --every [default: now]"))
+                        .group(ArgGroup::with_name("when")
+                            .args(&["cron", "every", "at"])
+                            .multiple(true)
+                            .required(true))
+                        .arg(Arg::with_name("rate")
+                            .short("R")
+                            .long("rate")
+                            .takes_value(true)
+                            .help("Probing rate in packets per second, capped by the maximum rate of the client [default: client maximum]"))
+                        .arg(Arg::with_name("sample-prefixes")
+                            .long("sample-prefixes")
+                            .help("Pings one address in every /24 (IPv6: /48) of the prefixes in IP_FILE, instead of every address"))
+                        .arg(Arg::with_name("seed")
+                            .long("seed")
+                            .takes_value(true)
+                            .help("Seed of the pseudo-random order in which destinations are probed, the same for every run [default: random for every run]"))
+                        .arg(Arg::with_name("grace-period")
+                            .long("grace-period")
+                            .takes_value(true)
+                            .value_name("SECONDS")
+                            .help("Time to wait for the last replies after the final probe was sent, at least 5 seconds [default: 10]"))
+                        .arg(Arg::with_name("tcp")
+                            .short("t")
+                            .long("tcp")
+                            .takes_value(true)
+                            .value_name("PORT")
+                            .help("Probe with TCP SYN segments to the given port instead of ICMP echo requests"))
+                        .arg(Arg::with_name("dns")
+                            .short("d")
+                            .long("dns")
+                            .takes_value(true)
+                            .value_name("DOMAIN")
+                            .conflicts_with("tcp")
+                            .help("Probe with DNS queries for names under DOMAIN instead of ICMP echo requests, IP_FILE holds resolvers or authoritative servers"))
+                    )
+                    .subcommand(SubCommand::with_name("list").about("lists the schedules with their next and last run"))
+                    .subcommand(SubCommand::with_name("remove").about("removes a schedule, the results of its runs are kept")
+                        .arg(Arg::with_name("SCHEDULE_ID").help("The id of the schedule")
+                            .required(true)
+                            .index(1)))
+                )
+                .subcommand(SubCommand::with_name("diff").about("compares the catchments of two saved result files (CSV or JSON)")
+                    .arg(Arg::with_name("BEFORE").help("Results of the earlier run")
+                        .required(true)
+                        .index(1))
+                    .arg(Arg::with_name("AFTER").help("Results of the later run")
+                        .required(true)
+                        .index(2))
+                    .arg(Arg::with_name("blocks")
+                        .short("b")
+                        .long("blocks")
+                        .help("Output the blocks that switched site, appeared or disappeared, instead of the summaries"))
+                    .arg(Arg::with_name("json")
+                        .short("j")
+                        .multiple(false)
+                        .requires("blocks")
+                        .help("Output blocks in JSON format"))
+                    .arg(Arg::with_name("prefix-length")
+                        .long("prefix-length")
+                        .takes_value(true)
+                        .help("Prefix length of the blocks that replies are aggregated into [default: 24 for IPv4, 48 for IPv6]"))
+                    .arg(Arg::with_name("ip2asn")
+                        .short("a")
+                        .takes_value(true)
+                        .help("Adds a summary per AS, or a column with the AS of the block. Needs a path to a IP2ASN database (MaxMind binary format)"))
+                )
         )
         .get_matches()
 }
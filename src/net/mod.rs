@@ -1,7 +1,7 @@
 use super::byteorder::{LittleEndian, NetworkEndian, ReadBytesExt, WriteBytesExt};
 use std::io::Cursor;
 use std::io::Write;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use crate::INFO_URL;
 
 #[derive(Debug)]
@@ -12,12 +12,62 @@ pub struct IPv4Packet {
     pub payload: PacketPayload,
 }
 
+#[derive(Debug)]
+pub struct IPv6Packet {
+    /// None for packets received without their IPv6 header, raw IPv6 sockets do not pass it
+    pub hop_limit: Option<u8>,
+    pub source_address: Ipv6Addr,
+    pub destination_address: Ipv6Addr,
+    pub payload: PacketPayload,
+}
+
+/// An IP packet of either family, as handed from the receiving sockets to the processor
+#[derive(Debug)]
+pub enum IPPacket {
+    V4(IPv4Packet),
+    V6(IPv6Packet),
+}
+
 #[derive(Debug)]
 pub enum PacketPayload {
     ICMPv4 { value: ICMP4Packet },
+    ICMPv6 { value: ICMP6Packet },
+    TCP { value: TCPPacket },
+    UDP { value: UDPPacket },
     Unimplemented,
 }
 
+impl IPPacket {
+    pub fn source_address(&self) -> IpAddr {
+        match self {
+            IPPacket::V4(packet) => IpAddr::V4(packet.source_address),
+            IPPacket::V6(packet) => IpAddr::V6(packet.source_address),
+        }
+    }
+
+    pub fn destination_address(&self) -> IpAddr {
+        match self {
+            IPPacket::V4(packet) => IpAddr::V4(packet.destination_address),
+            IPPacket::V6(packet) => IpAddr::V6(packet.destination_address),
+        }
+    }
+
+    /// TTL for IPv4, hop limit for IPv6 if the packet was received with its IP header
+    pub fn ttl(&self) -> Option<u8> {
+        match self {
+            IPPacket::V4(packet) => Some(packet.ttl),
+            IPPacket::V6(packet) => packet.hop_limit,
+        }
+    }
+
+    pub fn payload(&self) -> &PacketPayload {
+        match self {
+            IPPacket::V4(packet) => &packet.payload,
+            IPPacket::V6(packet) => &packet.payload,
+        }
+    }
+}
+
 impl From<&[u8]> for IPv4Packet {
     fn from(data: &[u8]) -> Self {
         let mut cursor = Cursor::new(data);
@@ -40,6 +90,12 @@ impl From<&[u8]> for IPv4Packet {
             1 => PacketPayload::ICMPv4 {
                 value: ICMP4Packet::from(payload_bytes),
             },
+            6 => PacketPayload::TCP {
+                value: TCPPacket::from(payload_bytes),
+            },
+            17 => PacketPayload::UDP {
+                value: UDPPacket::from(payload_bytes),
+            },
             _ => PacketPayload::Unimplemented,
         };
 
@@ -52,6 +108,57 @@ impl From<&[u8]> for IPv4Packet {
     }
 }
 
+impl From<&[u8]> for IPv6Packet {
+    /// Parse an IPv6 packet including its fixed 40 byte header, extension headers are not
+    /// supported and result in an unimplemented payload, as do packets too short for their
+    /// headers
+    fn from(data: &[u8]) -> Self {
+        if data.len() < 40 {
+            return IPv6Packet {
+                hop_limit: None,
+                source_address: Ipv6Addr::UNSPECIFIED,
+                destination_address: Ipv6Addr::UNSPECIFIED,
+                payload: PacketPayload::Unimplemented,
+            };
+        }
+
+        let mut cursor = Cursor::new(data);
+        // Skip version, traffic class and flow label (4 bytes)
+        cursor.set_position(4);
+        let payload_length = cursor.read_u16::<NetworkEndian>().unwrap() as usize;
+        let next_header = cursor.read_u8().unwrap();
+        let hop_limit = cursor.read_u8().unwrap();
+
+        let data = cursor.into_inner();
+        let mut source_address = [0; 16];
+        source_address.copy_from_slice(&data[8..24]);
+        let mut destination_address = [0; 16];
+        destination_address.copy_from_slice(&data[24..40]);
+
+        let payload_end = usize::min(40 + payload_length, data.len());
+        let payload_bytes = &data[40..payload_end];
+        let payload = match (next_header, payload_bytes.len()) {
+            (6, length) if length >= 20 => PacketPayload::TCP {
+                value: TCPPacket::from(payload_bytes),
+            },
+            (17, length) if length >= 8 => PacketPayload::UDP {
+                value: UDPPacket::from(payload_bytes),
+            },
+            (58, length) if length >= 8 => PacketPayload::ICMPv6 {
+                value: ICMP6Packet::from(payload_bytes),
+            },
+            _ => PacketPayload::Unimplemented,
+        };
+
+        IPv6Packet {
+            hop_limit: Some(hop_limit),
+            source_address: Ipv6Addr::from(source_address),
+            destination_address: Ipv6Addr::from(destination_address),
+            payload,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ICMP4Packet {
     pub icmp_type: u8,
@@ -127,7 +234,7 @@ impl ICMP4Packet {
     }
 
     /// Calc ICMP Checksum covers the entire ICMPv4 message (16-bit one's complement)
-    /// TODO L-> ICMPv6 it also covers a pseudo-header derived from portions of the IPv6 header.
+    /// For ICMPv6 it also covers a pseudo-header, see ICMP6Packet::calc_checksum.
     fn calc_checksum(buffer: &[u8]) -> u16 {
         debug!("ICMP4Packet::calc_checksum()");
         let mut cursor = Cursor::new(buffer);
@@ -144,3 +251,443 @@ impl ICMP4Packet {
         !sum as u16
     }
 }
+
+#[derive(Debug)]
+pub struct ICMP6Packet {
+    pub icmp_type: u8,
+    pub code: u8,
+    pub checksum: u16,
+    pub identifier: u16,
+    pub sequence_number: u16,
+    pub body: Vec<u8>,
+}
+
+impl From<&[u8]> for ICMP6Packet {
+    fn from(data: &[u8]) -> Self {
+        debug!("From for ICMPv6Packet");
+        let mut data = Cursor::new(data);
+        ICMP6Packet {
+            icmp_type: data.read_u8().unwrap(),
+            code: data.read_u8().unwrap(),
+            checksum: data.read_u16::<NetworkEndian>().unwrap(),
+            identifier: data.read_u16::<NetworkEndian>().unwrap(),
+            sequence_number: data.read_u16::<NetworkEndian>().unwrap(),
+            body: data.into_inner()[8..].to_vec(),
+        }
+    }
+}
+
+impl Into<Vec<u8>> for &ICMP6Packet {
+    fn into(self) -> Vec<u8> {
+        debug!("IntoVec for ICMPv6");
+        let mut wtr = vec![];
+        wtr.write_u8(self.icmp_type)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr.write_u8(self.code)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr.write_u16::<NetworkEndian>(self.checksum)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr.write_u16::<NetworkEndian>(self.identifier)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr.write_u16::<NetworkEndian>(self.sequence_number)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr.write_all(&self.body)
+            .expect("Unable to write to byte buffer for ICMP packet");
+        wtr
+    }
+}
+
+impl ICMP6Packet {
+    /// Create a basic ICMPv6 ECHO_REQUEST (128.0) packet with checksum
+    /// The source and destination address are needed for the pseudo-header in the checksum
+    pub fn echo_request(
+        identifier: u16,
+        sequence_number: u16,
+        body: Vec<u8>,
+        source_address: Ipv6Addr,
+        destination_address: Ipv6Addr,
+    ) -> Vec<u8> {
+        debug!("ICMP6Packet::echo_request()");
+        let mut packet = ICMP6Packet {
+            icmp_type: 128,
+            code: 0,
+            checksum: 0,
+            identifier,
+            sequence_number,
+            body,
+        };
+
+        let mut bytes: Vec<u8> = (&packet).into();
+        bytes.extend(INFO_URL.bytes());
+        packet.checksum = ICMP6Packet::calc_checksum(source_address, destination_address, &bytes);
+
+        let mut cursor = Cursor::new(bytes);
+        cursor.set_position(2); // Skip icmp_type (1 byte) and code (1 byte)
+        cursor.write_u16::<LittleEndian>(packet.checksum).unwrap();
+
+        cursor.into_inner()
+    }
+
+    /// Calc ICMPv6 Checksum, which covers the entire ICMPv6 message prefixed with the IPv6
+    /// pseudo-header
+    fn calc_checksum(source_address: Ipv6Addr, destination_address: Ipv6Addr, buffer: &[u8]) -> u16 {
+        debug!("ICMP6Packet::calc_checksum()");
+        pseudo_header_checksum(source_address.into(), destination_address.into(), 58, buffer)
+    }
+}
+
+/// Checksum of an upper-layer message prefixed with the pseudo-header of its IP packet,
+/// for IPv4 (RFC 793) source, destination, protocol and length, for IPv6 (RFC 8200 8.1)
+/// source, destination, upper-layer length and next header
+fn pseudo_header_checksum(
+    source_address: IpAddr,
+    destination_address: IpAddr,
+    protocol: u8,
+    buffer: &[u8],
+) -> u16 {
+    let mut pseudo_header = Vec::with_capacity(40 + buffer.len());
+    match (source_address, destination_address) {
+        (IpAddr::V4(source), IpAddr::V4(destination)) => {
+            pseudo_header.extend_from_slice(&source.octets());
+            pseudo_header.extend_from_slice(&destination.octets());
+            pseudo_header.extend_from_slice(&[0, protocol]);
+            pseudo_header
+                .write_u16::<NetworkEndian>(buffer.len() as u16)
+                .unwrap();
+        }
+        (source, destination) => {
+            pseudo_header.extend_from_slice(&to_ipv6(source).octets());
+            pseudo_header.extend_from_slice(&to_ipv6(destination).octets());
+            pseudo_header
+                .write_u32::<NetworkEndian>(buffer.len() as u32)
+                .unwrap();
+            pseudo_header.extend_from_slice(&[0, 0, 0, protocol]);
+        }
+    }
+    pseudo_header.extend_from_slice(buffer);
+    ICMP4Packet::calc_checksum(&pseudo_header)
+}
+
+fn to_ipv6(address: IpAddr) -> Ipv6Addr {
+    match address {
+        IpAddr::V4(address) => address.to_ipv6_mapped(),
+        IpAddr::V6(address) => address,
+    }
+}
+
+pub const TCP_SYN: u8 = 0x02;
+pub const TCP_RST: u8 = 0x04;
+pub const TCP_ACK: u8 = 0x10;
+
+/// TCP header, options and data are not used by any of the probes
+#[derive(Debug)]
+pub struct TCPPacket {
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub sequence_number: u32,
+    pub acknowledgement_number: u32,
+    pub flags: u8,
+    pub window_size: u16,
+    pub checksum: u16,
+}
+
+impl From<&[u8]> for TCPPacket {
+    fn from(data: &[u8]) -> Self {
+        debug!("From for TCPPacket");
+        let mut data = Cursor::new(data);
+        let source_port = data.read_u16::<NetworkEndian>().unwrap();
+        let destination_port = data.read_u16::<NetworkEndian>().unwrap();
+        let sequence_number = data.read_u32::<NetworkEndian>().unwrap();
+        let acknowledgement_number = data.read_u32::<NetworkEndian>().unwrap();
+        data.set_position(13); // Skip data offset (4 bits) and reserved bits
+        TCPPacket {
+            source_port,
+            destination_port,
+            sequence_number,
+            acknowledgement_number,
+            flags: data.read_u8().unwrap(),
+            window_size: data.read_u16::<NetworkEndian>().unwrap(),
+            checksum: data.read_u16::<NetworkEndian>().unwrap(),
+        }
+    }
+}
+
+impl Into<Vec<u8>> for &TCPPacket {
+    fn into(self) -> Vec<u8> {
+        debug!("IntoVec for TCP");
+        let mut wtr = vec![];
+        wtr.write_u16::<NetworkEndian>(self.source_port)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u16::<NetworkEndian>(self.destination_port)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u32::<NetworkEndian>(self.sequence_number)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u32::<NetworkEndian>(self.acknowledgement_number)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u8(5 << 4) // Data offset of 5 words, no options
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u8(self.flags)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u16::<NetworkEndian>(self.window_size)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u16::<NetworkEndian>(self.checksum)
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr.write_u16::<NetworkEndian>(0) // Urgent pointer
+            .expect("Unable to write to byte buffer for TCP packet");
+        wtr
+    }
+}
+
+impl TCPPacket {
+    /// Create a TCP SYN segment with checksum, the source and destination address are
+    /// needed for the pseudo-header in the checksum
+    pub fn syn(
+        source_address: IpAddr,
+        destination_address: IpAddr,
+        source_port: u16,
+        destination_port: u16,
+        sequence_number: u32,
+    ) -> Vec<u8> {
+        debug!("TCPPacket::syn()");
+        let mut packet = TCPPacket {
+            source_port,
+            destination_port,
+            sequence_number,
+            acknowledgement_number: 0,
+            flags: TCP_SYN,
+            window_size: 65535,
+            checksum: 0,
+        };
+
+        let bytes: Vec<u8> = (&packet).into();
+        packet.checksum = pseudo_header_checksum(source_address, destination_address, 6, &bytes);
+
+        let mut cursor = Cursor::new(bytes);
+        cursor.set_position(16); // Skip everything up to the checksum
+        cursor.write_u16::<LittleEndian>(packet.checksum).unwrap();
+
+        cursor.into_inner()
+    }
+}
+
+/// UDP datagram
+#[derive(Debug)]
+pub struct UDPPacket {
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub checksum: u16,
+    pub body: Vec<u8>,
+}
+
+impl From<&[u8]> for UDPPacket {
+    fn from(data: &[u8]) -> Self {
+        debug!("From for UDPPacket");
+        let mut data = Cursor::new(data);
+        let source_port = data.read_u16::<NetworkEndian>().unwrap();
+        let destination_port = data.read_u16::<NetworkEndian>().unwrap();
+        data.set_position(6); // Skip length, the body is the remainder of the payload
+        let checksum = data.read_u16::<NetworkEndian>().unwrap();
+        UDPPacket {
+            source_port,
+            destination_port,
+            checksum,
+            body: data.into_inner()[8..].to_vec(),
+        }
+    }
+}
+
+impl Into<Vec<u8>> for &UDPPacket {
+    fn into(self) -> Vec<u8> {
+        debug!("IntoVec for UDP");
+        let mut wtr = vec![];
+        wtr.write_u16::<NetworkEndian>(self.source_port)
+            .expect("Unable to write to byte buffer for UDP packet");
+        wtr.write_u16::<NetworkEndian>(self.destination_port)
+            .expect("Unable to write to byte buffer for UDP packet");
+        wtr.write_u16::<NetworkEndian>(8 + self.body.len() as u16)
+            .expect("Unable to write to byte buffer for UDP packet");
+        wtr.write_u16::<NetworkEndian>(self.checksum)
+            .expect("Unable to write to byte buffer for UDP packet");
+        wtr.write_all(&self.body)
+            .expect("Unable to write to byte buffer for UDP packet");
+        wtr
+    }
+}
+
+impl UDPPacket {
+    /// Create a UDP datagram with checksum, the source and destination address are
+    /// needed for the pseudo-header in the checksum
+    pub fn datagram(
+        source_address: IpAddr,
+        destination_address: IpAddr,
+        source_port: u16,
+        destination_port: u16,
+        body: Vec<u8>,
+    ) -> Vec<u8> {
+        debug!("UDPPacket::datagram()");
+        let mut packet = UDPPacket {
+            source_port,
+            destination_port,
+            checksum: 0,
+            body,
+        };
+
+        let bytes: Vec<u8> = (&packet).into();
+        packet.checksum = pseudo_header_checksum(source_address, destination_address, 17, &bytes);
+        // A zero checksum means no checksum was computed, it is sent as all ones instead
+        if packet.checksum == 0 {
+            packet.checksum = 0xFFFF;
+        }
+
+        let mut cursor = Cursor::new(bytes);
+        cursor.set_position(6); // Skip ports and length
+        cursor.write_u16::<LittleEndian>(packet.checksum).unwrap();
+
+        cursor.into_inner()
+    }
+}
+
+#[cfg(test)]
+mod ipv6packet {
+    use super::*;
+    use std::str::FromStr;
+
+    /// Fixed IPv6 header followed by an ICMPv6 echo request
+    fn echo_request() -> Vec<u8> {
+        let source = Ipv6Addr::from_str("2001:db8::1").unwrap();
+        let destination = Ipv6Addr::from_str("2001:db8::2").unwrap();
+        let icmp = ICMP6Packet::echo_request(1, 2, vec![1, 2, 3], source, destination);
+        let mut bytes = vec![0x60, 0, 0, 0];
+        bytes.write_u16::<NetworkEndian>(icmp.len() as u16).unwrap();
+        bytes.extend_from_slice(&[58, 64]);
+        bytes.extend_from_slice(&source.octets());
+        bytes.extend_from_slice(&destination.octets());
+        bytes.extend_from_slice(&icmp);
+        bytes
+    }
+
+    #[test]
+    fn parses_echo_request() {
+        let packet = IPv6Packet::from(echo_request().as_slice());
+
+        assert_eq!(packet.hop_limit, Some(64));
+        assert_eq!(packet.source_address, Ipv6Addr::from_str("2001:db8::1").unwrap());
+        match packet.payload {
+            PacketPayload::ICMPv6 { value } => assert_eq!(value.sequence_number, 2),
+            payload => panic!("unexpected payload {:?}", payload),
+        }
+    }
+
+    #[test]
+    fn short_packets_are_not_parsed() {
+        let bytes = echo_request();
+        for length in &[0, 8, 39, 40, 47] {
+            let packet = IPv6Packet::from(&bytes[..*length]);
+            match packet.payload {
+                PacketPayload::Unimplemented => {}
+                payload => panic!("payload {:?} parsed from {} bytes", payload, length),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod icmp6packet {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn echo_request_has_valid_checksum() {
+        let source = Ipv6Addr::from_str("2001:db8::1").unwrap();
+        let destination = Ipv6Addr::from_str("2001:db8::2").unwrap();
+        let bytes = ICMP6Packet::echo_request(1, 2, vec![1, 2, 3, 4, 5], source, destination);
+
+        // Summing a packet including its checksum yields zero
+        assert_eq!(ICMP6Packet::calc_checksum(source, destination, &bytes), 0);
+    }
+
+    #[test]
+    fn checksum_covers_pseudo_header() {
+        let source = Ipv6Addr::from_str("2001:db8::1").unwrap();
+        let destination = Ipv6Addr::from_str("2001:db8::2").unwrap();
+        let other = Ipv6Addr::from_str("2001:db8::3").unwrap();
+        let bytes = ICMP6Packet::echo_request(1, 2, vec![1, 2, 3, 4, 5], source, destination);
+
+        assert_ne!(ICMP6Packet::calc_checksum(source, other, &bytes), 0);
+    }
+
+    #[test]
+    fn parses_echo_request() {
+        let source = Ipv6Addr::from_str("2001:db8::1").unwrap();
+        let destination = Ipv6Addr::from_str("2001:db8::2").unwrap();
+        let bytes = ICMP6Packet::echo_request(1, 2, vec![1, 2, 3], source, destination);
+        let packet = ICMP6Packet::from(bytes.as_slice());
+
+        assert_eq!(packet.icmp_type, 128);
+        assert_eq!(packet.identifier, 1);
+        assert_eq!(packet.sequence_number, 2);
+        assert_eq!(&packet.body[..3], &[1, 2, 3]);
+        assert!(packet.body.ends_with(INFO_URL.as_bytes()));
+    }
+}
+
+#[cfg(test)]
+mod tcppacket {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn syn_has_valid_checksum() {
+        for (source, destination) in &[("192.0.2.1", "198.51.100.2"), ("2001:db8::1", "2001:db8::2")] {
+            let source = IpAddr::from_str(source).unwrap();
+            let destination = IpAddr::from_str(destination).unwrap();
+            let bytes = TCPPacket::syn(source, destination, 40000, 80, 0xdead_beef);
+
+            assert_eq!(bytes.len(), 20);
+            assert_eq!(pseudo_header_checksum(source, destination, 6, &bytes), 0);
+        }
+    }
+
+    #[test]
+    fn parses_syn() {
+        let source = IpAddr::from_str("192.0.2.1").unwrap();
+        let destination = IpAddr::from_str("198.51.100.2").unwrap();
+        let bytes = TCPPacket::syn(source, destination, 40000, 443, 0xdead_beef);
+        let packet = TCPPacket::from(bytes.as_slice());
+
+        assert_eq!(packet.source_port, 40000);
+        assert_eq!(packet.destination_port, 443);
+        assert_eq!(packet.sequence_number, 0xdead_beef);
+        assert_eq!(packet.flags, TCP_SYN);
+    }
+}
+
+#[cfg(test)]
+mod udppacket {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn datagram_has_valid_checksum() {
+        for (source, destination) in &[("192.0.2.1", "198.51.100.2"), ("2001:db8::1", "2001:db8::2")] {
+            let source = IpAddr::from_str(source).unwrap();
+            let destination = IpAddr::from_str(destination).unwrap();
+            let bytes = UDPPacket::datagram(source, destination, 40000, 53, vec![1, 2, 3]);
+
+            assert_eq!(bytes.len(), 11);
+            assert_eq!(pseudo_header_checksum(source, destination, 17, &bytes), 0);
+        }
+    }
+
+    #[test]
+    fn parses_datagram() {
+        let source = IpAddr::from_str("192.0.2.1").unwrap();
+        let destination = IpAddr::from_str("198.51.100.2").unwrap();
+        let bytes = UDPPacket::datagram(source, destination, 40000, 53, vec![1, 2, 3]);
+        let packet = UDPPacket::from(bytes.as_slice());
+
+        assert_eq!(packet.source_port, 40000);
+        assert_eq!(packet.destination_port, 53);
+        assert_eq!(packet.body, vec![1, 2, 3]);
+    }
+}
@@ -3,7 +3,7 @@
 pub mod verfploeter;
 pub mod verfploeter_grpc;
 
-use self::verfploeter::{Address, PingPayload, TaskResult};
+use self::verfploeter::{Address, Metadata, PingPayload, TaskResult};
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use std::fmt;
@@ -39,25 +39,72 @@ impl From<Ipv4Addr> for Address {
     }
 }
 
+impl From<Ipv6Addr> for Address {
+    fn from(addr: Ipv6Addr) -> Self {
+        let mut address = Address::new();
+        address.set_v6(addr.octets().to_vec());
+        address
+    }
+}
+
+impl From<IpAddr> for Address {
+    fn from(addr: IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(v4) => v4.into(),
+            IpAddr::V6(v6) => v6.into(),
+        }
+    }
+}
+
+impl Metadata {
+    /// Stable identity of a client, clients that do not report a client id are identified
+    /// by their hostname
+    pub fn id(&self) -> &str {
+        if self.get_client_id().is_empty() {
+            self.get_hostname()
+        } else {
+            self.get_client_id()
+        }
+    }
+}
+
 impl fmt::Display for TaskResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let task_id = self.get_task_id();
-        let client_id = self.get_client().get_metadata().get_hostname();
+        let client_id = self.get_client().get_metadata().id();
         for (idx, result) in self.get_result_list().iter().enumerate() {
             if idx != 0 {
                 writeln!(f).unwrap();
             }
-            if result.has_ping() {
-                let ping = result.get_ping();
+            if result.has_ping() || result.has_dns() {
+                let (source_address, destination_address, payload) = if result.has_dns() {
+                    let dns = result.get_dns();
+                    (dns.get_source_address(), dns.get_destination_address(), dns.get_payload())
+                } else {
+                    let ping = result.get_ping();
+                    (ping.get_source_address(), ping.get_destination_address(), ping.get_payload())
+                };
                 write!(
                     f,
                     "{}|{}|{}|{}|{}|{}",
                     task_id,
                     client_id,
-                    IpAddr::from(ping.get_source_address()),
-                    IpAddr::from(ping.get_destination_address()),
-                    IpAddr::from(ping.get_payload().get_source_address()),
-                    IpAddr::from(ping.get_payload().get_destination_address()),
+                    IpAddr::from(source_address),
+                    IpAddr::from(destination_address),
+                    IpAddr::from(payload.get_source_address()),
+                    IpAddr::from(payload.get_destination_address()),
+                )
+                .unwrap();
+            } else if result.has_tcp() {
+                let tcp = result.get_tcp();
+                write!(
+                    f,
+                    "{}|{}|{}|{}|{}",
+                    task_id,
+                    client_id,
+                    IpAddr::from(tcp.get_source_address()),
+                    IpAddr::from(tcp.get_destination_address()),
+                    tcp.get_source_port(),
                 )
                 .unwrap();
             } else {
@@ -125,6 +172,28 @@ impl Signable<PingPayload> for PingPayload {
     }
 }
 
+impl PingPayload {
+    /// Reads the key id from signed bytes without verifying the signature, so the
+    /// secret of that key can be used to verify them
+    pub fn key_id_from_signed_bytes(buffer: &[u8]) -> Result<u32, Box<Error>> {
+        if buffer.len() < 32 {
+            return Err(LengthError {}.into());
+        }
+        let value = &buffer[..buffer.len() - 32];
+        Ok(protobuf::parse_from_bytes::<PingPayload>(value)?.get_key_id())
+    }
+
+    /// Reads the task id from signed bytes without verifying the signature, to tell
+    /// which task a reply that fails verification belongs to
+    pub fn task_id_from_signed_bytes(buffer: &[u8]) -> Result<u32, Box<Error>> {
+        if buffer.len() < 32 {
+            return Err(LengthError {}.into());
+        }
+        let value = &buffer[..buffer.len() - 32];
+        Ok(protobuf::parse_from_bytes::<PingPayload>(value)?.get_task_id())
+    }
+}
+
 #[cfg(test)]
 mod signable_pingpayload {
     use super::*;
@@ -178,6 +247,19 @@ mod signable_pingpayload {
         );
     }
 
+    #[test]
+    fn key_id_can_be_read_before_validation() {
+        let mut pp = PingPayload::new();
+        pp.set_task_id(1234);
+        pp.set_key_id(7);
+
+        let pp_bytes_signed = pp.to_signed_bytes("abc123").unwrap();
+        assert_eq!(
+            PingPayload::key_id_from_signed_bytes(&pp_bytes_signed).unwrap(),
+            7
+        );
+    }
+
     #[test]
     fn does_not_validate_with_too_few_bytes_in_payload() {
         let pp2 = PingPayload::from_signed_bytes("abc123", &vec![0, 1, 2, 3]);
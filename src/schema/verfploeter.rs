@@ -531,121 +531,53 @@ impl ::protobuf::reflect::ProtobufValue for TaskId {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ScheduleTask {
+pub struct ResultRequest {
     // message fields
-    pub client: ::protobuf::SingularPtrField<Client>,
-    // message oneof groups
-    pub data: ::std::option::Option<ScheduleTask_oneof_data>,
+    pub task_id: u32,
+    pub offset: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum ScheduleTask_oneof_data {
-    ping(Ping),
-}
-
-impl ScheduleTask {
-    pub fn new() -> ScheduleTask {
+impl ResultRequest {
+    pub fn new() -> ResultRequest {
         ::std::default::Default::default()
     }
 
-    // .Client client = 1;
-
-    pub fn clear_client(&mut self) {
-        self.client.clear();
-    }
+    // uint32 task_id = 1;
 
-    pub fn has_client(&self) -> bool {
-        self.client.is_some()
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_client(&mut self, v: Client) {
-        self.client = ::protobuf::SingularPtrField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_client(&mut self) -> &mut Client {
-        if self.client.is_none() {
-            self.client.set_default();
-        }
-        self.client.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_client(&mut self) -> Client {
-        self.client.take().unwrap_or_else(|| Client::new())
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
     }
 
-    pub fn get_client(&self) -> &Client {
-        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
     }
 
-    // .Ping ping = 2;
-
-    pub fn clear_ping(&mut self) {
-        self.data = ::std::option::Option::None;
-    }
+    // uint32 offset = 2;
 
-    pub fn has_ping(&self) -> bool {
-        match self.data {
-            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(..)) => true,
-            _ => false,
-        }
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: Ping) {
-        self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_ping(&mut self) -> &mut Ping {
-        if let ::std::option::Option::Some(ScheduleTask_oneof_data::ping(_)) = self.data {
-        } else {
-            self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(Ping::new()));
-        }
-        match self.data {
-            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_ping(&mut self) -> Ping {
-        if self.has_ping() {
-            match self.data.take() {
-                ::std::option::Option::Some(ScheduleTask_oneof_data::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ping::new()
-        }
+    pub fn set_offset(&mut self, v: u32) {
+        self.offset = v;
     }
 
-    pub fn get_ping(&self) -> &Ping {
-        match self.data {
-            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(ref v)) => v,
-            _ => Ping::default_instance(),
-        }
+    pub fn get_offset(&self) -> u32 {
+        self.offset
     }
 }
 
-impl ::protobuf::Message for ScheduleTask {
+impl ::protobuf::Message for ResultRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.client {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        if let Some(ScheduleTask_oneof_data::ping(ref v)) = self.data {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -654,13 +586,18 @@ impl ::protobuf::Message for ScheduleTask {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(is.read_message()?));
+                    let tmp = is.read_uint32()?;
+                    self.offset = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -674,17 +611,11 @@ impl ::protobuf::Message for ScheduleTask {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.client.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &ScheduleTask_oneof_data::ping(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -692,19 +623,11 @@ impl ::protobuf::Message for ScheduleTask {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.client.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &ScheduleTask_oneof_data::ping(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.offset != 0 {
+            os.write_uint32(2, self.offset)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -736,8 +659,8 @@ impl ::protobuf::Message for ScheduleTask {
         Self::descriptor_static()
     }
 
-    fn new() -> ScheduleTask {
-        ScheduleTask::new()
+    fn new() -> ResultRequest {
+        ResultRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -748,18 +671,18 @@ impl ::protobuf::Message for ScheduleTask {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
-                    "client",
-                    |m: &ScheduleTask| { &m.client },
-                    |m: &mut ScheduleTask| { &mut m.client },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &ResultRequest| { &m.task_id },
+                    |m: &mut ResultRequest| { &mut m.task_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
-                    "ping",
-                    ScheduleTask::has_ping,
-                    ScheduleTask::get_ping,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "offset",
+                    |m: &ResultRequest| { &m.offset },
+                    |m: &mut ResultRequest| { &mut m.offset },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ScheduleTask>(
-                    "ScheduleTask",
+                ::protobuf::reflect::MessageDescriptor::new::<ResultRequest>(
+                    "ResultRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -767,126 +690,6705 @@ impl ::protobuf::Message for ScheduleTask {
         }
     }
 
-    fn default_instance() -> &'static ScheduleTask {
-        static mut instance: ::protobuf::lazy::Lazy<ScheduleTask> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ResultRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ResultRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ScheduleTask,
+            ptr: 0 as *const ResultRequest,
         };
         unsafe {
-            instance.get(ScheduleTask::new)
+            instance.get(ResultRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ScheduleTask {
+impl ::protobuf::Clear for ResultRequest {
     fn clear(&mut self) {
-        self.clear_client();
-        self.clear_ping();
+        self.clear_task_id();
+        self.clear_offset();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ScheduleTask {
+impl ::std::fmt::Debug for ResultRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ScheduleTask {
+impl ::protobuf::reflect::ProtobufValue for ResultRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ClientList {
+pub struct ScheduleTask {
     // message fields
-    pub clients: ::protobuf::RepeatedField<Client>,
+    pub client: ::protobuf::SingularPtrField<Client>,
+    pub seed: u64,
+    pub destinations: ::protobuf::SingularPtrField<Destinations>,
+    pub grace_period: u32,
+    // message oneof groups
+    pub data: ::std::option::Option<ScheduleTask_oneof_data>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl ClientList {
-    pub fn new() -> ClientList {
+#[derive(Clone,PartialEq)]
+pub enum ScheduleTask_oneof_data {
+    ping(Ping),
+    tcp_syn(TcpSyn),
+    dns(Dns),
+}
+
+impl ScheduleTask {
+    pub fn new() -> ScheduleTask {
         ::std::default::Default::default()
     }
 
-    // repeated .Client clients = 1;
+    // .Client client = 1;
 
-    pub fn clear_clients(&mut self) {
-        self.clients.clear();
+    pub fn clear_client(&mut self) {
+        self.client.clear();
+    }
+
+    pub fn has_client(&self) -> bool {
+        self.client.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_clients(&mut self, v: ::protobuf::RepeatedField<Client>) {
-        self.clients = v;
+    pub fn set_client(&mut self, v: Client) {
+        self.client = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_clients(&mut self) -> &mut ::protobuf::RepeatedField<Client> {
-        &mut self.clients
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client(&mut self) -> &mut Client {
+        if self.client.is_none() {
+            self.client.set_default();
+        }
+        self.client.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_clients(&mut self) -> ::protobuf::RepeatedField<Client> {
-        ::std::mem::replace(&mut self.clients, ::protobuf::RepeatedField::new())
+    pub fn take_client(&mut self) -> Client {
+        self.client.take().unwrap_or_else(|| Client::new())
     }
 
-    pub fn get_clients(&self) -> &[Client] {
-        &self.clients
+    pub fn get_client(&self) -> &Client {
+        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
     }
-}
 
-impl ::protobuf::Message for ClientList {
-    fn is_initialized(&self) -> bool {
-        for v in &self.clients {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
-    }
+    // .Ping ping = 2;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.clients)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn clear_ping(&mut self) {
+        self.data = ::std::option::Option::None;
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        for value in &self.clients {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn has_ping(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(..)) => true,
+            _ => false,
+        }
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.clients {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(v))
     }
 
-    fn get_cached_size(&self) -> u32 {
+    // Mutable pointer to the field.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if let ::std::option::Option::Some(ScheduleTask_oneof_data::ping(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(Ping::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        if self.has_ping() {
+            match self.data.take() {
+                ::std::option::Option::Some(ScheduleTask_oneof_data::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ping::new()
+        }
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::ping(ref v)) => v,
+            _ => Ping::default_instance(),
+        }
+    }
+
+    // .TcpSyn tcp_syn = 3;
+
+    pub fn clear_tcp_syn(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_tcp_syn(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tcp_syn(&mut self, v: TcpSyn) {
+        self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tcp_syn(&mut self) -> &mut TcpSyn {
+        if let ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(TcpSyn::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tcp_syn(&mut self) -> TcpSyn {
+        if self.has_tcp_syn() {
+            match self.data.take() {
+                ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TcpSyn::new()
+        }
+    }
+
+    pub fn get_tcp_syn(&self) -> &TcpSyn {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(ref v)) => v,
+            _ => TcpSyn::default_instance(),
+        }
+    }
+
+    // .Dns dns = 4;
+
+    pub fn clear_dns(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_dns(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::dns(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dns(&mut self, v: Dns) {
+        self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::dns(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_dns(&mut self) -> &mut Dns {
+        if let ::std::option::Option::Some(ScheduleTask_oneof_data::dns(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::dns(Dns::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::dns(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_dns(&mut self) -> Dns {
+        if self.has_dns() {
+            match self.data.take() {
+                ::std::option::Option::Some(ScheduleTask_oneof_data::dns(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Dns::new()
+        }
+    }
+
+    pub fn get_dns(&self) -> &Dns {
+        match self.data {
+            ::std::option::Option::Some(ScheduleTask_oneof_data::dns(ref v)) => v,
+            _ => Dns::default_instance(),
+        }
+    }
+
+    // uint64 seed = 5;
+
+    pub fn clear_seed(&mut self) {
+        self.seed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seed(&mut self, v: u64) {
+        self.seed = v;
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // .Destinations destinations = 6;
+
+    pub fn clear_destinations(&mut self) {
+        self.destinations.clear();
+    }
+
+    pub fn has_destinations(&self) -> bool {
+        self.destinations.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations(&mut self, v: Destinations) {
+        self.destinations = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_destinations(&mut self) -> &mut Destinations {
+        if self.destinations.is_none() {
+            self.destinations.set_default();
+        }
+        self.destinations.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_destinations(&mut self) -> Destinations {
+        self.destinations.take().unwrap_or_else(|| Destinations::new())
+    }
+
+    pub fn get_destinations(&self) -> &Destinations {
+        self.destinations.as_ref().unwrap_or_else(|| Destinations::default_instance())
+    }
+
+    // uint32 grace_period = 7;
+
+    pub fn clear_grace_period(&mut self) {
+        self.grace_period = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grace_period(&mut self, v: u32) {
+        self.grace_period = v;
+    }
+
+    pub fn get_grace_period(&self) -> u32 {
+        self.grace_period
+    }
+}
+
+impl ::protobuf::Message for ScheduleTask {
+    fn is_initialized(&self) -> bool {
+        for v in &self.client {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(ScheduleTask_oneof_data::ping(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ScheduleTask_oneof_data::tcp_syn(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(ScheduleTask_oneof_data::dns(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        for v in &self.destinations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::ping(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::tcp_syn(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(ScheduleTask_oneof_data::dns(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seed = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.destinations)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.grace_period = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.client.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.seed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.destinations.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.grace_period != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.grace_period, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &ScheduleTask_oneof_data::ping(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ScheduleTask_oneof_data::tcp_syn(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &ScheduleTask_oneof_data::dns(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.client.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.seed != 0 {
+            os.write_uint64(5, self.seed)?;
+        }
+        if let Some(ref v) = self.destinations.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.grace_period != 0 {
+            os.write_uint32(7, self.grace_period)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &ScheduleTask_oneof_data::ping(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ScheduleTask_oneof_data::tcp_syn(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &ScheduleTask_oneof_data::dns(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScheduleTask {
+        ScheduleTask::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
+                    "client",
+                    |m: &ScheduleTask| { &m.client },
+                    |m: &mut ScheduleTask| { &mut m.client },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
+                    "ping",
+                    ScheduleTask::has_ping,
+                    ScheduleTask::get_ping,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, TcpSyn>(
+                    "tcp_syn",
+                    ScheduleTask::has_tcp_syn,
+                    ScheduleTask::get_tcp_syn,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Dns>(
+                    "dns",
+                    ScheduleTask::has_dns,
+                    ScheduleTask::get_dns,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seed",
+                    |m: &ScheduleTask| { &m.seed },
+                    |m: &mut ScheduleTask| { &mut m.seed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Destinations>>(
+                    "destinations",
+                    |m: &ScheduleTask| { &m.destinations },
+                    |m: &mut ScheduleTask| { &mut m.destinations },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "grace_period",
+                    |m: &ScheduleTask| { &m.grace_period },
+                    |m: &mut ScheduleTask| { &mut m.grace_period },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScheduleTask>(
+                    "ScheduleTask",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScheduleTask {
+        static mut instance: ::protobuf::lazy::Lazy<ScheduleTask> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScheduleTask,
+        };
+        unsafe {
+            instance.get(ScheduleTask::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScheduleTask {
+    fn clear(&mut self) {
+        self.clear_client();
+        self.clear_ping();
+        self.clear_tcp_syn();
+        self.clear_dns();
+        self.clear_seed();
+        self.clear_destinations();
+        self.clear_grace_period();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScheduleTask {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScheduleTask {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScheduleCampaign {
+    // message fields
+    pub client: ::protobuf::SingularPtrField<Client>,
+    pub ping: ::protobuf::SingularPtrField<Ping>,
+    pub rotate: bool,
+    pub seed: u64,
+    pub destinations: ::protobuf::SingularPtrField<Destinations>,
+    pub grace_period: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ScheduleCampaign {
+    pub fn new() -> ScheduleCampaign {
+        ::std::default::Default::default()
+    }
+
+    // .Client client = 1;
+
+    pub fn clear_client(&mut self) {
+        self.client.clear();
+    }
+
+    pub fn has_client(&self) -> bool {
+        self.client.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client(&mut self, v: Client) {
+        self.client = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client(&mut self) -> &mut Client {
+        if self.client.is_none() {
+            self.client.set_default();
+        }
+        self.client.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_client(&mut self) -> Client {
+        self.client.take().unwrap_or_else(|| Client::new())
+    }
+
+    pub fn get_client(&self) -> &Client {
+        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
+    }
+
+    // .Ping ping = 2;
+
+    pub fn clear_ping(&mut self) {
+        self.ping.clear();
+    }
+
+    pub fn has_ping(&self) -> bool {
+        self.ping.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.ping = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if self.ping.is_none() {
+            self.ping.set_default();
+        }
+        self.ping.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        self.ping.take().unwrap_or_else(|| Ping::new())
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        self.ping.as_ref().unwrap_or_else(|| Ping::default_instance())
+    }
+
+    // bool rotate = 3;
+
+    pub fn clear_rotate(&mut self) {
+        self.rotate = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rotate(&mut self, v: bool) {
+        self.rotate = v;
+    }
+
+    pub fn get_rotate(&self) -> bool {
+        self.rotate
+    }
+
+    // uint64 seed = 4;
+
+    pub fn clear_seed(&mut self) {
+        self.seed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seed(&mut self, v: u64) {
+        self.seed = v;
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // .Destinations destinations = 5;
+
+    pub fn clear_destinations(&mut self) {
+        self.destinations.clear();
+    }
+
+    pub fn has_destinations(&self) -> bool {
+        self.destinations.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations(&mut self, v: Destinations) {
+        self.destinations = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_destinations(&mut self) -> &mut Destinations {
+        if self.destinations.is_none() {
+            self.destinations.set_default();
+        }
+        self.destinations.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_destinations(&mut self) -> Destinations {
+        self.destinations.take().unwrap_or_else(|| Destinations::new())
+    }
+
+    pub fn get_destinations(&self) -> &Destinations {
+        self.destinations.as_ref().unwrap_or_else(|| Destinations::default_instance())
+    }
+
+    // uint32 grace_period = 6;
+
+    pub fn clear_grace_period(&mut self) {
+        self.grace_period = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grace_period(&mut self, v: u32) {
+        self.grace_period = v;
+    }
+
+    pub fn get_grace_period(&self) -> u32 {
+        self.grace_period
+    }
+}
+
+impl ::protobuf::Message for ScheduleCampaign {
+    fn is_initialized(&self) -> bool {
+        for v in &self.client {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ping {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.destinations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ping)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.rotate = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seed = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.destinations)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.grace_period = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.client.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.ping.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.rotate != false {
+            my_size += 2;
+        }
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.seed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.destinations.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.grace_period != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.grace_period, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.client.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.ping.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.rotate != false {
+            os.write_bool(3, self.rotate)?;
+        }
+        if self.seed != 0 {
+            os.write_uint64(4, self.seed)?;
+        }
+        if let Some(ref v) = self.destinations.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.grace_period != 0 {
+            os.write_uint32(6, self.grace_period)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScheduleCampaign {
+        ScheduleCampaign::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
+                    "client",
+                    |m: &ScheduleCampaign| { &m.client },
+                    |m: &mut ScheduleCampaign| { &mut m.client },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ping>>(
+                    "ping",
+                    |m: &ScheduleCampaign| { &m.ping },
+                    |m: &mut ScheduleCampaign| { &mut m.ping },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "rotate",
+                    |m: &ScheduleCampaign| { &m.rotate },
+                    |m: &mut ScheduleCampaign| { &mut m.rotate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seed",
+                    |m: &ScheduleCampaign| { &m.seed },
+                    |m: &mut ScheduleCampaign| { &mut m.seed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Destinations>>(
+                    "destinations",
+                    |m: &ScheduleCampaign| { &m.destinations },
+                    |m: &mut ScheduleCampaign| { &mut m.destinations },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "grace_period",
+                    |m: &ScheduleCampaign| { &m.grace_period },
+                    |m: &mut ScheduleCampaign| { &mut m.grace_period },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScheduleCampaign>(
+                    "ScheduleCampaign",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScheduleCampaign {
+        static mut instance: ::protobuf::lazy::Lazy<ScheduleCampaign> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScheduleCampaign,
+        };
+        unsafe {
+            instance.get(ScheduleCampaign::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScheduleCampaign {
+    fn clear(&mut self) {
+        self.clear_client();
+        self.clear_ping();
+        self.clear_rotate();
+        self.clear_seed();
+        self.clear_destinations();
+        self.clear_grace_period();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScheduleCampaign {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScheduleCampaign {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Schedule {
+    // message fields
+    pub schedule_id: u32,
+    pub task: ::protobuf::SingularPtrField<ScheduleTask>,
+    pub cron: ::std::string::String,
+    pub interval: u64,
+    pub start_at: u64,
+    pub next_run: u64,
+    pub owner: ::std::string::String,
+    pub runs: ::protobuf::RepeatedField<ScheduleRun>,
+    pub destinations: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Schedule {
+    pub fn new() -> Schedule {
+        ::std::default::Default::default()
+    }
+
+    // uint32 schedule_id = 1;
+
+    pub fn clear_schedule_id(&mut self) {
+        self.schedule_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedule_id(&mut self, v: u32) {
+        self.schedule_id = v;
+    }
+
+    pub fn get_schedule_id(&self) -> u32 {
+        self.schedule_id
+    }
+
+    // .ScheduleTask task = 2;
+
+    pub fn clear_task(&mut self) {
+        self.task.clear();
+    }
+
+    pub fn has_task(&self) -> bool {
+        self.task.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task(&mut self, v: ScheduleTask) {
+        self.task = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_task(&mut self) -> &mut ScheduleTask {
+        if self.task.is_none() {
+            self.task.set_default();
+        }
+        self.task.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_task(&mut self) -> ScheduleTask {
+        self.task.take().unwrap_or_else(|| ScheduleTask::new())
+    }
+
+    pub fn get_task(&self) -> &ScheduleTask {
+        self.task.as_ref().unwrap_or_else(|| ScheduleTask::default_instance())
+    }
+
+    // string cron = 3;
+
+    pub fn clear_cron(&mut self) {
+        self.cron.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron(&mut self, v: ::std::string::String) {
+        self.cron = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron(&mut self) -> &mut ::std::string::String {
+        &mut self.cron
+    }
+
+    // Take field
+    pub fn take_cron(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.cron, ::std::string::String::new())
+    }
+
+    pub fn get_cron(&self) -> &str {
+        &self.cron
+    }
+
+    // uint64 interval = 4;
+
+    pub fn clear_interval(&mut self) {
+        self.interval = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_interval(&mut self, v: u64) {
+        self.interval = v;
+    }
+
+    pub fn get_interval(&self) -> u64 {
+        self.interval
+    }
+
+    // uint64 start_at = 5;
+
+    pub fn clear_start_at(&mut self) {
+        self.start_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_at(&mut self, v: u64) {
+        self.start_at = v;
+    }
+
+    pub fn get_start_at(&self) -> u64 {
+        self.start_at
+    }
+
+    // uint64 next_run = 6;
+
+    pub fn clear_next_run(&mut self) {
+        self.next_run = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_run(&mut self, v: u64) {
+        self.next_run = v;
+    }
+
+    pub fn get_next_run(&self) -> u64 {
+        self.next_run
+    }
+
+    // string owner = 7;
+
+    pub fn clear_owner(&mut self) {
+        self.owner.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner(&mut self, v: ::std::string::String) {
+        self.owner = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner(&mut self) -> &mut ::std::string::String {
+        &mut self.owner
+    }
+
+    // Take field
+    pub fn take_owner(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner, ::std::string::String::new())
+    }
+
+    pub fn get_owner(&self) -> &str {
+        &self.owner
+    }
+
+    // repeated .ScheduleRun runs = 8;
+
+    pub fn clear_runs(&mut self) {
+        self.runs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_runs(&mut self, v: ::protobuf::RepeatedField<ScheduleRun>) {
+        self.runs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_runs(&mut self) -> &mut ::protobuf::RepeatedField<ScheduleRun> {
+        &mut self.runs
+    }
+
+    // Take field
+    pub fn take_runs(&mut self) -> ::protobuf::RepeatedField<ScheduleRun> {
+        ::std::mem::replace(&mut self.runs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_runs(&self) -> &[ScheduleRun] {
+        &self.runs
+    }
+
+    // uint32 destinations = 9;
+
+    pub fn clear_destinations(&mut self) {
+        self.destinations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations(&mut self, v: u32) {
+        self.destinations = v;
+    }
+
+    pub fn get_destinations(&self) -> u32 {
+        self.destinations
+    }
+}
+
+impl ::protobuf::Message for Schedule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.task {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.runs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.schedule_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.task)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.cron)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.interval = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_at = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.next_run = tmp;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.owner)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.runs)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.destinations = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.schedule_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.schedule_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.task.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.cron.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.cron);
+        }
+        if self.interval != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.interval, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.start_at != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.start_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.next_run != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.next_run, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.owner.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.owner);
+        }
+        for value in &self.runs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.destinations != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.destinations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.schedule_id != 0 {
+            os.write_uint32(1, self.schedule_id)?;
+        }
+        if let Some(ref v) = self.task.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.cron.is_empty() {
+            os.write_string(3, &self.cron)?;
+        }
+        if self.interval != 0 {
+            os.write_uint64(4, self.interval)?;
+        }
+        if self.start_at != 0 {
+            os.write_uint64(5, self.start_at)?;
+        }
+        if self.next_run != 0 {
+            os.write_uint64(6, self.next_run)?;
+        }
+        if !self.owner.is_empty() {
+            os.write_string(7, &self.owner)?;
+        }
+        for v in &self.runs {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.destinations != 0 {
+            os.write_uint32(9, self.destinations)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Schedule {
+        Schedule::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "schedule_id",
+                    |m: &Schedule| { &m.schedule_id },
+                    |m: &mut Schedule| { &mut m.schedule_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ScheduleTask>>(
+                    "task",
+                    |m: &Schedule| { &m.task },
+                    |m: &mut Schedule| { &mut m.task },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cron",
+                    |m: &Schedule| { &m.cron },
+                    |m: &mut Schedule| { &mut m.cron },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "interval",
+                    |m: &Schedule| { &m.interval },
+                    |m: &mut Schedule| { &mut m.interval },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_at",
+                    |m: &Schedule| { &m.start_at },
+                    |m: &mut Schedule| { &mut m.start_at },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "next_run",
+                    |m: &Schedule| { &m.next_run },
+                    |m: &mut Schedule| { &mut m.next_run },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "owner",
+                    |m: &Schedule| { &m.owner },
+                    |m: &mut Schedule| { &mut m.owner },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ScheduleRun>>(
+                    "runs",
+                    |m: &Schedule| { &m.runs },
+                    |m: &mut Schedule| { &mut m.runs },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "destinations",
+                    |m: &Schedule| { &m.destinations },
+                    |m: &mut Schedule| { &mut m.destinations },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Schedule>(
+                    "Schedule",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Schedule {
+        static mut instance: ::protobuf::lazy::Lazy<Schedule> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Schedule,
+        };
+        unsafe {
+            instance.get(Schedule::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Schedule {
+    fn clear(&mut self) {
+        self.clear_schedule_id();
+        self.clear_task();
+        self.clear_cron();
+        self.clear_interval();
+        self.clear_start_at();
+        self.clear_next_run();
+        self.clear_owner();
+        self.clear_runs();
+        self.clear_destinations();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Schedule {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScheduleRun {
+    // message fields
+    pub task_id: u32,
+    pub start_time: u64,
+    pub error_message: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ScheduleRun {
+    pub fn new() -> ScheduleRun {
+        ::std::default::Default::default()
+    }
+
+    // uint32 task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // uint64 start_time = 2;
+
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    // string error_message = 3;
+
+    pub fn clear_error_message(&mut self) {
+        self.error_message.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error_message(&mut self, v: ::std::string::String) {
+        self.error_message = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error_message(&mut self) -> &mut ::std::string::String {
+        &mut self.error_message
+    }
+
+    // Take field
+    pub fn take_error_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error_message, ::std::string::String::new())
+    }
+
+    pub fn get_error_message(&self) -> &str {
+        &self.error_message
+    }
+}
+
+impl ::protobuf::Message for ScheduleRun {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error_message)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.error_message.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.error_message);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(2, self.start_time)?;
+        }
+        if !self.error_message.is_empty() {
+            os.write_string(3, &self.error_message)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScheduleRun {
+        ScheduleRun::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &ScheduleRun| { &m.task_id },
+                    |m: &mut ScheduleRun| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_time",
+                    |m: &ScheduleRun| { &m.start_time },
+                    |m: &mut ScheduleRun| { &mut m.start_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error_message",
+                    |m: &ScheduleRun| { &m.error_message },
+                    |m: &mut ScheduleRun| { &mut m.error_message },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScheduleRun>(
+                    "ScheduleRun",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScheduleRun {
+        static mut instance: ::protobuf::lazy::Lazy<ScheduleRun> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScheduleRun,
+        };
+        unsafe {
+            instance.get(ScheduleRun::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScheduleRun {
+    fn clear(&mut self) {
+        self.clear_task_id();
+        self.clear_start_time();
+        self.clear_error_message();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScheduleRun {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScheduleRun {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScheduleList {
+    // message fields
+    pub schedules: ::protobuf::RepeatedField<Schedule>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ScheduleList {
+    pub fn new() -> ScheduleList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Schedule schedules = 1;
+
+    pub fn clear_schedules(&mut self) {
+        self.schedules.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedules(&mut self, v: ::protobuf::RepeatedField<Schedule>) {
+        self.schedules = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_schedules(&mut self) -> &mut ::protobuf::RepeatedField<Schedule> {
+        &mut self.schedules
+    }
+
+    // Take field
+    pub fn take_schedules(&mut self) -> ::protobuf::RepeatedField<Schedule> {
+        ::std::mem::replace(&mut self.schedules, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_schedules(&self) -> &[Schedule] {
+        &self.schedules
+    }
+}
+
+impl ::protobuf::Message for ScheduleList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.schedules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.schedules)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.schedules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.schedules {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScheduleList {
+        ScheduleList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Schedule>>(
+                    "schedules",
+                    |m: &ScheduleList| { &m.schedules },
+                    |m: &mut ScheduleList| { &mut m.schedules },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScheduleList>(
+                    "ScheduleList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScheduleList {
+        static mut instance: ::protobuf::lazy::Lazy<ScheduleList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScheduleList,
+        };
+        unsafe {
+            instance.get(ScheduleList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScheduleList {
+    fn clear(&mut self) {
+        self.clear_schedules();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScheduleList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScheduleList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ScheduleId {
+    // message fields
+    pub schedule_id: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ScheduleId {
+    pub fn new() -> ScheduleId {
+        ::std::default::Default::default()
+    }
+
+    // uint32 schedule_id = 1;
+
+    pub fn clear_schedule_id(&mut self) {
+        self.schedule_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedule_id(&mut self, v: u32) {
+        self.schedule_id = v;
+    }
+
+    pub fn get_schedule_id(&self) -> u32 {
+        self.schedule_id
+    }
+}
+
+impl ::protobuf::Message for ScheduleId {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.schedule_id = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.schedule_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.schedule_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.schedule_id != 0 {
+            os.write_uint32(1, self.schedule_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ScheduleId {
+        ScheduleId::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "schedule_id",
+                    |m: &ScheduleId| { &m.schedule_id },
+                    |m: &mut ScheduleId| { &mut m.schedule_id },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ScheduleId>(
+                    "ScheduleId",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ScheduleId {
+        static mut instance: ::protobuf::lazy::Lazy<ScheduleId> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ScheduleId,
+        };
+        unsafe {
+            instance.get(ScheduleId::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ScheduleId {
+    fn clear(&mut self) {
+        self.clear_schedule_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ScheduleId {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ScheduleId {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ClientList {
+    // message fields
+    pub clients: ::protobuf::RepeatedField<Client>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl ClientList {
+    pub fn new() -> ClientList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Client clients = 1;
+
+    pub fn clear_clients(&mut self) {
+        self.clients.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clients(&mut self, v: ::protobuf::RepeatedField<Client>) {
+        self.clients = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_clients(&mut self) -> &mut ::protobuf::RepeatedField<Client> {
+        &mut self.clients
+    }
+
+    // Take field
+    pub fn take_clients(&mut self) -> ::protobuf::RepeatedField<Client> {
+        ::std::mem::replace(&mut self.clients, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_clients(&self) -> &[Client] {
+        &self.clients
+    }
+}
+
+impl ::protobuf::Message for ClientList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.clients {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.clients)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.clients {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.clients {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ClientList {
+        ClientList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
+                    "clients",
+                    |m: &ClientList| { &m.clients },
+                    |m: &mut ClientList| { &mut m.clients },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ClientList>(
+                    "ClientList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static ClientList {
+        static mut instance: ::protobuf::lazy::Lazy<ClientList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ClientList,
+        };
+        unsafe {
+            instance.get(ClientList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for ClientList {
+    fn clear(&mut self) {
+        self.clear_clients();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ClientList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClientList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client {
+    // message fields
+    pub index: u32,
+    pub metadata: ::protobuf::SingularPtrField<Metadata>,
+    pub clock: ::protobuf::SingularPtrField<ClockOffset>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Client {
+    pub fn new() -> Client {
+        ::std::default::Default::default()
+    }
+
+    // uint32 index = 1;
+
+    pub fn clear_index(&mut self) {
+        self.index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: u32) {
+        self.index = v;
+    }
+
+    pub fn get_index(&self) -> u32 {
+        self.index
+    }
+
+    // .Metadata metadata = 2;
+
+    pub fn clear_metadata(&mut self) {
+        self.metadata.clear();
+    }
+
+    pub fn has_metadata(&self) -> bool {
+        self.metadata.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_metadata(&mut self, v: Metadata) {
+        self.metadata = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_metadata(&mut self) -> &mut Metadata {
+        if self.metadata.is_none() {
+            self.metadata.set_default();
+        }
+        self.metadata.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_metadata(&mut self) -> Metadata {
+        self.metadata.take().unwrap_or_else(|| Metadata::new())
+    }
+
+    pub fn get_metadata(&self) -> &Metadata {
+        self.metadata.as_ref().unwrap_or_else(|| Metadata::default_instance())
+    }
+
+    // .ClockOffset clock = 3;
+
+    pub fn clear_clock(&mut self) {
+        self.clock.clear();
+    }
+
+    pub fn has_clock(&self) -> bool {
+        self.clock.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clock(&mut self, v: ClockOffset) {
+        self.clock = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clock(&mut self) -> &mut ClockOffset {
+        if self.clock.is_none() {
+            self.clock.set_default();
+        }
+        self.clock.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_clock(&mut self) -> ClockOffset {
+        self.clock.take().unwrap_or_else(|| ClockOffset::new())
+    }
+
+    pub fn get_clock(&self) -> &ClockOffset {
+        self.clock.as_ref().unwrap_or_else(|| ClockOffset::default_instance())
+    }
+}
+
+impl ::protobuf::Message for Client {
+    fn is_initialized(&self) -> bool {
+        for v in &self.metadata {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.clock {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.index = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.metadata)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.clock)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.index != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.metadata.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.clock.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.index != 0 {
+            os.write_uint32(1, self.index)?;
+        }
+        if let Some(ref v) = self.metadata.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.clock.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client {
+        Client::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "index",
+                    |m: &Client| { &m.index },
+                    |m: &mut Client| { &mut m.index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Metadata>>(
+                    "metadata",
+                    |m: &Client| { &m.metadata },
+                    |m: &mut Client| { &mut m.metadata },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ClockOffset>>(
+                    "clock",
+                    |m: &Client| { &m.clock },
+                    |m: &mut Client| { &mut m.clock },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Client>(
+                    "Client",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Client {
+        static mut instance: ::protobuf::lazy::Lazy<Client> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Client,
+        };
+        unsafe {
+            instance.get(Client::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Client {
+    fn clear(&mut self) {
+        self.clear_index();
+        self.clear_metadata();
+        self.clear_clock();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task {
+    // message fields
+    pub task_id: u32,
+    pub seed: u64,
+    pub grace_period: u32,
+    // message oneof groups
+    pub data: ::std::option::Option<Task_oneof_data>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+#[derive(Clone,PartialEq)]
+pub enum Task_oneof_data {
+    ping(Ping),
+    empty(Empty),
+    keys(KeyList),
+    cancel(Empty),
+    tcp_syn(TcpSyn),
+    dns(Dns),
+    destinations(Destinations),
+    shutdown(Empty),
+}
+
+impl Task {
+    pub fn new() -> Task {
+        ::std::default::Default::default()
+    }
+
+    // uint32 task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // .Ping ping = 2;
+
+    pub fn clear_ping(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_ping(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::ping(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ping(&mut self, v: Ping) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::ping(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ping(&mut self) -> &mut Ping {
+        if let ::std::option::Option::Some(Task_oneof_data::ping(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::ping(Ping::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::ping(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_ping(&mut self) -> Ping {
+        if self.has_ping() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Ping::new()
+        }
+    }
+
+    pub fn get_ping(&self) -> &Ping {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::ping(ref v)) => v,
+            _ => Ping::default_instance(),
+        }
+    }
+
+    // .Empty empty = 3;
+
+    pub fn clear_empty(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_empty(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::empty(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_empty(&mut self, v: Empty) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::empty(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_empty(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Task_oneof_data::empty(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::empty(Empty::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::empty(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_empty(&mut self) -> Empty {
+        if self.has_empty() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::empty(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+
+    pub fn get_empty(&self) -> &Empty {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::empty(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+
+    // .KeyList keys = 4;
+
+    pub fn clear_keys(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_keys(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::keys(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: KeyList) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::keys(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut KeyList {
+        if let ::std::option::Option::Some(Task_oneof_data::keys(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::keys(KeyList::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::keys(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> KeyList {
+        if self.has_keys() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::keys(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            KeyList::new()
+        }
+    }
+
+    pub fn get_keys(&self) -> &KeyList {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::keys(ref v)) => v,
+            _ => KeyList::default_instance(),
+        }
+    }
+
+    // .Empty cancel = 5;
+
+    pub fn clear_cancel(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_cancel(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::cancel(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cancel(&mut self, v: Empty) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::cancel(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cancel(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Task_oneof_data::cancel(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::cancel(Empty::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::cancel(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_cancel(&mut self) -> Empty {
+        if self.has_cancel() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::cancel(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+
+    pub fn get_cancel(&self) -> &Empty {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::cancel(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+
+    // .TcpSyn tcp_syn = 6;
+
+    pub fn clear_tcp_syn(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_tcp_syn(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::tcp_syn(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tcp_syn(&mut self, v: TcpSyn) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::tcp_syn(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tcp_syn(&mut self) -> &mut TcpSyn {
+        if let ::std::option::Option::Some(Task_oneof_data::tcp_syn(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::tcp_syn(TcpSyn::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::tcp_syn(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tcp_syn(&mut self) -> TcpSyn {
+        if self.has_tcp_syn() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::tcp_syn(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TcpSyn::new()
+        }
+    }
+
+    pub fn get_tcp_syn(&self) -> &TcpSyn {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::tcp_syn(ref v)) => v,
+            _ => TcpSyn::default_instance(),
+        }
+    }
+
+    // .Dns dns = 7;
+
+    pub fn clear_dns(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_dns(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::dns(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dns(&mut self, v: Dns) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::dns(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_dns(&mut self) -> &mut Dns {
+        if let ::std::option::Option::Some(Task_oneof_data::dns(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::dns(Dns::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::dns(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_dns(&mut self) -> Dns {
+        if self.has_dns() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::dns(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Dns::new()
+        }
+    }
+
+    pub fn get_dns(&self) -> &Dns {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::dns(ref v)) => v,
+            _ => Dns::default_instance(),
+        }
+    }
+
+    // .Destinations destinations = 9;
+
+    pub fn clear_destinations(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_destinations(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::destinations(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations(&mut self, v: Destinations) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::destinations(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_destinations(&mut self) -> &mut Destinations {
+        if let ::std::option::Option::Some(Task_oneof_data::destinations(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::destinations(Destinations::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::destinations(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_destinations(&mut self) -> Destinations {
+        if self.has_destinations() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::destinations(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Destinations::new()
+        }
+    }
+
+    pub fn get_destinations(&self) -> &Destinations {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::destinations(ref v)) => v,
+            _ => Destinations::default_instance(),
+        }
+    }
+
+    // .Empty shutdown = 10;
+
+    pub fn clear_shutdown(&mut self) {
+        self.data = ::std::option::Option::None;
+    }
+
+    pub fn has_shutdown(&self) -> bool {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::shutdown(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shutdown(&mut self, v: Empty) {
+        self.data = ::std::option::Option::Some(Task_oneof_data::shutdown(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shutdown(&mut self) -> &mut Empty {
+        if let ::std::option::Option::Some(Task_oneof_data::shutdown(_)) = self.data {
+        } else {
+            self.data = ::std::option::Option::Some(Task_oneof_data::shutdown(Empty::new()));
+        }
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::shutdown(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_shutdown(&mut self) -> Empty {
+        if self.has_shutdown() {
+            match self.data.take() {
+                ::std::option::Option::Some(Task_oneof_data::shutdown(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Empty::new()
+        }
+    }
+
+    pub fn get_shutdown(&self) -> &Empty {
+        match self.data {
+            ::std::option::Option::Some(Task_oneof_data::shutdown(ref v)) => v,
+            _ => Empty::default_instance(),
+        }
+    }
+
+    // uint64 seed = 8;
+
+    pub fn clear_seed(&mut self) {
+        self.seed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seed(&mut self, v: u64) {
+        self.seed = v;
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // uint32 grace_period = 11;
+
+    pub fn clear_grace_period(&mut self) {
+        self.grace_period = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_grace_period(&mut self, v: u32) {
+        self.grace_period = v;
+    }
+
+    pub fn get_grace_period(&self) -> u32 {
+        self.grace_period
+    }
+}
+
+impl ::protobuf::Message for Task {
+    fn is_initialized(&self) -> bool {
+        if let Some(Task_oneof_data::ping(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::empty(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::keys(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::cancel(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::tcp_syn(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::dns(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::destinations(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_data::shutdown(ref v)) = self.data {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::ping(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::empty(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::keys(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::cancel(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::tcp_syn(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::dns(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::destinations(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.data = ::std::option::Option::Some(Task_oneof_data::shutdown(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seed = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.grace_period = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.seed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.grace_period != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.grace_period, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &Task_oneof_data::ping(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::empty(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::keys(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::cancel(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::tcp_syn(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::dns(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::destinations(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_data::shutdown(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
+        }
+        if self.seed != 0 {
+            os.write_uint64(8, self.seed)?;
+        }
+        if self.grace_period != 0 {
+            os.write_uint32(11, self.grace_period)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.data {
+            match v {
+                &Task_oneof_data::ping(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::empty(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::keys(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::cancel(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::tcp_syn(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::dns(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::destinations(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_data::shutdown(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task {
+        Task::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &Task| { &m.task_id },
+                    |m: &mut Task| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
+                    "ping",
+                    Task::has_ping,
+                    Task::get_ping,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "empty",
+                    Task::has_empty,
+                    Task::get_empty,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, KeyList>(
+                    "keys",
+                    Task::has_keys,
+                    Task::get_keys,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "cancel",
+                    Task::has_cancel,
+                    Task::get_cancel,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, TcpSyn>(
+                    "tcp_syn",
+                    Task::has_tcp_syn,
+                    Task::get_tcp_syn,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Dns>(
+                    "dns",
+                    Task::has_dns,
+                    Task::get_dns,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Destinations>(
+                    "destinations",
+                    Task::has_destinations,
+                    Task::get_destinations,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
+                    "shutdown",
+                    Task::has_shutdown,
+                    Task::get_shutdown,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seed",
+                    |m: &Task| { &m.seed },
+                    |m: &mut Task| { &mut m.seed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "grace_period",
+                    |m: &Task| { &m.grace_period },
+                    |m: &mut Task| { &mut m.grace_period },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Task>(
+                    "Task",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Task {
+        static mut instance: ::protobuf::lazy::Lazy<Task> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Task,
+        };
+        unsafe {
+            instance.get(Task::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Task {
+    fn clear(&mut self) {
+        self.clear_task_id();
+        self.clear_ping();
+        self.clear_empty();
+        self.clear_keys();
+        self.clear_cancel();
+        self.clear_tcp_syn();
+        self.clear_dns();
+        self.clear_destinations();
+        self.clear_shutdown();
+        self.clear_seed();
+        self.clear_grace_period();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TaskStatus {
+    // message fields
+    pub task_id: u32,
+    pub campaign_id: u32,
+    pub client: ::protobuf::SingularPtrField<Client>,
+    pub state: ::std::string::String,
+    pub destinations: u32,
+    pub destinations_sent: u32,
+    pub start_time: u64,
+    pub rate: u32,
+    pub seed: u64,
+    pub owner: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl TaskStatus {
+    pub fn new() -> TaskStatus {
+        ::std::default::Default::default()
+    }
+
+    // uint32 task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // uint32 campaign_id = 2;
+
+    pub fn clear_campaign_id(&mut self) {
+        self.campaign_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_campaign_id(&mut self, v: u32) {
+        self.campaign_id = v;
+    }
+
+    pub fn get_campaign_id(&self) -> u32 {
+        self.campaign_id
+    }
+
+    // .Client client = 3;
+
+    pub fn clear_client(&mut self) {
+        self.client.clear();
+    }
+
+    pub fn has_client(&self) -> bool {
+        self.client.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client(&mut self, v: Client) {
+        self.client = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client(&mut self) -> &mut Client {
+        if self.client.is_none() {
+            self.client.set_default();
+        }
+        self.client.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_client(&mut self) -> Client {
+        self.client.take().unwrap_or_else(|| Client::new())
+    }
+
+    pub fn get_client(&self) -> &Client {
+        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
+    }
+
+    // string state = 4;
+
+    pub fn clear_state(&mut self) {
+        self.state.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: ::std::string::String) {
+        self.state = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_state(&mut self) -> &mut ::std::string::String {
+        &mut self.state
+    }
+
+    // Take field
+    pub fn take_state(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.state, ::std::string::String::new())
+    }
+
+    pub fn get_state(&self) -> &str {
+        &self.state
+    }
+
+    // uint32 destinations = 5;
+
+    pub fn clear_destinations(&mut self) {
+        self.destinations = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations(&mut self, v: u32) {
+        self.destinations = v;
+    }
+
+    pub fn get_destinations(&self) -> u32 {
+        self.destinations
+    }
+
+    // uint32 destinations_sent = 6;
+
+    pub fn clear_destinations_sent(&mut self) {
+        self.destinations_sent = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations_sent(&mut self, v: u32) {
+        self.destinations_sent = v;
+    }
+
+    pub fn get_destinations_sent(&self) -> u32 {
+        self.destinations_sent
+    }
+
+    // uint64 start_time = 7;
+
+    pub fn clear_start_time(&mut self) {
+        self.start_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start_time(&mut self, v: u64) {
+        self.start_time = v;
+    }
+
+    pub fn get_start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    // uint32 rate = 8;
+
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u32) {
+        self.rate = v;
+    }
+
+    pub fn get_rate(&self) -> u32 {
+        self.rate
+    }
+
+    // uint64 seed = 9;
+
+    pub fn clear_seed(&mut self) {
+        self.seed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seed(&mut self, v: u64) {
+        self.seed = v;
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    // string owner = 10;
+
+    pub fn clear_owner(&mut self) {
+        self.owner.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner(&mut self, v: ::std::string::String) {
+        self.owner = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_owner(&mut self) -> &mut ::std::string::String {
+        &mut self.owner
+    }
+
+    // Take field
+    pub fn take_owner(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.owner, ::std::string::String::new())
+    }
+
+    pub fn get_owner(&self) -> &str {
+        &self.owner
+    }
+}
+
+impl ::protobuf::Message for TaskStatus {
+    fn is_initialized(&self) -> bool {
+        for v in &self.client {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.campaign_id = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.state)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.destinations = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.destinations_sent = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start_time = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rate = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seed = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.owner)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.campaign_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.campaign_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.client.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.state.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.state);
+        }
+        if self.destinations != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.destinations, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.destinations_sent != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.destinations_sent, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.start_time != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.start_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.seed != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.seed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.owner.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.owner);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
+        }
+        if self.campaign_id != 0 {
+            os.write_uint32(2, self.campaign_id)?;
+        }
+        if let Some(ref v) = self.client.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.state.is_empty() {
+            os.write_string(4, &self.state)?;
+        }
+        if self.destinations != 0 {
+            os.write_uint32(5, self.destinations)?;
+        }
+        if self.destinations_sent != 0 {
+            os.write_uint32(6, self.destinations_sent)?;
+        }
+        if self.start_time != 0 {
+            os.write_uint64(7, self.start_time)?;
+        }
+        if self.rate != 0 {
+            os.write_uint32(8, self.rate)?;
+        }
+        if self.seed != 0 {
+            os.write_uint64(9, self.seed)?;
+        }
+        if !self.owner.is_empty() {
+            os.write_string(10, &self.owner)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TaskStatus {
+        TaskStatus::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &TaskStatus| { &m.task_id },
+                    |m: &mut TaskStatus| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "campaign_id",
+                    |m: &TaskStatus| { &m.campaign_id },
+                    |m: &mut TaskStatus| { &mut m.campaign_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
+                    "client",
+                    |m: &TaskStatus| { &m.client },
+                    |m: &mut TaskStatus| { &mut m.client },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "state",
+                    |m: &TaskStatus| { &m.state },
+                    |m: &mut TaskStatus| { &mut m.state },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "destinations",
+                    |m: &TaskStatus| { &m.destinations },
+                    |m: &mut TaskStatus| { &mut m.destinations },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "destinations_sent",
+                    |m: &TaskStatus| { &m.destinations_sent },
+                    |m: &mut TaskStatus| { &mut m.destinations_sent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start_time",
+                    |m: &TaskStatus| { &m.start_time },
+                    |m: &mut TaskStatus| { &mut m.start_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rate",
+                    |m: &TaskStatus| { &m.rate },
+                    |m: &mut TaskStatus| { &mut m.rate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seed",
+                    |m: &TaskStatus| { &m.seed },
+                    |m: &mut TaskStatus| { &mut m.seed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "owner",
+                    |m: &TaskStatus| { &m.owner },
+                    |m: &mut TaskStatus| { &mut m.owner },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskStatus>(
+                    "TaskStatus",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TaskStatus {
+        static mut instance: ::protobuf::lazy::Lazy<TaskStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TaskStatus,
+        };
+        unsafe {
+            instance.get(TaskStatus::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TaskStatus {
+    fn clear(&mut self) {
+        self.clear_task_id();
+        self.clear_campaign_id();
+        self.clear_client();
+        self.clear_state();
+        self.clear_destinations();
+        self.clear_destinations_sent();
+        self.clear_start_time();
+        self.clear_rate();
+        self.clear_seed();
+        self.clear_owner();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TaskStatus {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TaskStatus {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TaskStatusList {
+    // message fields
+    pub tasks: ::protobuf::RepeatedField<TaskStatus>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl TaskStatusList {
+    pub fn new() -> TaskStatusList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .TaskStatus tasks = 1;
+
+    pub fn clear_tasks(&mut self) {
+        self.tasks.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tasks(&mut self, v: ::protobuf::RepeatedField<TaskStatus>) {
+        self.tasks = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tasks(&mut self) -> &mut ::protobuf::RepeatedField<TaskStatus> {
+        &mut self.tasks
+    }
+
+    // Take field
+    pub fn take_tasks(&mut self) -> ::protobuf::RepeatedField<TaskStatus> {
+        ::std::mem::replace(&mut self.tasks, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tasks(&self) -> &[TaskStatus] {
+        &self.tasks
+    }
+}
+
+impl ::protobuf::Message for TaskStatusList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tasks {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tasks)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.tasks {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.tasks {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TaskStatusList {
+        TaskStatusList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TaskStatus>>(
+                    "tasks",
+                    |m: &TaskStatusList| { &m.tasks },
+                    |m: &mut TaskStatusList| { &mut m.tasks },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskStatusList>(
+                    "TaskStatusList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TaskStatusList {
+        static mut instance: ::protobuf::lazy::Lazy<TaskStatusList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TaskStatusList,
+        };
+        unsafe {
+            instance.get(TaskStatusList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TaskStatusList {
+    fn clear(&mut self) {
+        self.clear_tasks();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TaskStatusList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TaskStatusList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TaskProgress {
+    // message fields
+    pub task_id: u32,
+    pub destinations_sent: u32,
+    pub rate: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl TaskProgress {
+    pub fn new() -> TaskProgress {
+        ::std::default::Default::default()
+    }
+
+    // uint32 task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // uint32 destinations_sent = 2;
+
+    pub fn clear_destinations_sent(&mut self) {
+        self.destinations_sent = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destinations_sent(&mut self, v: u32) {
+        self.destinations_sent = v;
+    }
+
+    pub fn get_destinations_sent(&self) -> u32 {
+        self.destinations_sent
+    }
+
+    // uint32 rate = 3;
+
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u32) {
+        self.rate = v;
+    }
+
+    pub fn get_rate(&self) -> u32 {
+        self.rate
+    }
+}
+
+impl ::protobuf::Message for TaskProgress {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.destinations_sent = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rate = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.destinations_sent != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.destinations_sent, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
+        }
+        if self.destinations_sent != 0 {
+            os.write_uint32(2, self.destinations_sent)?;
+        }
+        if self.rate != 0 {
+            os.write_uint32(3, self.rate)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TaskProgress {
+        TaskProgress::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &TaskProgress| { &m.task_id },
+                    |m: &mut TaskProgress| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "destinations_sent",
+                    |m: &TaskProgress| { &m.destinations_sent },
+                    |m: &mut TaskProgress| { &mut m.destinations_sent },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rate",
+                    |m: &TaskProgress| { &m.rate },
+                    |m: &mut TaskProgress| { &mut m.rate },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskProgress>(
+                    "TaskProgress",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TaskProgress {
+        static mut instance: ::protobuf::lazy::Lazy<TaskProgress> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TaskProgress,
+        };
+        unsafe {
+            instance.get(TaskProgress::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TaskProgress {
+    fn clear(&mut self) {
+        self.clear_task_id();
+        self.clear_destinations_sent();
+        self.clear_rate();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TaskProgress {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TaskProgress {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Metadata {
+    // message fields
+    pub hostname: ::std::string::String,
+    pub version: ::std::string::String,
+    pub client_id: ::std::string::String,
+    pub site: ::std::string::String,
+    pub location: ::std::string::String,
+    pub announced_prefix: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Metadata {
+    pub fn new() -> Metadata {
+        ::std::default::Default::default()
+    }
+
+    // string hostname = 1;
+
+    pub fn clear_hostname(&mut self) {
+        self.hostname.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hostname(&mut self, v: ::std::string::String) {
+        self.hostname = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
+        &mut self.hostname
+    }
+
+    // Take field
+    pub fn take_hostname(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.hostname, ::std::string::String::new())
+    }
+
+    pub fn get_hostname(&self) -> &str {
+        &self.hostname
+    }
+
+    // string version = 2;
+
+    pub fn clear_version(&mut self) {
+        self.version.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_version(&mut self, v: ::std::string::String) {
+        self.version = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_version(&mut self) -> &mut ::std::string::String {
+        &mut self.version
+    }
+
+    // Take field
+    pub fn take_version(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    }
+
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+
+    // string client_id = 3;
+
+    pub fn clear_client_id(&mut self) {
+        self.client_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client_id(&mut self, v: ::std::string::String) {
+        self.client_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
+        &mut self.client_id
+    }
+
+    // Take field
+    pub fn take_client_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    // string site = 4;
+
+    pub fn clear_site(&mut self) {
+        self.site.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_site(&mut self, v: ::std::string::String) {
+        self.site = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_site(&mut self) -> &mut ::std::string::String {
+        &mut self.site
+    }
+
+    // Take field
+    pub fn take_site(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.site, ::std::string::String::new())
+    }
+
+    pub fn get_site(&self) -> &str {
+        &self.site
+    }
+
+    // string location = 5;
+
+    pub fn clear_location(&mut self) {
+        self.location.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_location(&mut self, v: ::std::string::String) {
+        self.location = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_location(&mut self) -> &mut ::std::string::String {
+        &mut self.location
+    }
+
+    // Take field
+    pub fn take_location(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.location, ::std::string::String::new())
+    }
+
+    pub fn get_location(&self) -> &str {
+        &self.location
+    }
+
+    // string announced_prefix = 6;
+
+    pub fn clear_announced_prefix(&mut self) {
+        self.announced_prefix.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_announced_prefix(&mut self, v: ::std::string::String) {
+        self.announced_prefix = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_announced_prefix(&mut self) -> &mut ::std::string::String {
+        &mut self.announced_prefix
+    }
+
+    // Take field
+    pub fn take_announced_prefix(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.announced_prefix, ::std::string::String::new())
+    }
+
+    pub fn get_announced_prefix(&self) -> &str {
+        &self.announced_prefix
+    }
+}
+
+impl ::protobuf::Message for Metadata {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.hostname)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.site)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.location)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.announced_prefix)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.hostname.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.hostname);
+        }
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.version);
+        }
+        if !self.client_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.client_id);
+        }
+        if !self.site.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.site);
+        }
+        if !self.location.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.location);
+        }
+        if !self.announced_prefix.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.announced_prefix);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if !self.hostname.is_empty() {
+            os.write_string(1, &self.hostname)?;
+        }
+        if !self.version.is_empty() {
+            os.write_string(2, &self.version)?;
+        }
+        if !self.client_id.is_empty() {
+            os.write_string(3, &self.client_id)?;
+        }
+        if !self.site.is_empty() {
+            os.write_string(4, &self.site)?;
+        }
+        if !self.location.is_empty() {
+            os.write_string(5, &self.location)?;
+        }
+        if !self.announced_prefix.is_empty() {
+            os.write_string(6, &self.announced_prefix)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Metadata {
+        Metadata::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "hostname",
+                    |m: &Metadata| { &m.hostname },
+                    |m: &mut Metadata| { &mut m.hostname },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "version",
+                    |m: &Metadata| { &m.version },
+                    |m: &mut Metadata| { &mut m.version },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "client_id",
+                    |m: &Metadata| { &m.client_id },
+                    |m: &mut Metadata| { &mut m.client_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "site",
+                    |m: &Metadata| { &m.site },
+                    |m: &mut Metadata| { &mut m.site },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "location",
+                    |m: &Metadata| { &m.location },
+                    |m: &mut Metadata| { &mut m.location },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "announced_prefix",
+                    |m: &Metadata| { &m.announced_prefix },
+                    |m: &mut Metadata| { &mut m.announced_prefix },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Metadata>(
+                    "Metadata",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Metadata {
+        static mut instance: ::protobuf::lazy::Lazy<Metadata> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Metadata,
+        };
+        unsafe {
+            instance.get(Metadata::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Metadata {
+    fn clear(&mut self) {
+        self.clear_hostname();
+        self.clear_version();
+        self.clear_client_id();
+        self.clear_site();
+        self.clear_location();
+        self.clear_announced_prefix();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Metadata {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Ping {
+    // message fields
+    pub source_address: ::protobuf::SingularPtrField<Address>,
+    pub key: ::protobuf::SingularPtrField<Key>,
+    pub rate: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Ping {
+    pub fn new() -> Ping {
+        ::std::default::Default::default()
+    }
+
+    // .Address source_address = 1;
+
+    pub fn clear_source_address(&mut self) {
+        self.source_address.clear();
+    }
+
+    pub fn has_source_address(&self) -> bool {
+        self.source_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_address(&mut self, v: Address) {
+        self.source_address = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_address(&mut self) -> &mut Address {
+        if self.source_address.is_none() {
+            self.source_address.set_default();
+        }
+        self.source_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_address(&mut self) -> Address {
+        self.source_address.take().unwrap_or_else(|| Address::new())
+    }
+
+    pub fn get_source_address(&self) -> &Address {
+        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    }
+
+    // .Key key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: Key) {
+        self.key = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut Key {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> Key {
+        self.key.take().unwrap_or_else(|| Key::new())
+    }
+
+    pub fn get_key(&self) -> &Key {
+        self.key.as_ref().unwrap_or_else(|| Key::default_instance())
+    }
+
+    // uint32 rate = 4;
+
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u32) {
+        self.rate = v;
+    }
+
+    pub fn get_rate(&self) -> u32 {
+        self.rate
+    }
+}
+
+impl ::protobuf::Message for Ping {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source_address {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rate = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source_address.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.rate != 0 {
+            os.write_uint32(4, self.rate)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Ping {
+        Ping::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "source_address",
+                    |m: &Ping| { &m.source_address },
+                    |m: &mut Ping| { &mut m.source_address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Key>>(
+                    "key",
+                    |m: &Ping| { &m.key },
+                    |m: &mut Ping| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rate",
+                    |m: &Ping| { &m.rate },
+                    |m: &mut Ping| { &mut m.rate },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Ping>(
+                    "Ping",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Ping {
+        static mut instance: ::protobuf::lazy::Lazy<Ping> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Ping,
+        };
+        unsafe {
+            instance.get(Ping::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Ping {
+    fn clear(&mut self) {
+        self.clear_source_address();
+        self.clear_key();
+        self.clear_rate();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Ping {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Ping {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TcpSyn {
+    // message fields
+    pub source_address: ::protobuf::SingularPtrField<Address>,
+    pub key: ::protobuf::SingularPtrField<Key>,
+    pub rate: u32,
+    pub destination_port: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl TcpSyn {
+    pub fn new() -> TcpSyn {
+        ::std::default::Default::default()
+    }
+
+    // .Address source_address = 1;
+
+    pub fn clear_source_address(&mut self) {
+        self.source_address.clear();
+    }
+
+    pub fn has_source_address(&self) -> bool {
+        self.source_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_address(&mut self, v: Address) {
+        self.source_address = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_address(&mut self) -> &mut Address {
+        if self.source_address.is_none() {
+            self.source_address.set_default();
+        }
+        self.source_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_address(&mut self) -> Address {
+        self.source_address.take().unwrap_or_else(|| Address::new())
+    }
+
+    pub fn get_source_address(&self) -> &Address {
+        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    }
+
+    // .Key key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: Key) {
+        self.key = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut Key {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> Key {
+        self.key.take().unwrap_or_else(|| Key::new())
+    }
+
+    pub fn get_key(&self) -> &Key {
+        self.key.as_ref().unwrap_or_else(|| Key::default_instance())
+    }
+
+    // uint32 rate = 4;
+
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u32) {
+        self.rate = v;
+    }
+
+    pub fn get_rate(&self) -> u32 {
+        self.rate
+    }
+
+    // uint32 destination_port = 5;
+
+    pub fn clear_destination_port(&mut self) {
+        self.destination_port = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destination_port(&mut self, v: u32) {
+        self.destination_port = v;
+    }
+
+    pub fn get_destination_port(&self) -> u32 {
+        self.destination_port
+    }
+}
+
+impl ::protobuf::Message for TcpSyn {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source_address {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rate = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.destination_port = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.destination_port != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.destination_port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source_address.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.rate != 0 {
+            os.write_uint32(4, self.rate)?;
+        }
+        if self.destination_port != 0 {
+            os.write_uint32(5, self.destination_port)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TcpSyn {
+        TcpSyn::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "source_address",
+                    |m: &TcpSyn| { &m.source_address },
+                    |m: &mut TcpSyn| { &mut m.source_address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Key>>(
+                    "key",
+                    |m: &TcpSyn| { &m.key },
+                    |m: &mut TcpSyn| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rate",
+                    |m: &TcpSyn| { &m.rate },
+                    |m: &mut TcpSyn| { &mut m.rate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "destination_port",
+                    |m: &TcpSyn| { &m.destination_port },
+                    |m: &mut TcpSyn| { &mut m.destination_port },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TcpSyn>(
+                    "TcpSyn",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TcpSyn {
+        static mut instance: ::protobuf::lazy::Lazy<TcpSyn> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TcpSyn,
+        };
+        unsafe {
+            instance.get(TcpSyn::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TcpSyn {
+    fn clear(&mut self) {
+        self.clear_source_address();
+        self.clear_key();
+        self.clear_rate();
+        self.clear_destination_port();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TcpSyn {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TcpSyn {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Dns {
+    // message fields
+    pub source_address: ::protobuf::SingularPtrField<Address>,
+    pub key: ::protobuf::SingularPtrField<Key>,
+    pub rate: u32,
+    pub query_domain: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Dns {
+    pub fn new() -> Dns {
+        ::std::default::Default::default()
+    }
+
+    // .Address source_address = 1;
+
+    pub fn clear_source_address(&mut self) {
+        self.source_address.clear();
+    }
+
+    pub fn has_source_address(&self) -> bool {
+        self.source_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_address(&mut self, v: Address) {
+        self.source_address = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_address(&mut self) -> &mut Address {
+        if self.source_address.is_none() {
+            self.source_address.set_default();
+        }
+        self.source_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_address(&mut self) -> Address {
+        self.source_address.take().unwrap_or_else(|| Address::new())
+    }
+
+    pub fn get_source_address(&self) -> &Address {
+        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    }
+
+    // .Key key = 3;
+
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: Key) {
+        self.key = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut Key {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> Key {
+        self.key.take().unwrap_or_else(|| Key::new())
+    }
+
+    pub fn get_key(&self) -> &Key {
+        self.key.as_ref().unwrap_or_else(|| Key::default_instance())
+    }
+
+    // uint32 rate = 4;
+
+    pub fn clear_rate(&mut self) {
+        self.rate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rate(&mut self, v: u32) {
+        self.rate = v;
+    }
+
+    pub fn get_rate(&self) -> u32 {
+        self.rate
+    }
+
+    // string query_domain = 5;
+
+    pub fn clear_query_domain(&mut self) {
+        self.query_domain.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_query_domain(&mut self, v: ::std::string::String) {
+        self.query_domain = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_query_domain(&mut self) -> &mut ::std::string::String {
+        &mut self.query_domain
+    }
+
+    // Take field
+    pub fn take_query_domain(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.query_domain, ::std::string::String::new())
+    }
+
+    pub fn get_query_domain(&self) -> &str {
+        &self.query_domain
+    }
+}
+
+impl ::protobuf::Message for Dns {
+    fn is_initialized(&self) -> bool {
+        for v in &self.source_address {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rate = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.query_domain)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.source_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.rate != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.rate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.query_domain.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.query_domain);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.source_address.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.rate != 0 {
+            os.write_uint32(4, self.rate)?;
+        }
+        if !self.query_domain.is_empty() {
+            os.write_string(5, &self.query_domain)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Dns {
+        Dns::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "source_address",
+                    |m: &Dns| { &m.source_address },
+                    |m: &mut Dns| { &mut m.source_address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Key>>(
+                    "key",
+                    |m: &Dns| { &m.key },
+                    |m: &mut Dns| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rate",
+                    |m: &Dns| { &m.rate },
+                    |m: &mut Dns| { &mut m.rate },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "query_domain",
+                    |m: &Dns| { &m.query_domain },
+                    |m: &mut Dns| { &mut m.query_domain },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Dns>(
+                    "Dns",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Dns {
+        static mut instance: ::protobuf::lazy::Lazy<Dns> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Dns,
+        };
+        unsafe {
+            instance.get(Dns::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Dns {
+    fn clear(&mut self) {
+        self.clear_source_address();
+        self.clear_key();
+        self.clear_rate();
+        self.clear_query_domain();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Dns {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Dns {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Destinations {
+    // message fields
+    pub addresses: ::protobuf::RepeatedField<Address>,
+    pub last: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Destinations {
+    pub fn new() -> Destinations {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Address addresses = 1;
+
+    pub fn clear_addresses(&mut self) {
+        self.addresses.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_addresses(&mut self, v: ::protobuf::RepeatedField<Address>) {
+        self.addresses = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_addresses(&mut self) -> &mut ::protobuf::RepeatedField<Address> {
+        &mut self.addresses
+    }
+
+    // Take field
+    pub fn take_addresses(&mut self) -> ::protobuf::RepeatedField<Address> {
+        ::std::mem::replace(&mut self.addresses, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_addresses(&self) -> &[Address] {
+        &self.addresses
+    }
+
+    // bool last = 2;
+
+    pub fn clear_last(&mut self) {
+        self.last = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last(&mut self, v: bool) {
+        self.last = v;
+    }
+
+    pub fn get_last(&self) -> bool {
+        self.last
+    }
+}
+
+impl ::protobuf::Message for Destinations {
+    fn is_initialized(&self) -> bool {
+        for v in &self.addresses {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.addresses)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.last = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.addresses {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.last != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.addresses {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.last != false {
+            os.write_bool(2, self.last)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Destinations {
+        Destinations::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "addresses",
+                    |m: &Destinations| { &m.addresses },
+                    |m: &mut Destinations| { &mut m.addresses },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "last",
+                    |m: &Destinations| { &m.last },
+                    |m: &mut Destinations| { &mut m.last },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Destinations>(
+                    "Destinations",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Destinations {
+        static mut instance: ::protobuf::lazy::Lazy<Destinations> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Destinations,
+        };
+        unsafe {
+            instance.get(Destinations::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Destinations {
+    fn clear(&mut self) {
+        self.clear_addresses();
+        self.clear_last();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Destinations {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Destinations {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Key {
+    // message fields
+    pub key_id: u32,
+    pub secret: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl Key {
+    pub fn new() -> Key {
+        ::std::default::Default::default()
+    }
+
+    // uint32 key_id = 1;
+
+    pub fn clear_key_id(&mut self) {
+        self.key_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_id(&mut self, v: u32) {
+        self.key_id = v;
+    }
+
+    pub fn get_key_id(&self) -> u32 {
+        self.key_id
+    }
+
+    // string secret = 2;
+
+    pub fn clear_secret(&mut self) {
+        self.secret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secret(&mut self, v: ::std::string::String) {
+        self.secret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_secret(&mut self) -> &mut ::std::string::String {
+        &mut self.secret
+    }
+
+    // Take field
+    pub fn take_secret(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.secret, ::std::string::String::new())
+    }
+
+    pub fn get_secret(&self) -> &str {
+        &self.secret
+    }
+}
+
+impl ::protobuf::Message for Key {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.key_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.secret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.key_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.key_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.secret.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.secret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.key_id != 0 {
+            os.write_uint32(1, self.key_id)?;
+        }
+        if !self.secret.is_empty() {
+            os.write_string(2, &self.secret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Key {
+        Key::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "key_id",
+                    |m: &Key| { &m.key_id },
+                    |m: &mut Key| { &mut m.key_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "secret",
+                    |m: &Key| { &m.secret },
+                    |m: &mut Key| { &mut m.secret },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Key>(
+                    "Key",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Key {
+        static mut instance: ::protobuf::lazy::Lazy<Key> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Key,
+        };
+        unsafe {
+            instance.get(Key::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Key {
+    fn clear(&mut self) {
+        self.clear_key_id();
+        self.clear_secret();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Key {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct KeyList {
+    // message fields
+    pub keys: ::protobuf::RepeatedField<Key>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl KeyList {
+    pub fn new() -> KeyList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Key keys = 1;
+
+    pub fn clear_keys(&mut self) {
+        self.keys.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_keys(&mut self, v: ::protobuf::RepeatedField<Key>) {
+        self.keys = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_keys(&mut self) -> &mut ::protobuf::RepeatedField<Key> {
+        &mut self.keys
+    }
+
+    // Take field
+    pub fn take_keys(&mut self) -> ::protobuf::RepeatedField<Key> {
+        ::std::mem::replace(&mut self.keys, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_keys(&self) -> &[Key] {
+        &self.keys
+    }
+}
+
+impl ::protobuf::Message for KeyList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.keys {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.keys)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.keys {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.keys {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> KeyList {
+        KeyList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Key>>(
+                    "keys",
+                    |m: &KeyList| { &m.keys },
+                    |m: &mut KeyList| { &mut m.keys },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<KeyList>(
+                    "KeyList",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static KeyList {
+        static mut instance: ::protobuf::lazy::Lazy<KeyList> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const KeyList,
+        };
+        unsafe {
+            instance.get(KeyList::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for KeyList {
+    fn clear(&mut self) {
+        self.clear_keys();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for KeyList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for KeyList {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Address {
+    // message oneof groups
+    pub value: ::std::option::Option<Address_oneof_value>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+#[derive(Clone,PartialEq)]
+pub enum Address_oneof_value {
+    v4(u32),
+    v6(::std::vec::Vec<u8>),
+}
+
+impl Address {
+    pub fn new() -> Address {
+        ::std::default::Default::default()
+    }
+
+    // uint32 v4 = 1;
+
+    pub fn clear_v4(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_v4(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(Address_oneof_value::v4(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_v4(&mut self, v: u32) {
+        self.value = ::std::option::Option::Some(Address_oneof_value::v4(v))
+    }
+
+    pub fn get_v4(&self) -> u32 {
+        match self.value {
+            ::std::option::Option::Some(Address_oneof_value::v4(v)) => v,
+            _ => 0,
+        }
+    }
+
+    // bytes v6 = 2;
+
+    pub fn clear_v6(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_v6(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(Address_oneof_value::v6(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_v6(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = ::std::option::Option::Some(Address_oneof_value::v6(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_v6(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if let ::std::option::Option::Some(Address_oneof_value::v6(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(Address_oneof_value::v6(::std::vec::Vec::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(Address_oneof_value::v6(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_v6(&mut self) -> ::std::vec::Vec<u8> {
+        if self.has_v6() {
+            match self.value.take() {
+                ::std::option::Option::Some(Address_oneof_value::v6(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::vec::Vec::new()
+        }
+    }
+
+    pub fn get_v6(&self) -> &[u8] {
+        match self.value {
+            ::std::option::Option::Some(Address_oneof_value::v6(ref v)) => v,
+            _ => &[],
+        }
+    }
+}
+
+impl ::protobuf::Message for Address {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(Address_oneof_value::v4(is.read_uint32()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(Address_oneof_value::v6(is.read_bytes()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &Address_oneof_value::v4(v) => {
+                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &Address_oneof_value::v6(ref v) => {
+                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.value {
+            match v {
+                &Address_oneof_value::v4(v) => {
+                    os.write_uint32(1, v)?;
+                },
+                &Address_oneof_value::v6(ref v) => {
+                    os.write_bytes(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Address {
+        Address::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor::<_>(
+                    "v4",
+                    Address::has_v4,
+                    Address::get_v4,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor::<_>(
+                    "v6",
+                    Address::has_v6,
+                    Address::get_v6,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Address>(
+                    "Address",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static Address {
+        static mut instance: ::protobuf::lazy::Lazy<Address> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Address,
+        };
+        unsafe {
+            instance.get(Address::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for Address {
+    fn clear(&mut self) {
+        self.clear_v4();
+        self.clear_v6();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Address {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Address {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TaskResult {
+    // message fields
+    pub task_id: u32,
+    pub client: ::protobuf::SingularPtrField<Client>,
+    pub result_list: ::protobuf::RepeatedField<Result>,
+    pub is_finished: bool,
+    pub campaign_id: u32,
+    pub transmitter_clock: ::protobuf::SingularPtrField<ClockOffset>,
+    pub receiver_clock: ::protobuf::SingularPtrField<ClockOffset>,
+    pub invalid_replies: u32,
+    pub summary: ::protobuf::SingularPtrField<TaskSummary>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl TaskResult {
+    pub fn new() -> TaskResult {
+        ::std::default::Default::default()
+    }
+
+    // uint32 task_id = 1;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // .Client client = 2;
+
+    pub fn clear_client(&mut self) {
+        self.client.clear();
+    }
+
+    pub fn has_client(&self) -> bool {
+        self.client.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_client(&mut self, v: Client) {
+        self.client = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_client(&mut self) -> &mut Client {
+        if self.client.is_none() {
+            self.client.set_default();
+        }
+        self.client.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_client(&mut self) -> Client {
+        self.client.take().unwrap_or_else(|| Client::new())
+    }
+
+    pub fn get_client(&self) -> &Client {
+        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
+    }
+
+    // repeated .Result result_list = 3;
+
+    pub fn clear_result_list(&mut self) {
+        self.result_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result_list(&mut self, v: ::protobuf::RepeatedField<Result>) {
+        self.result_list = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result_list(&mut self) -> &mut ::protobuf::RepeatedField<Result> {
+        &mut self.result_list
+    }
+
+    // Take field
+    pub fn take_result_list(&mut self) -> ::protobuf::RepeatedField<Result> {
+        ::std::mem::replace(&mut self.result_list, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_result_list(&self) -> &[Result] {
+        &self.result_list
+    }
+
+    // bool is_finished = 4;
+
+    pub fn clear_is_finished(&mut self) {
+        self.is_finished = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_finished(&mut self, v: bool) {
+        self.is_finished = v;
+    }
+
+    pub fn get_is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    // uint32 campaign_id = 5;
+
+    pub fn clear_campaign_id(&mut self) {
+        self.campaign_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_campaign_id(&mut self, v: u32) {
+        self.campaign_id = v;
+    }
+
+    pub fn get_campaign_id(&self) -> u32 {
+        self.campaign_id
+    }
+
+    // .ClockOffset transmitter_clock = 6;
+
+    pub fn clear_transmitter_clock(&mut self) {
+        self.transmitter_clock.clear();
+    }
+
+    pub fn has_transmitter_clock(&self) -> bool {
+        self.transmitter_clock.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transmitter_clock(&mut self, v: ClockOffset) {
+        self.transmitter_clock = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transmitter_clock(&mut self) -> &mut ClockOffset {
+        if self.transmitter_clock.is_none() {
+            self.transmitter_clock.set_default();
+        }
+        self.transmitter_clock.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_transmitter_clock(&mut self) -> ClockOffset {
+        self.transmitter_clock.take().unwrap_or_else(|| ClockOffset::new())
+    }
+
+    pub fn get_transmitter_clock(&self) -> &ClockOffset {
+        self.transmitter_clock.as_ref().unwrap_or_else(|| ClockOffset::default_instance())
+    }
+
+    // .ClockOffset receiver_clock = 7;
+
+    pub fn clear_receiver_clock(&mut self) {
+        self.receiver_clock.clear();
+    }
+
+    pub fn has_receiver_clock(&self) -> bool {
+        self.receiver_clock.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_receiver_clock(&mut self, v: ClockOffset) {
+        self.receiver_clock = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_receiver_clock(&mut self) -> &mut ClockOffset {
+        if self.receiver_clock.is_none() {
+            self.receiver_clock.set_default();
+        }
+        self.receiver_clock.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_receiver_clock(&mut self) -> ClockOffset {
+        self.receiver_clock.take().unwrap_or_else(|| ClockOffset::new())
+    }
+
+    pub fn get_receiver_clock(&self) -> &ClockOffset {
+        self.receiver_clock.as_ref().unwrap_or_else(|| ClockOffset::default_instance())
+    }
+
+    // uint32 invalid_replies = 8;
+
+    pub fn clear_invalid_replies(&mut self) {
+        self.invalid_replies = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_invalid_replies(&mut self, v: u32) {
+        self.invalid_replies = v;
+    }
+
+    pub fn get_invalid_replies(&self) -> u32 {
+        self.invalid_replies
+    }
+
+    // .TaskSummary summary = 9;
+
+    pub fn clear_summary(&mut self) {
+        self.summary.clear();
+    }
+
+    pub fn has_summary(&self) -> bool {
+        self.summary.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_summary(&mut self, v: TaskSummary) {
+        self.summary = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_summary(&mut self) -> &mut TaskSummary {
+        if self.summary.is_none() {
+            self.summary.set_default();
+        }
+        self.summary.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_summary(&mut self) -> TaskSummary {
+        self.summary.take().unwrap_or_else(|| TaskSummary::new())
+    }
+
+    pub fn get_summary(&self) -> &TaskSummary {
+        self.summary.as_ref().unwrap_or_else(|| TaskSummary::default_instance())
+    }
+}
+
+impl ::protobuf::Message for TaskResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.client {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.result_list {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.transmitter_clock {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.receiver_clock {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.summary {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.result_list)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_finished = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.campaign_id = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.transmitter_clock)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.receiver_clock)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.invalid_replies = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.summary)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.client.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.result_list {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.is_finished != false {
+            my_size += 2;
+        }
+        if self.campaign_id != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.campaign_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.transmitter_clock.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.receiver_clock.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.invalid_replies != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.invalid_replies, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.summary.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if self.task_id != 0 {
+            os.write_uint32(1, self.task_id)?;
+        }
+        if let Some(ref v) = self.client.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.result_list {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.is_finished != false {
+            os.write_bool(4, self.is_finished)?;
+        }
+        if self.campaign_id != 0 {
+            os.write_uint32(5, self.campaign_id)?;
+        }
+        if let Some(ref v) = self.transmitter_clock.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.receiver_clock.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.invalid_replies != 0 {
+            os.write_uint32(8, self.invalid_replies)?;
+        }
+        if let Some(ref v) = self.summary.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
         self.cached_size.get()
     }
 
@@ -912,8 +7414,8 @@ impl ::protobuf::Message for ClientList {
         Self::descriptor_static()
     }
 
-    fn new() -> ClientList {
-        ClientList::new()
+    fn new() -> TaskResult {
+        TaskResult::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -924,13 +7426,53 @@ impl ::protobuf::Message for ClientList {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
-                    "clients",
-                    |m: &ClientList| { &m.clients },
-                    |m: &mut ClientList| { &mut m.clients },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &TaskResult| { &m.task_id },
+                    |m: &mut TaskResult| { &mut m.task_id },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ClientList>(
-                    "ClientList",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
+                    "client",
+                    |m: &TaskResult| { &m.client },
+                    |m: &mut TaskResult| { &mut m.client },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Result>>(
+                    "result_list",
+                    |m: &TaskResult| { &m.result_list },
+                    |m: &mut TaskResult| { &mut m.result_list },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "is_finished",
+                    |m: &TaskResult| { &m.is_finished },
+                    |m: &mut TaskResult| { &mut m.is_finished },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "campaign_id",
+                    |m: &TaskResult| { &m.campaign_id },
+                    |m: &mut TaskResult| { &mut m.campaign_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ClockOffset>>(
+                    "transmitter_clock",
+                    |m: &TaskResult| { &m.transmitter_clock },
+                    |m: &mut TaskResult| { &mut m.transmitter_clock },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ClockOffset>>(
+                    "receiver_clock",
+                    |m: &TaskResult| { &m.receiver_clock },
+                    |m: &mut TaskResult| { &mut m.receiver_clock },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "invalid_replies",
+                    |m: &TaskResult| { &m.invalid_replies },
+                    |m: &mut TaskResult| { &mut m.invalid_replies },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TaskSummary>>(
+                    "summary",
+                    |m: &TaskResult| { &m.summary },
+                    |m: &mut TaskResult| { &mut m.summary },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskResult>(
+                    "TaskResult",
                     fields,
                     file_descriptor_proto()
                 )
@@ -938,103 +7480,119 @@ impl ::protobuf::Message for ClientList {
         }
     }
 
-    fn default_instance() -> &'static ClientList {
-        static mut instance: ::protobuf::lazy::Lazy<ClientList> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static TaskResult {
+        static mut instance: ::protobuf::lazy::Lazy<TaskResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ClientList,
+            ptr: 0 as *const TaskResult,
         };
         unsafe {
-            instance.get(ClientList::new)
+            instance.get(TaskResult::new)
         }
     }
 }
 
-impl ::protobuf::Clear for ClientList {
+impl ::protobuf::Clear for TaskResult {
     fn clear(&mut self) {
-        self.clear_clients();
+        self.clear_task_id();
+        self.clear_client();
+        self.clear_result_list();
+        self.clear_is_finished();
+        self.clear_campaign_id();
+        self.clear_transmitter_clock();
+        self.clear_receiver_clock();
+        self.clear_invalid_replies();
+        self.clear_summary();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ClientList {
+impl ::std::fmt::Debug for TaskResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ClientList {
+impl ::protobuf::reflect::ProtobufValue for TaskResult {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Client {
+pub struct TaskSummary {
     // message fields
-    pub index: u32,
-    pub metadata: ::protobuf::SingularPtrField<Metadata>,
+    pub probes_sent: u32,
+    pub site_replies: ::protobuf::RepeatedField<SiteReplies>,
+    pub invalid_replies: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl Client {
-    pub fn new() -> Client {
+impl TaskSummary {
+    pub fn new() -> TaskSummary {
         ::std::default::Default::default()
     }
 
-    // uint32 index = 1;
+    // uint32 probes_sent = 1;
 
-    pub fn clear_index(&mut self) {
-        self.index = 0;
+    pub fn clear_probes_sent(&mut self) {
+        self.probes_sent = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_index(&mut self, v: u32) {
-        self.index = v;
+    pub fn set_probes_sent(&mut self, v: u32) {
+        self.probes_sent = v;
     }
 
-    pub fn get_index(&self) -> u32 {
-        self.index
+    pub fn get_probes_sent(&self) -> u32 {
+        self.probes_sent
     }
 
-    // .Metadata metadata = 2;
-
-    pub fn clear_metadata(&mut self) {
-        self.metadata.clear();
-    }
+    // repeated .SiteReplies site_replies = 2;
 
-    pub fn has_metadata(&self) -> bool {
-        self.metadata.is_some()
+    pub fn clear_site_replies(&mut self) {
+        self.site_replies.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_metadata(&mut self, v: Metadata) {
-        self.metadata = ::protobuf::SingularPtrField::some(v);
+    pub fn set_site_replies(&mut self, v: ::protobuf::RepeatedField<SiteReplies>) {
+        self.site_replies = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_metadata(&mut self) -> &mut Metadata {
-        if self.metadata.is_none() {
-            self.metadata.set_default();
-        }
-        self.metadata.as_mut().unwrap()
+    pub fn mut_site_replies(&mut self) -> &mut ::protobuf::RepeatedField<SiteReplies> {
+        &mut self.site_replies
+    }
+
+    // Take field
+    pub fn take_site_replies(&mut self) -> ::protobuf::RepeatedField<SiteReplies> {
+        ::std::mem::replace(&mut self.site_replies, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_site_replies(&self) -> &[SiteReplies] {
+        &self.site_replies
+    }
+
+    // uint32 invalid_replies = 3;
+
+    pub fn clear_invalid_replies(&mut self) {
+        self.invalid_replies = 0;
     }
 
-    // Take field
-    pub fn take_metadata(&mut self) -> Metadata {
-        self.metadata.take().unwrap_or_else(|| Metadata::new())
+    // Param is passed by value, moved
+    pub fn set_invalid_replies(&mut self, v: u32) {
+        self.invalid_replies = v;
     }
 
-    pub fn get_metadata(&self) -> &Metadata {
-        self.metadata.as_ref().unwrap_or_else(|| Metadata::default_instance())
+    pub fn get_invalid_replies(&self) -> u32 {
+        self.invalid_replies
     }
 }
 
-impl ::protobuf::Message for Client {
+impl ::protobuf::Message for TaskSummary {
     fn is_initialized(&self) -> bool {
-        for v in &self.metadata {
+        for v in &self.site_replies {
             if !v.is_initialized() {
                 return false;
             }
@@ -1051,10 +7609,17 @@ impl ::protobuf::Message for Client {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint32()?;
-                    self.index = tmp;
+                    self.probes_sent = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.metadata)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.site_replies)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.invalid_replies = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1068,12 +7633,15 @@ impl ::protobuf::Message for Client {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.index != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.index, ::protobuf::wire_format::WireTypeVarint);
+        if self.probes_sent != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.probes_sent, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.metadata.as_ref() {
-            let len = v.compute_size();
+        for value in &self.site_replies {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.invalid_replies != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.invalid_replies, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1081,13 +7649,16 @@ impl ::protobuf::Message for Client {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.index != 0 {
-            os.write_uint32(1, self.index)?;
+        if self.probes_sent != 0 {
+            os.write_uint32(1, self.probes_sent)?;
         }
-        if let Some(ref v) = self.metadata.as_ref() {
+        for v in &self.site_replies {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
+        };
+        if self.invalid_replies != 0 {
+            os.write_uint32(3, self.invalid_replies)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1119,8 +7690,8 @@ impl ::protobuf::Message for Client {
         Self::descriptor_static()
     }
 
-    fn new() -> Client {
-        Client::new()
+    fn new() -> TaskSummary {
+        TaskSummary::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1132,17 +7703,22 @@ impl ::protobuf::Message for Client {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "index",
-                    |m: &Client| { &m.index },
-                    |m: &mut Client| { &mut m.index },
+                    "probes_sent",
+                    |m: &TaskSummary| { &m.probes_sent },
+                    |m: &mut TaskSummary| { &mut m.probes_sent },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Metadata>>(
-                    "metadata",
-                    |m: &Client| { &m.metadata },
-                    |m: &mut Client| { &mut m.metadata },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SiteReplies>>(
+                    "site_replies",
+                    |m: &TaskSummary| { &m.site_replies },
+                    |m: &mut TaskSummary| { &mut m.site_replies },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Client>(
-                    "Client",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "invalid_replies",
+                    |m: &TaskSummary| { &m.invalid_replies },
+                    |m: &mut TaskSummary| { &mut m.invalid_replies },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TaskSummary>(
+                    "TaskSummary",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1150,185 +7726,97 @@ impl ::protobuf::Message for Client {
         }
     }
 
-    fn default_instance() -> &'static Client {
-        static mut instance: ::protobuf::lazy::Lazy<Client> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static TaskSummary {
+        static mut instance: ::protobuf::lazy::Lazy<TaskSummary> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Client,
+            ptr: 0 as *const TaskSummary,
         };
         unsafe {
-            instance.get(Client::new)
+            instance.get(TaskSummary::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Client {
+impl ::protobuf::Clear for TaskSummary {
     fn clear(&mut self) {
-        self.clear_index();
-        self.clear_metadata();
+        self.clear_probes_sent();
+        self.clear_site_replies();
+        self.clear_invalid_replies();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Client {
+impl ::std::fmt::Debug for TaskSummary {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Client {
+impl ::protobuf::reflect::ProtobufValue for TaskSummary {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Task {
+pub struct SiteReplies {
     // message fields
-    pub task_id: u32,
-    // message oneof groups
-    pub data: ::std::option::Option<Task_oneof_data>,
+    pub site: ::std::string::String,
+    pub replies: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum Task_oneof_data {
-    ping(Ping),
-    empty(Empty),
-}
-
-impl Task {
-    pub fn new() -> Task {
+impl SiteReplies {
+    pub fn new() -> SiteReplies {
         ::std::default::Default::default()
     }
 
-    // uint32 task_id = 1;
-
-    pub fn clear_task_id(&mut self) {
-        self.task_id = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_task_id(&mut self, v: u32) {
-        self.task_id = v;
-    }
-
-    pub fn get_task_id(&self) -> u32 {
-        self.task_id
-    }
-
-    // .Ping ping = 2;
-
-    pub fn clear_ping(&mut self) {
-        self.data = ::std::option::Option::None;
-    }
+    // string site = 1;
 
-    pub fn has_ping(&self) -> bool {
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::ping(..)) => true,
-            _ => false,
-        }
+    pub fn clear_site(&mut self) {
+        self.site.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: Ping) {
-        self.data = ::std::option::Option::Some(Task_oneof_data::ping(v))
+    pub fn set_site(&mut self, v: ::std::string::String) {
+        self.site = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_ping(&mut self) -> &mut Ping {
-        if let ::std::option::Option::Some(Task_oneof_data::ping(_)) = self.data {
-        } else {
-            self.data = ::std::option::Option::Some(Task_oneof_data::ping(Ping::new()));
-        }
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_site(&mut self) -> &mut ::std::string::String {
+        &mut self.site
     }
 
     // Take field
-    pub fn take_ping(&mut self) -> Ping {
-        if self.has_ping() {
-            match self.data.take() {
-                ::std::option::Option::Some(Task_oneof_data::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Ping::new()
-        }
+    pub fn take_site(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.site, ::std::string::String::new())
     }
 
-    pub fn get_ping(&self) -> &Ping {
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::ping(ref v)) => v,
-            _ => Ping::default_instance(),
-        }
+    pub fn get_site(&self) -> &str {
+        &self.site
     }
 
-    // .Empty empty = 3;
-
-    pub fn clear_empty(&mut self) {
-        self.data = ::std::option::Option::None;
-    }
+    // uint32 replies = 2;
 
-    pub fn has_empty(&self) -> bool {
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::empty(..)) => true,
-            _ => false,
-        }
+    pub fn clear_replies(&mut self) {
+        self.replies = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_empty(&mut self, v: Empty) {
-        self.data = ::std::option::Option::Some(Task_oneof_data::empty(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_empty(&mut self) -> &mut Empty {
-        if let ::std::option::Option::Some(Task_oneof_data::empty(_)) = self.data {
-        } else {
-            self.data = ::std::option::Option::Some(Task_oneof_data::empty(Empty::new()));
-        }
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::empty(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_empty(&mut self) -> Empty {
-        if self.has_empty() {
-            match self.data.take() {
-                ::std::option::Option::Some(Task_oneof_data::empty(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Empty::new()
-        }
+    pub fn set_replies(&mut self, v: u32) {
+        self.replies = v;
     }
 
-    pub fn get_empty(&self) -> &Empty {
-        match self.data {
-            ::std::option::Option::Some(Task_oneof_data::empty(ref v)) => v,
-            _ => Empty::default_instance(),
-        }
+    pub fn get_replies(&self) -> u32 {
+        self.replies
     }
 }
 
-impl ::protobuf::Message for Task {
+impl ::protobuf::Message for SiteReplies {
     fn is_initialized(&self) -> bool {
-        if let Some(Task_oneof_data::ping(ref v)) = self.data {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Task_oneof_data::empty(ref v)) = self.data {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -1337,23 +7825,14 @@ impl ::protobuf::Message for Task {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.task_id = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.site)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.data = ::std::option::Option::Some(Task_oneof_data::ping(is.read_message()?));
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.data = ::std::option::Option::Some(Task_oneof_data::empty(is.read_message()?));
+                    let tmp = is.read_uint32()?;
+                    self.replies = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1367,20 +7846,11 @@ impl ::protobuf::Message for Task {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.task_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        if !self.site.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.site);
         }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &Task_oneof_data::ping(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Task_oneof_data::empty(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if self.replies != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.replies, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1388,22 +7858,11 @@ impl ::protobuf::Message for Task {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.task_id != 0 {
-            os.write_uint32(1, self.task_id)?;
+        if !self.site.is_empty() {
+            os.write_string(1, &self.site)?;
         }
-        if let ::std::option::Option::Some(ref v) = self.data {
-            match v {
-                &Task_oneof_data::ping(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Task_oneof_data::empty(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if self.replies != 0 {
+            os.write_uint32(2, self.replies)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1435,8 +7894,8 @@ impl ::protobuf::Message for Task {
         Self::descriptor_static()
     }
 
-    fn new() -> Task {
-        Task::new()
+    fn new() -> SiteReplies {
+        SiteReplies::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1447,23 +7906,18 @@ impl ::protobuf::Message for Task {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "task_id",
-                    |m: &Task| { &m.task_id },
-                    |m: &mut Task| { &mut m.task_id },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Ping>(
-                    "ping",
-                    Task::has_ping,
-                    Task::get_ping,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "site",
+                    |m: &SiteReplies| { &m.site },
+                    |m: &mut SiteReplies| { &mut m.site },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Empty>(
-                    "empty",
-                    Task::has_empty,
-                    Task::get_empty,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "replies",
+                    |m: &SiteReplies| { &m.replies },
+                    |m: &mut SiteReplies| { &mut m.replies },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Task>(
-                    "Task",
+                ::protobuf::reflect::MessageDescriptor::new::<SiteReplies>(
+                    "SiteReplies",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1471,107 +7925,100 @@ impl ::protobuf::Message for Task {
         }
     }
 
-    fn default_instance() -> &'static Task {
-        static mut instance: ::protobuf::lazy::Lazy<Task> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SiteReplies {
+        static mut instance: ::protobuf::lazy::Lazy<SiteReplies> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Task,
+            ptr: 0 as *const SiteReplies,
         };
         unsafe {
-            instance.get(Task::new)
+            instance.get(SiteReplies::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Task {
+impl ::protobuf::Clear for SiteReplies {
     fn clear(&mut self) {
-        self.clear_task_id();
-        self.clear_ping();
-        self.clear_empty();
+        self.clear_site();
+        self.clear_replies();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Task {
+impl ::std::fmt::Debug for SiteReplies {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Task {
+impl ::protobuf::reflect::ProtobufValue for SiteReplies {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Metadata {
+pub struct ClockSync {
     // message fields
-    pub hostname: ::std::string::String,
-    pub version: ::std::string::String,
+    pub client_transmit_time: u64,
+    pub server_receive_time: u64,
+    pub server_transmit_time: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl Metadata {
-    pub fn new() -> Metadata {
+impl ClockSync {
+    pub fn new() -> ClockSync {
         ::std::default::Default::default()
     }
 
-    // string hostname = 1;
-
-    pub fn clear_hostname(&mut self) {
-        self.hostname.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_hostname(&mut self, v: ::std::string::String) {
-        self.hostname = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_hostname(&mut self) -> &mut ::std::string::String {
-        &mut self.hostname
+    // uint64 client_transmit_time = 1;
+
+    pub fn clear_client_transmit_time(&mut self) {
+        self.client_transmit_time = 0;
     }
 
-    // Take field
-    pub fn take_hostname(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.hostname, ::std::string::String::new())
+    // Param is passed by value, moved
+    pub fn set_client_transmit_time(&mut self, v: u64) {
+        self.client_transmit_time = v;
     }
 
-    pub fn get_hostname(&self) -> &str {
-        &self.hostname
+    pub fn get_client_transmit_time(&self) -> u64 {
+        self.client_transmit_time
     }
 
-    // string version = 2;
+    // uint64 server_receive_time = 2;
 
-    pub fn clear_version(&mut self) {
-        self.version.clear();
+    pub fn clear_server_receive_time(&mut self) {
+        self.server_receive_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_version(&mut self, v: ::std::string::String) {
-        self.version = v;
+    pub fn set_server_receive_time(&mut self, v: u64) {
+        self.server_receive_time = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_version(&mut self) -> &mut ::std::string::String {
-        &mut self.version
+    pub fn get_server_receive_time(&self) -> u64 {
+        self.server_receive_time
     }
 
-    // Take field
-    pub fn take_version(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.version, ::std::string::String::new())
+    // uint64 server_transmit_time = 3;
+
+    pub fn clear_server_transmit_time(&mut self) {
+        self.server_transmit_time = 0;
     }
 
-    pub fn get_version(&self) -> &str {
-        &self.version
+    // Param is passed by value, moved
+    pub fn set_server_transmit_time(&mut self, v: u64) {
+        self.server_transmit_time = v;
+    }
+
+    pub fn get_server_transmit_time(&self) -> u64 {
+        self.server_transmit_time
     }
 }
 
-impl ::protobuf::Message for Metadata {
+impl ::protobuf::Message for ClockSync {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1581,10 +8028,25 @@ impl ::protobuf::Message for Metadata {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.hostname)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.client_transmit_time = tmp;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.version)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.server_receive_time = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.server_transmit_time = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1598,11 +8060,14 @@ impl ::protobuf::Message for Metadata {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.hostname.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.hostname);
+        if self.client_transmit_time != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.client_transmit_time, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.version.is_empty() {
-            my_size += ::protobuf::rt::string_size(2, &self.version);
+        if self.server_receive_time != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.server_receive_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.server_transmit_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.server_transmit_time, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1610,11 +8075,14 @@ impl ::protobuf::Message for Metadata {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if !self.hostname.is_empty() {
-            os.write_string(1, &self.hostname)?;
+        if self.client_transmit_time != 0 {
+            os.write_uint64(1, self.client_transmit_time)?;
         }
-        if !self.version.is_empty() {
-            os.write_string(2, &self.version)?;
+        if self.server_receive_time != 0 {
+            os.write_uint64(2, self.server_receive_time)?;
+        }
+        if self.server_transmit_time != 0 {
+            os.write_uint64(3, self.server_transmit_time)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1646,8 +8114,8 @@ impl ::protobuf::Message for Metadata {
         Self::descriptor_static()
     }
 
-    fn new() -> Metadata {
-        Metadata::new()
+    fn new() -> ClockSync {
+        ClockSync::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1658,18 +8126,23 @@ impl ::protobuf::Message for Metadata {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "hostname",
-                    |m: &Metadata| { &m.hostname },
-                    |m: &mut Metadata| { &mut m.hostname },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "client_transmit_time",
+                    |m: &ClockSync| { &m.client_transmit_time },
+                    |m: &mut ClockSync| { &mut m.client_transmit_time },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "version",
-                    |m: &Metadata| { &m.version },
-                    |m: &mut Metadata| { &mut m.version },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "server_receive_time",
+                    |m: &ClockSync| { &m.server_receive_time },
+                    |m: &mut ClockSync| { &mut m.server_receive_time },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Metadata>(
-                    "Metadata",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "server_transmit_time",
+                    |m: &ClockSync| { &m.server_transmit_time },
+                    |m: &mut ClockSync| { &mut m.server_transmit_time },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ClockSync>(
+                    "ClockSync",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1677,123 +8150,113 @@ impl ::protobuf::Message for Metadata {
         }
     }
 
-    fn default_instance() -> &'static Metadata {
-        static mut instance: ::protobuf::lazy::Lazy<Metadata> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ClockSync {
+        static mut instance: ::protobuf::lazy::Lazy<ClockSync> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Metadata,
+            ptr: 0 as *const ClockSync,
         };
         unsafe {
-            instance.get(Metadata::new)
+            instance.get(ClockSync::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Metadata {
+impl ::protobuf::Clear for ClockSync {
     fn clear(&mut self) {
-        self.clear_hostname();
-        self.clear_version();
+        self.clear_client_transmit_time();
+        self.clear_server_receive_time();
+        self.clear_server_transmit_time();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Metadata {
+impl ::std::fmt::Debug for ClockSync {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Metadata {
+impl ::protobuf::reflect::ProtobufValue for ClockSync {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Ping {
+pub struct ClockOffset {
     // message fields
-    pub source_address: ::protobuf::SingularPtrField<Address>,
-    pub destination_addresses: ::protobuf::RepeatedField<Address>,
+    pub client_id: ::std::string::String,
+    pub offset: i64,
+    pub delay: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl Ping {
-    pub fn new() -> Ping {
+impl ClockOffset {
+    pub fn new() -> ClockOffset {
         ::std::default::Default::default()
     }
 
-    // .Address source_address = 1;
-
-    pub fn clear_source_address(&mut self) {
-        self.source_address.clear();
-    }
+    // string client_id = 1;
 
-    pub fn has_source_address(&self) -> bool {
-        self.source_address.is_some()
+    pub fn clear_client_id(&mut self) {
+        self.client_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_source_address(&mut self, v: Address) {
-        self.source_address = ::protobuf::SingularPtrField::some(v);
+    pub fn set_client_id(&mut self, v: ::std::string::String) {
+        self.client_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_source_address(&mut self) -> &mut Address {
-        if self.source_address.is_none() {
-            self.source_address.set_default();
-        }
-        self.source_address.as_mut().unwrap()
+    pub fn mut_client_id(&mut self) -> &mut ::std::string::String {
+        &mut self.client_id
     }
 
     // Take field
-    pub fn take_source_address(&mut self) -> Address {
-        self.source_address.take().unwrap_or_else(|| Address::new())
+    pub fn take_client_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.client_id, ::std::string::String::new())
     }
 
-    pub fn get_source_address(&self) -> &Address {
-        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
     }
 
-    // repeated .Address destination_addresses = 2;
+    // int64 offset = 2;
 
-    pub fn clear_destination_addresses(&mut self) {
-        self.destination_addresses.clear();
+    pub fn clear_offset(&mut self) {
+        self.offset = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_destination_addresses(&mut self, v: ::protobuf::RepeatedField<Address>) {
-        self.destination_addresses = v;
+    pub fn set_offset(&mut self, v: i64) {
+        self.offset = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_destination_addresses(&mut self) -> &mut ::protobuf::RepeatedField<Address> {
-        &mut self.destination_addresses
+    pub fn get_offset(&self) -> i64 {
+        self.offset
     }
 
-    // Take field
-    pub fn take_destination_addresses(&mut self) -> ::protobuf::RepeatedField<Address> {
-        ::std::mem::replace(&mut self.destination_addresses, ::protobuf::RepeatedField::new())
+    // uint64 delay = 3;
+
+    pub fn clear_delay(&mut self) {
+        self.delay = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delay(&mut self, v: u64) {
+        self.delay = v;
     }
 
-    pub fn get_destination_addresses(&self) -> &[Address] {
-        &self.destination_addresses
+    pub fn get_delay(&self) -> u64 {
+        self.delay
     }
 }
 
-impl ::protobuf::Message for Ping {
+impl ::protobuf::Message for ClockOffset {
     fn is_initialized(&self) -> bool {
-        for v in &self.source_address {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.destination_addresses {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1802,10 +8265,21 @@ impl ::protobuf::Message for Ping {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.client_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.destination_addresses)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.offset = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.delay = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1819,30 +8293,30 @@ impl ::protobuf::Message for Ping {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.source_address.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.client_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.client_id);
+        }
+        if self.offset != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.offset, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.delay != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.delay, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.destination_addresses {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.source_address.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.client_id.is_empty() {
+            os.write_string(1, &self.client_id)?;
+        }
+        if self.offset != 0 {
+            os.write_int64(2, self.offset)?;
+        }
+        if self.delay != 0 {
+            os.write_uint64(3, self.delay)?;
         }
-        for v in &self.destination_addresses {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1873,8 +8347,8 @@ impl ::protobuf::Message for Ping {
         Self::descriptor_static()
     }
 
-    fn new() -> Ping {
-        Ping::new()
+    fn new() -> ClockOffset {
+        ClockOffset::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1885,18 +8359,23 @@ impl ::protobuf::Message for Ping {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
-                    "source_address",
-                    |m: &Ping| { &m.source_address },
-                    |m: &mut Ping| { &mut m.source_address },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "client_id",
+                    |m: &ClockOffset| { &m.client_id },
+                    |m: &mut ClockOffset| { &mut m.client_id },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
-                    "destination_addresses",
-                    |m: &Ping| { &m.destination_addresses },
-                    |m: &mut Ping| { &mut m.destination_addresses },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "offset",
+                    |m: &ClockOffset| { &m.offset },
+                    |m: &mut ClockOffset| { &mut m.offset },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Ping>(
-                    "Ping",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "delay",
+                    |m: &ClockOffset| { &m.delay },
+                    |m: &mut ClockOffset| { &mut m.delay },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ClockOffset>(
+                    "ClockOffset",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1904,134 +8383,224 @@ impl ::protobuf::Message for Ping {
         }
     }
 
-    fn default_instance() -> &'static Ping {
-        static mut instance: ::protobuf::lazy::Lazy<Ping> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ClockOffset {
+        static mut instance: ::protobuf::lazy::Lazy<ClockOffset> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Ping,
+            ptr: 0 as *const ClockOffset,
         };
         unsafe {
-            instance.get(Ping::new)
+            instance.get(ClockOffset::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Ping {
+impl ::protobuf::Clear for ClockOffset {
     fn clear(&mut self) {
-        self.clear_source_address();
-        self.clear_destination_addresses();
+        self.clear_client_id();
+        self.clear_offset();
+        self.clear_delay();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Ping {
+impl ::std::fmt::Debug for ClockOffset {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Ping {
+impl ::protobuf::reflect::ProtobufValue for ClockOffset {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Address {
+pub struct Result {
     // message oneof groups
-    pub value: ::std::option::Option<Address_oneof_value>,
+    pub value: ::std::option::Option<Result_oneof_value>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
 #[derive(Clone,PartialEq)]
-pub enum Address_oneof_value {
-    v4(u32),
-    v6(::std::vec::Vec<u8>),
+pub enum Result_oneof_value {
+    ping(PingResult),
+    tcp(TcpResult),
+    dns(DnsResult),
 }
 
-impl Address {
-    pub fn new() -> Address {
+impl Result {
+    pub fn new() -> Result {
         ::std::default::Default::default()
     }
 
-    // uint32 v4 = 1;
+    // .PingResult ping = 1;
 
-    pub fn clear_v4(&mut self) {
+    pub fn clear_ping(&mut self) {
         self.value = ::std::option::Option::None;
     }
 
-    pub fn has_v4(&self) -> bool {
+    pub fn has_ping(&self) -> bool {
         match self.value {
-            ::std::option::Option::Some(Address_oneof_value::v4(..)) => true,
+            ::std::option::Option::Some(Result_oneof_value::ping(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_v4(&mut self, v: u32) {
-        self.value = ::std::option::Option::Some(Address_oneof_value::v4(v))
+    pub fn set_ping(&mut self, v: PingResult) {
+        self.value = ::std::option::Option::Some(Result_oneof_value::ping(v))
     }
 
-    pub fn get_v4(&self) -> u32 {
+    // Mutable pointer to the field.
+    pub fn mut_ping(&mut self) -> &mut PingResult {
+        if let ::std::option::Option::Some(Result_oneof_value::ping(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(Result_oneof_value::ping(PingResult::new()));
+        }
         match self.value {
-            ::std::option::Option::Some(Address_oneof_value::v4(v)) => v,
-            _ => 0,
+            ::std::option::Option::Some(Result_oneof_value::ping(ref mut v)) => v,
+            _ => panic!(),
         }
     }
 
-    // bytes v6 = 2;
+    // Take field
+    pub fn take_ping(&mut self) -> PingResult {
+        if self.has_ping() {
+            match self.value.take() {
+                ::std::option::Option::Some(Result_oneof_value::ping(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            PingResult::new()
+        }
+    }
 
-    pub fn clear_v6(&mut self) {
+    pub fn get_ping(&self) -> &PingResult {
+        match self.value {
+            ::std::option::Option::Some(Result_oneof_value::ping(ref v)) => v,
+            _ => PingResult::default_instance(),
+        }
+    }
+
+    // .TcpResult tcp = 2;
+
+    pub fn clear_tcp(&mut self) {
         self.value = ::std::option::Option::None;
     }
 
-    pub fn has_v6(&self) -> bool {
+    pub fn has_tcp(&self) -> bool {
         match self.value {
-            ::std::option::Option::Some(Address_oneof_value::v6(..)) => true,
+            ::std::option::Option::Some(Result_oneof_value::tcp(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_v6(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = ::std::option::Option::Some(Address_oneof_value::v6(v))
+    pub fn set_tcp(&mut self, v: TcpResult) {
+        self.value = ::std::option::Option::Some(Result_oneof_value::tcp(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tcp(&mut self) -> &mut TcpResult {
+        if let ::std::option::Option::Some(Result_oneof_value::tcp(_)) = self.value {
+        } else {
+            self.value = ::std::option::Option::Some(Result_oneof_value::tcp(TcpResult::new()));
+        }
+        match self.value {
+            ::std::option::Option::Some(Result_oneof_value::tcp(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_tcp(&mut self) -> TcpResult {
+        if self.has_tcp() {
+            match self.value.take() {
+                ::std::option::Option::Some(Result_oneof_value::tcp(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            TcpResult::new()
+        }
+    }
+
+    pub fn get_tcp(&self) -> &TcpResult {
+        match self.value {
+            ::std::option::Option::Some(Result_oneof_value::tcp(ref v)) => v,
+            _ => TcpResult::default_instance(),
+        }
+    }
+
+    // .DnsResult dns = 3;
+
+    pub fn clear_dns(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_dns(&self) -> bool {
+        match self.value {
+            ::std::option::Option::Some(Result_oneof_value::dns(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_dns(&mut self, v: DnsResult) {
+        self.value = ::std::option::Option::Some(Result_oneof_value::dns(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_v6(&mut self) -> &mut ::std::vec::Vec<u8> {
-        if let ::std::option::Option::Some(Address_oneof_value::v6(_)) = self.value {
+    pub fn mut_dns(&mut self) -> &mut DnsResult {
+        if let ::std::option::Option::Some(Result_oneof_value::dns(_)) = self.value {
         } else {
-            self.value = ::std::option::Option::Some(Address_oneof_value::v6(::std::vec::Vec::new()));
+            self.value = ::std::option::Option::Some(Result_oneof_value::dns(DnsResult::new()));
         }
         match self.value {
-            ::std::option::Option::Some(Address_oneof_value::v6(ref mut v)) => v,
+            ::std::option::Option::Some(Result_oneof_value::dns(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_v6(&mut self) -> ::std::vec::Vec<u8> {
-        if self.has_v6() {
+    pub fn take_dns(&mut self) -> DnsResult {
+        if self.has_dns() {
             match self.value.take() {
-                ::std::option::Option::Some(Address_oneof_value::v6(v)) => v,
+                ::std::option::Option::Some(Result_oneof_value::dns(v)) => v,
                 _ => panic!(),
             }
         } else {
-            ::std::vec::Vec::new()
+            DnsResult::new()
         }
     }
 
-    pub fn get_v6(&self) -> &[u8] {
+    pub fn get_dns(&self) -> &DnsResult {
         match self.value {
-            ::std::option::Option::Some(Address_oneof_value::v6(ref v)) => v,
-            _ => &[],
+            ::std::option::Option::Some(Result_oneof_value::dns(ref v)) => v,
+            _ => DnsResult::default_instance(),
         }
     }
 }
 
-impl ::protobuf::Message for Address {
+impl ::protobuf::Message for Result {
     fn is_initialized(&self) -> bool {
+        if let Some(Result_oneof_value::ping(ref v)) = self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Result_oneof_value::tcp(ref v)) = self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Result_oneof_value::dns(ref v)) = self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -2040,16 +8609,22 @@ impl ::protobuf::Message for Address {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.value = ::std::option::Option::Some(Address_oneof_value::v4(is.read_uint32()?));
+                    self.value = ::std::option::Option::Some(Result_oneof_value::ping(is.read_message()?));
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.value = ::std::option::Option::Some(Address_oneof_value::v6(is.read_bytes()?));
+                    self.value = ::std::option::Option::Some(Result_oneof_value::tcp(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.value = ::std::option::Option::Some(Result_oneof_value::dns(is.read_message()?));
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2065,11 +8640,17 @@ impl ::protobuf::Message for Address {
         let mut my_size = 0;
         if let ::std::option::Option::Some(ref v) = self.value {
             match v {
-                &Address_oneof_value::v4(v) => {
-                    my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+                &Result_oneof_value::ping(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
-                &Address_oneof_value::v6(ref v) => {
-                    my_size += ::protobuf::rt::bytes_size(2, &v);
+                &Result_oneof_value::tcp(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Result_oneof_value::dns(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
             };
         }
@@ -2081,11 +8662,20 @@ impl ::protobuf::Message for Address {
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
         if let ::std::option::Option::Some(ref v) = self.value {
             match v {
-                &Address_oneof_value::v4(v) => {
-                    os.write_uint32(1, v)?;
+                &Result_oneof_value::ping(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
-                &Address_oneof_value::v6(ref v) => {
-                    os.write_bytes(2, v)?;
+                &Result_oneof_value::tcp(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Result_oneof_value::dns(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
                 },
             };
         }
@@ -2119,8 +8709,8 @@ impl ::protobuf::Message for Address {
         Self::descriptor_static()
     }
 
-    fn new() -> Address {
-        Address::new()
+    fn new() -> Result {
+        Result::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2131,18 +8721,23 @@ impl ::protobuf::Message for Address {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor::<_>(
-                    "v4",
-                    Address::has_v4,
-                    Address::get_v4,
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingResult>(
+                    "ping",
+                    Result::has_ping,
+                    Result::get_ping,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bytes_accessor::<_>(
-                    "v6",
-                    Address::has_v6,
-                    Address::get_v6,
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, TcpResult>(
+                    "tcp",
+                    Result::has_tcp,
+                    Result::get_tcp,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Address>(
-                    "Address",
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, DnsResult>(
+                    "dns",
+                    Result::has_dns,
+                    Result::get_dns,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Result>(
+                    "Result",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2150,151 +8745,199 @@ impl ::protobuf::Message for Address {
         }
     }
 
-    fn default_instance() -> &'static Address {
-        static mut instance: ::protobuf::lazy::Lazy<Address> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Result {
+        static mut instance: ::protobuf::lazy::Lazy<Result> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Address,
+            ptr: 0 as *const Result,
         };
         unsafe {
-            instance.get(Address::new)
+            instance.get(Result::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Address {
+impl ::protobuf::Clear for Result {
     fn clear(&mut self) {
-        self.clear_v4();
-        self.clear_v6();
+        self.clear_ping();
+        self.clear_tcp();
+        self.clear_dns();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Address {
+impl ::std::fmt::Debug for Result {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Address {
+impl ::protobuf::reflect::ProtobufValue for Result {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct TaskResult {
+pub struct PingResult {
     // message fields
-    pub task_id: u32,
-    pub client: ::protobuf::SingularPtrField<Client>,
-    pub result_list: ::protobuf::RepeatedField<Result>,
-    pub is_finished: bool,
+    pub source_address: ::protobuf::SingularPtrField<Address>,
+    pub destination_address: ::protobuf::SingularPtrField<Address>,
+    pub receive_time: u64,
+    pub payload: ::protobuf::SingularPtrField<PingPayload>,
+    pub ttl: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl TaskResult {
-    pub fn new() -> TaskResult {
+impl PingResult {
+    pub fn new() -> PingResult {
         ::std::default::Default::default()
     }
 
-    // uint32 task_id = 1;
+    // .Address source_address = 1;
 
-    pub fn clear_task_id(&mut self) {
-        self.task_id = 0;
+    pub fn clear_source_address(&mut self) {
+        self.source_address.clear();
+    }
+
+    pub fn has_source_address(&self) -> bool {
+        self.source_address.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_task_id(&mut self, v: u32) {
-        self.task_id = v;
+    pub fn set_source_address(&mut self, v: Address) {
+        self.source_address = ::protobuf::SingularPtrField::some(v);
     }
 
-    pub fn get_task_id(&self) -> u32 {
-        self.task_id
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_address(&mut self) -> &mut Address {
+        if self.source_address.is_none() {
+            self.source_address.set_default();
+        }
+        self.source_address.as_mut().unwrap()
     }
 
-    // .Client client = 2;
+    // Take field
+    pub fn take_source_address(&mut self) -> Address {
+        self.source_address.take().unwrap_or_else(|| Address::new())
+    }
 
-    pub fn clear_client(&mut self) {
-        self.client.clear();
+    pub fn get_source_address(&self) -> &Address {
+        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
     }
 
-    pub fn has_client(&self) -> bool {
-        self.client.is_some()
+    // .Address destination_address = 2;
+
+    pub fn clear_destination_address(&mut self) {
+        self.destination_address.clear();
+    }
+
+    pub fn has_destination_address(&self) -> bool {
+        self.destination_address.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_client(&mut self, v: Client) {
-        self.client = ::protobuf::SingularPtrField::some(v);
+    pub fn set_destination_address(&mut self, v: Address) {
+        self.destination_address = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_client(&mut self) -> &mut Client {
-        if self.client.is_none() {
-            self.client.set_default();
+    pub fn mut_destination_address(&mut self) -> &mut Address {
+        if self.destination_address.is_none() {
+            self.destination_address.set_default();
         }
-        self.client.as_mut().unwrap()
+        self.destination_address.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_client(&mut self) -> Client {
-        self.client.take().unwrap_or_else(|| Client::new())
+    pub fn take_destination_address(&mut self) -> Address {
+        self.destination_address.take().unwrap_or_else(|| Address::new())
     }
 
-    pub fn get_client(&self) -> &Client {
-        self.client.as_ref().unwrap_or_else(|| Client::default_instance())
+    pub fn get_destination_address(&self) -> &Address {
+        self.destination_address.as_ref().unwrap_or_else(|| Address::default_instance())
     }
 
-    // repeated .Result result_list = 3;
+    // uint64 receive_time = 3;
 
-    pub fn clear_result_list(&mut self) {
-        self.result_list.clear();
+    pub fn clear_receive_time(&mut self) {
+        self.receive_time = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_result_list(&mut self, v: ::protobuf::RepeatedField<Result>) {
-        self.result_list = v;
+    pub fn set_receive_time(&mut self, v: u64) {
+        self.receive_time = v;
+    }
+
+    pub fn get_receive_time(&self) -> u64 {
+        self.receive_time
+    }
+
+    // .PingPayload payload = 4;
+
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    pub fn has_payload(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_payload(&mut self, v: PingPayload) {
+        self.payload = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_result_list(&mut self) -> &mut ::protobuf::RepeatedField<Result> {
-        &mut self.result_list
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_payload(&mut self) -> &mut PingPayload {
+        if self.payload.is_none() {
+            self.payload.set_default();
+        }
+        self.payload.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_result_list(&mut self) -> ::protobuf::RepeatedField<Result> {
-        ::std::mem::replace(&mut self.result_list, ::protobuf::RepeatedField::new())
+    pub fn take_payload(&mut self) -> PingPayload {
+        self.payload.take().unwrap_or_else(|| PingPayload::new())
     }
 
-    pub fn get_result_list(&self) -> &[Result] {
-        &self.result_list
+    pub fn get_payload(&self) -> &PingPayload {
+        self.payload.as_ref().unwrap_or_else(|| PingPayload::default_instance())
     }
 
-    // bool is_finished = 4;
+    // uint32 ttl = 5;
 
-    pub fn clear_is_finished(&mut self) {
-        self.is_finished = false;
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_is_finished(&mut self, v: bool) {
-        self.is_finished = v;
+    pub fn set_ttl(&mut self, v: u32) {
+        self.ttl = v;
     }
 
-    pub fn get_is_finished(&self) -> bool {
-        self.is_finished
+    pub fn get_ttl(&self) -> u32 {
+        self.ttl
     }
 }
 
-impl ::protobuf::Message for TaskResult {
+impl ::protobuf::Message for PingResult {
     fn is_initialized(&self) -> bool {
-        for v in &self.client {
+        for v in &self.source_address {
             if !v.is_initialized() {
                 return false;
             }
         };
-        for v in &self.result_list {
+        for v in &self.destination_address {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.payload {
             if !v.is_initialized() {
                 return false;
             }
@@ -2304,27 +8947,30 @@ impl ::protobuf::Message for TaskResult {
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
         while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint32()?;
-                    self.task_id = tmp;
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.client)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.destination_address)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.result_list)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.receive_time = tmp;
                 },
                 4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.payload)?;
+                },
+                5 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_bool()?;
-                    self.is_finished = tmp;
+                    let tmp = is.read_uint32()?;
+                    self.ttl = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2338,19 +8984,23 @@ impl ::protobuf::Message for TaskResult {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.task_id != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.source_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if let Some(ref v) = self.client.as_ref() {
+        if let Some(ref v) = self.destination_address.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        for value in &self.result_list {
-            let len = value.compute_size();
+        if self.receive_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.receive_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.is_finished != false {
-            my_size += 2;
+        }
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.ttl, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2358,21 +9008,26 @@ impl ::protobuf::Message for TaskResult {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if self.task_id != 0 {
-            os.write_uint32(1, self.task_id)?;
+        if let Some(ref v) = self.source_address.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
-        if let Some(ref v) = self.client.as_ref() {
+        if let Some(ref v) = self.destination_address.as_ref() {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
-        for v in &self.result_list {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if self.receive_time != 0 {
+            os.write_uint64(3, self.receive_time)?;
+        }
+        if let Some(ref v) = self.payload.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        };
-        if self.is_finished != false {
-            os.write_bool(4, self.is_finished)?;
+        }
+        if self.ttl != 0 {
+            os.write_uint32(5, self.ttl)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2404,8 +9059,8 @@ impl ::protobuf::Message for TaskResult {
         Self::descriptor_static()
     }
 
-    fn new() -> TaskResult {
-        TaskResult::new()
+    fn new() -> PingResult {
+        PingResult::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2416,28 +9071,33 @@ impl ::protobuf::Message for TaskResult {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
-                    "task_id",
-                    |m: &TaskResult| { &m.task_id },
-                    |m: &mut TaskResult| { &mut m.task_id },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "source_address",
+                    |m: &PingResult| { &m.source_address },
+                    |m: &mut PingResult| { &mut m.source_address },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Client>>(
-                    "client",
-                    |m: &TaskResult| { &m.client },
-                    |m: &mut TaskResult| { &mut m.client },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "destination_address",
+                    |m: &PingResult| { &m.destination_address },
+                    |m: &mut PingResult| { &mut m.destination_address },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Result>>(
-                    "result_list",
-                    |m: &TaskResult| { &m.result_list },
-                    |m: &mut TaskResult| { &mut m.result_list },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "receive_time",
+                    |m: &PingResult| { &m.receive_time },
+                    |m: &mut PingResult| { &mut m.receive_time },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "is_finished",
-                    |m: &TaskResult| { &m.is_finished },
-                    |m: &mut TaskResult| { &mut m.is_finished },
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PingPayload>>(
+                    "payload",
+                    |m: &PingResult| { &m.payload },
+                    |m: &mut PingResult| { &mut m.payload },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<TaskResult>(
-                    "TaskResult",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "ttl",
+                    |m: &PingResult| { &m.ttl },
+                    |m: &mut PingResult| { &mut m.ttl },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PingResult>(
+                    "PingResult",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2445,115 +9105,230 @@ impl ::protobuf::Message for TaskResult {
         }
     }
 
-    fn default_instance() -> &'static TaskResult {
-        static mut instance: ::protobuf::lazy::Lazy<TaskResult> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PingResult {
+        static mut instance: ::protobuf::lazy::Lazy<PingResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const TaskResult,
+            ptr: 0 as *const PingResult,
         };
         unsafe {
-            instance.get(TaskResult::new)
+            instance.get(PingResult::new)
         }
     }
 }
 
-impl ::protobuf::Clear for TaskResult {
+impl ::protobuf::Clear for PingResult {
     fn clear(&mut self) {
-        self.clear_task_id();
-        self.clear_client();
-        self.clear_result_list();
-        self.clear_is_finished();
+        self.clear_source_address();
+        self.clear_destination_address();
+        self.clear_receive_time();
+        self.clear_payload();
+        self.clear_ttl();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for TaskResult {
+impl ::std::fmt::Debug for PingResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for TaskResult {
+impl ::protobuf::reflect::ProtobufValue for PingResult {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Result {
-    // message oneof groups
-    pub value: ::std::option::Option<Result_oneof_value>,
+pub struct TcpResult {
+    // message fields
+    pub source_address: ::protobuf::SingularPtrField<Address>,
+    pub destination_address: ::protobuf::SingularPtrField<Address>,
+    pub receive_time: u64,
+    pub ttl: u32,
+    pub task_id: u32,
+    pub transmit_time: u64,
+    pub source_port: u32,
+    pub flags: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-#[derive(Clone,PartialEq)]
-pub enum Result_oneof_value {
-    ping(PingResult),
-}
-
-impl Result {
-    pub fn new() -> Result {
+impl TcpResult {
+    pub fn new() -> TcpResult {
         ::std::default::Default::default()
     }
 
-    // .PingResult ping = 1;
+    // .Address source_address = 1;
+
+    pub fn clear_source_address(&mut self) {
+        self.source_address.clear();
+    }
+
+    pub fn has_source_address(&self) -> bool {
+        self.source_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_source_address(&mut self, v: Address) {
+        self.source_address = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_source_address(&mut self) -> &mut Address {
+        if self.source_address.is_none() {
+            self.source_address.set_default();
+        }
+        self.source_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_source_address(&mut self) -> Address {
+        self.source_address.take().unwrap_or_else(|| Address::new())
+    }
+
+    pub fn get_source_address(&self) -> &Address {
+        self.source_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    }
+
+    // .Address destination_address = 2;
+
+    pub fn clear_destination_address(&mut self) {
+        self.destination_address.clear();
+    }
+
+    pub fn has_destination_address(&self) -> bool {
+        self.destination_address.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_destination_address(&mut self, v: Address) {
+        self.destination_address = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_destination_address(&mut self) -> &mut Address {
+        if self.destination_address.is_none() {
+            self.destination_address.set_default();
+        }
+        self.destination_address.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_destination_address(&mut self) -> Address {
+        self.destination_address.take().unwrap_or_else(|| Address::new())
+    }
+
+    pub fn get_destination_address(&self) -> &Address {
+        self.destination_address.as_ref().unwrap_or_else(|| Address::default_instance())
+    }
+
+    // uint64 receive_time = 3;
+
+    pub fn clear_receive_time(&mut self) {
+        self.receive_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_receive_time(&mut self, v: u64) {
+        self.receive_time = v;
+    }
+
+    pub fn get_receive_time(&self) -> u64 {
+        self.receive_time
+    }
+
+    // uint32 ttl = 4;
+
+    pub fn clear_ttl(&mut self) {
+        self.ttl = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ttl(&mut self, v: u32) {
+        self.ttl = v;
+    }
+
+    pub fn get_ttl(&self) -> u32 {
+        self.ttl
+    }
+
+    // uint32 task_id = 5;
+
+    pub fn clear_task_id(&mut self) {
+        self.task_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_id(&mut self, v: u32) {
+        self.task_id = v;
+    }
+
+    pub fn get_task_id(&self) -> u32 {
+        self.task_id
+    }
+
+    // uint64 transmit_time = 6;
+
+    pub fn clear_transmit_time(&mut self) {
+        self.transmit_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transmit_time(&mut self, v: u64) {
+        self.transmit_time = v;
+    }
 
-    pub fn clear_ping(&mut self) {
-        self.value = ::std::option::Option::None;
+    pub fn get_transmit_time(&self) -> u64 {
+        self.transmit_time
     }
 
-    pub fn has_ping(&self) -> bool {
-        match self.value {
-            ::std::option::Option::Some(Result_oneof_value::ping(..)) => true,
-            _ => false,
-        }
+    // uint32 source_port = 7;
+
+    pub fn clear_source_port(&mut self) {
+        self.source_port = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_ping(&mut self, v: PingResult) {
-        self.value = ::std::option::Option::Some(Result_oneof_value::ping(v))
+    pub fn set_source_port(&mut self, v: u32) {
+        self.source_port = v;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_ping(&mut self) -> &mut PingResult {
-        if let ::std::option::Option::Some(Result_oneof_value::ping(_)) = self.value {
-        } else {
-            self.value = ::std::option::Option::Some(Result_oneof_value::ping(PingResult::new()));
-        }
-        match self.value {
-            ::std::option::Option::Some(Result_oneof_value::ping(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn get_source_port(&self) -> u32 {
+        self.source_port
     }
 
-    // Take field
-    pub fn take_ping(&mut self) -> PingResult {
-        if self.has_ping() {
-            match self.value.take() {
-                ::std::option::Option::Some(Result_oneof_value::ping(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            PingResult::new()
-        }
+    // uint32 flags = 8;
+
+    pub fn clear_flags(&mut self) {
+        self.flags = 0;
     }
 
-    pub fn get_ping(&self) -> &PingResult {
-        match self.value {
-            ::std::option::Option::Some(Result_oneof_value::ping(ref v)) => v,
-            _ => PingResult::default_instance(),
-        }
+    // Param is passed by value, moved
+    pub fn set_flags(&mut self, v: u32) {
+        self.flags = v;
+    }
+
+    pub fn get_flags(&self) -> u32 {
+        self.flags
     }
 }
 
-impl ::protobuf::Message for Result {
+impl ::protobuf::Message for TcpResult {
     fn is_initialized(&self) -> bool {
-        if let Some(Result_oneof_value::ping(ref v)) = self.value {
+        for v in &self.source_address {
             if !v.is_initialized() {
                 return false;
             }
-        }
+        };
+        for v in &self.destination_address {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2562,10 +9337,52 @@ impl ::protobuf::Message for Result {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.source_address)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.destination_address)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    self.value = ::std::option::Option::Some(Result_oneof_value::ping(is.read_message()?));
+                    let tmp = is.read_uint64()?;
+                    self.receive_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.ttl = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_id = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.transmit_time = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.source_port = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.flags = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2579,13 +9396,31 @@ impl ::protobuf::Message for Result {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.value {
-            match v {
-                &Result_oneof_value::ping(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if let Some(ref v) = self.source_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.destination_address.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.receive_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.receive_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.ttl != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.ttl, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.task_id != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.task_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.transmit_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.transmit_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.source_port != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.source_port, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.flags != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.flags, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2593,14 +9428,33 @@ impl ::protobuf::Message for Result {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.value {
-            match v {
-                &Result_oneof_value::ping(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if let Some(ref v) = self.source_address.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.destination_address.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.receive_time != 0 {
+            os.write_uint64(3, self.receive_time)?;
+        }
+        if self.ttl != 0 {
+            os.write_uint32(4, self.ttl)?;
+        }
+        if self.task_id != 0 {
+            os.write_uint32(5, self.task_id)?;
+        }
+        if self.transmit_time != 0 {
+            os.write_uint64(6, self.transmit_time)?;
+        }
+        if self.source_port != 0 {
+            os.write_uint32(7, self.source_port)?;
+        }
+        if self.flags != 0 {
+            os.write_uint32(8, self.flags)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2632,8 +9486,8 @@ impl ::protobuf::Message for Result {
         Self::descriptor_static()
     }
 
-    fn new() -> Result {
-        Result::new()
+    fn new() -> TcpResult {
+        TcpResult::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2644,13 +9498,48 @@ impl ::protobuf::Message for Result {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, PingResult>(
-                    "ping",
-                    Result::has_ping,
-                    Result::get_ping,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "source_address",
+                    |m: &TcpResult| { &m.source_address },
+                    |m: &mut TcpResult| { &mut m.source_address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Result>(
-                    "Result",
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
+                    "destination_address",
+                    |m: &TcpResult| { &m.destination_address },
+                    |m: &mut TcpResult| { &mut m.destination_address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "receive_time",
+                    |m: &TcpResult| { &m.receive_time },
+                    |m: &mut TcpResult| { &mut m.receive_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "ttl",
+                    |m: &TcpResult| { &m.ttl },
+                    |m: &mut TcpResult| { &mut m.ttl },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "task_id",
+                    |m: &TcpResult| { &m.task_id },
+                    |m: &mut TcpResult| { &mut m.task_id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "transmit_time",
+                    |m: &TcpResult| { &m.transmit_time },
+                    |m: &mut TcpResult| { &mut m.transmit_time },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "source_port",
+                    |m: &TcpResult| { &m.source_port },
+                    |m: &mut TcpResult| { &mut m.source_port },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "flags",
+                    |m: &TcpResult| { &m.flags },
+                    |m: &mut TcpResult| { &mut m.flags },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TcpResult>(
+                    "TcpResult",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2658,51 +9547,59 @@ impl ::protobuf::Message for Result {
         }
     }
 
-    fn default_instance() -> &'static Result {
-        static mut instance: ::protobuf::lazy::Lazy<Result> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static TcpResult {
+        static mut instance: ::protobuf::lazy::Lazy<TcpResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Result,
+            ptr: 0 as *const TcpResult,
         };
         unsafe {
-            instance.get(Result::new)
+            instance.get(TcpResult::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Result {
+impl ::protobuf::Clear for TcpResult {
     fn clear(&mut self) {
-        self.clear_ping();
+        self.clear_source_address();
+        self.clear_destination_address();
+        self.clear_receive_time();
+        self.clear_ttl();
+        self.clear_task_id();
+        self.clear_transmit_time();
+        self.clear_source_port();
+        self.clear_flags();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Result {
+impl ::std::fmt::Debug for TcpResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Result {
+impl ::protobuf::reflect::ProtobufValue for TcpResult {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct PingResult {
+pub struct DnsResult {
     // message fields
     pub source_address: ::protobuf::SingularPtrField<Address>,
     pub destination_address: ::protobuf::SingularPtrField<Address>,
     pub receive_time: u64,
     pub payload: ::protobuf::SingularPtrField<PingPayload>,
     pub ttl: u32,
+    pub rcode: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl PingResult {
-    pub fn new() -> PingResult {
+impl DnsResult {
+    pub fn new() -> DnsResult {
         ::std::default::Default::default()
     }
 
@@ -2834,9 +9731,24 @@ impl PingResult {
     pub fn get_ttl(&self) -> u32 {
         self.ttl
     }
+
+    // uint32 rcode = 6;
+
+    pub fn clear_rcode(&mut self) {
+        self.rcode = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rcode(&mut self, v: u32) {
+        self.rcode = v;
+    }
+
+    pub fn get_rcode(&self) -> u32 {
+        self.rcode
+    }
 }
 
-impl ::protobuf::Message for PingResult {
+impl ::protobuf::Message for DnsResult {
     fn is_initialized(&self) -> bool {
         for v in &self.source_address {
             if !v.is_initialized() {
@@ -2883,6 +9795,13 @@ impl ::protobuf::Message for PingResult {
                     let tmp = is.read_uint32()?;
                     self.ttl = tmp;
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.rcode = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2913,6 +9832,9 @@ impl ::protobuf::Message for PingResult {
         if self.ttl != 0 {
             my_size += ::protobuf::rt::value_size(5, self.ttl, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.rcode != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.rcode, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2940,6 +9862,9 @@ impl ::protobuf::Message for PingResult {
         if self.ttl != 0 {
             os.write_uint32(5, self.ttl)?;
         }
+        if self.rcode != 0 {
+            os.write_uint32(6, self.rcode)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2970,8 +9895,8 @@ impl ::protobuf::Message for PingResult {
         Self::descriptor_static()
     }
 
-    fn new() -> PingResult {
-        PingResult::new()
+    fn new() -> DnsResult {
+        DnsResult::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2984,31 +9909,36 @@ impl ::protobuf::Message for PingResult {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
                     "source_address",
-                    |m: &PingResult| { &m.source_address },
-                    |m: &mut PingResult| { &mut m.source_address },
+                    |m: &DnsResult| { &m.source_address },
+                    |m: &mut DnsResult| { &mut m.source_address },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Address>>(
                     "destination_address",
-                    |m: &PingResult| { &m.destination_address },
-                    |m: &mut PingResult| { &mut m.destination_address },
+                    |m: &DnsResult| { &m.destination_address },
+                    |m: &mut DnsResult| { &mut m.destination_address },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "receive_time",
-                    |m: &PingResult| { &m.receive_time },
-                    |m: &mut PingResult| { &mut m.receive_time },
+                    |m: &DnsResult| { &m.receive_time },
+                    |m: &mut DnsResult| { &mut m.receive_time },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PingPayload>>(
                     "payload",
-                    |m: &PingResult| { &m.payload },
-                    |m: &mut PingResult| { &mut m.payload },
+                    |m: &DnsResult| { &m.payload },
+                    |m: &mut DnsResult| { &mut m.payload },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
                     "ttl",
-                    |m: &PingResult| { &m.ttl },
-                    |m: &mut PingResult| { &mut m.ttl },
+                    |m: &DnsResult| { &m.ttl },
+                    |m: &mut DnsResult| { &mut m.ttl },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<PingResult>(
-                    "PingResult",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "rcode",
+                    |m: &DnsResult| { &m.rcode },
+                    |m: &mut DnsResult| { &mut m.rcode },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<DnsResult>(
+                    "DnsResult",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3016,35 +9946,36 @@ impl ::protobuf::Message for PingResult {
         }
     }
 
-    fn default_instance() -> &'static PingResult {
-        static mut instance: ::protobuf::lazy::Lazy<PingResult> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static DnsResult {
+        static mut instance: ::protobuf::lazy::Lazy<DnsResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const PingResult,
+            ptr: 0 as *const DnsResult,
         };
         unsafe {
-            instance.get(PingResult::new)
+            instance.get(DnsResult::new)
         }
     }
 }
 
-impl ::protobuf::Clear for PingResult {
+impl ::protobuf::Clear for DnsResult {
     fn clear(&mut self) {
         self.clear_source_address();
         self.clear_destination_address();
         self.clear_receive_time();
         self.clear_payload();
         self.clear_ttl();
+        self.clear_rcode();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for PingResult {
+impl ::std::fmt::Debug for DnsResult {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for PingResult {
+impl ::protobuf::reflect::ProtobufValue for DnsResult {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -3057,6 +9988,7 @@ pub struct PingPayload {
     pub transmit_time: u64,
     pub source_address: ::protobuf::SingularPtrField<Address>,
     pub destination_address: ::protobuf::SingularPtrField<Address>,
+    pub key_id: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -3162,6 +10094,21 @@ impl PingPayload {
     pub fn get_destination_address(&self) -> &Address {
         self.destination_address.as_ref().unwrap_or_else(|| Address::default_instance())
     }
+
+    // uint32 key_id = 5;
+
+    pub fn clear_key_id(&mut self) {
+        self.key_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key_id(&mut self, v: u32) {
+        self.key_id = v;
+    }
+
+    pub fn get_key_id(&self) -> u32 {
+        self.key_id
+    }
 }
 
 impl ::protobuf::Message for PingPayload {
@@ -3203,6 +10150,13 @@ impl ::protobuf::Message for PingPayload {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.destination_address)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.key_id = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3229,6 +10183,9 @@ impl ::protobuf::Message for PingPayload {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if self.key_id != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.key_id, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3251,6 +10208,9 @@ impl ::protobuf::Message for PingPayload {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if self.key_id != 0 {
+            os.write_uint32(5, self.key_id)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3313,6 +10273,11 @@ impl ::protobuf::Message for PingPayload {
                     |m: &PingPayload| { &m.destination_address },
                     |m: &mut PingPayload| { &mut m.destination_address },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "key_id",
+                    |m: &PingPayload| { &m.key_id },
+                    |m: &mut PingPayload| { &mut m.key_id },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<PingPayload>(
                     "PingPayload",
                     fields,
@@ -3339,6 +10304,7 @@ impl ::protobuf::Clear for PingPayload {
         self.clear_transmit_time();
         self.clear_source_address();
         self.clear_destination_address();
+        self.clear_key_id();
         self.unknown_fields.clear();
     }
 }
@@ -3360,181 +10326,707 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x07task_id\x18\x01\x20\x01(\rR\x06taskId\x12\x18\n\x07success\x18\x02\
     \x20\x01(\x08R\x07success\x12#\n\rerror_message\x18\x03\x20\x01(\tR\x0ce\
     rrorMessage\"!\n\x06TaskId\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06t\
-    askId\"T\n\x0cScheduleTask\x12\x1f\n\x06client\x18\x01\x20\x01(\x0b2\x07\
-    .ClientR\x06client\x12\x1b\n\x04ping\x18\x02\x20\x01(\x0b2\x05.PingH\0R\
-    \x04pingB\x06\n\x04data\"/\n\nClientList\x12!\n\x07clients\x18\x01\x20\
-    \x03(\x0b2\x07.ClientR\x07clients\"E\n\x06Client\x12\x14\n\x05index\x18\
-    \x01\x20\x01(\rR\x05index\x12%\n\x08metadata\x18\x02\x20\x01(\x0b2\t.Met\
-    adataR\x08metadata\"d\n\x04Task\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\
-    \x06taskId\x12\x1b\n\x04ping\x18\x02\x20\x01(\x0b2\x05.PingH\0R\x04ping\
-    \x12\x1e\n\x05empty\x18\x03\x20\x01(\x0b2\x06.EmptyH\0R\x05emptyB\x06\n\
-    \x04data\"@\n\x08Metadata\x12\x1a\n\x08hostname\x18\x01\x20\x01(\tR\x08h\
-    ostname\x12\x18\n\x07version\x18\x02\x20\x01(\tR\x07version\"v\n\x04Ping\
-    \x12/\n\x0esource_address\x18\x01\x20\x01(\x0b2\x08.AddressR\rsourceAddr\
-    ess\x12=\n\x15destination_addresses\x18\x02\x20\x03(\x0b2\x08.AddressR\
-    \x14destinationAddresses\"6\n\x07Address\x12\x10\n\x02v4\x18\x01\x20\x01\
-    (\rH\0R\x02v4\x12\x10\n\x02v6\x18\x02\x20\x01(\x0cH\0R\x02v6B\x07\n\x05v\
-    alue\"\x91\x01\n\nTaskResult\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\
-    \x06taskId\x12\x1f\n\x06client\x18\x02\x20\x01(\x0b2\x07.ClientR\x06clie\
-    nt\x12(\n\x0bresult_list\x18\x03\x20\x03(\x0b2\x07.ResultR\nresultList\
-    \x12\x1f\n\x0bis_finished\x18\x04\x20\x01(\x08R\nisFinished\"4\n\x06Resu\
-    lt\x12!\n\x04ping\x18\x01\x20\x01(\x0b2\x0b.PingResultH\0R\x04pingB\x07\
-    \n\x05value\"\xd5\x01\n\nPingResult\x12/\n\x0esource_address\x18\x01\x20\
-    \x01(\x0b2\x08.AddressR\rsourceAddress\x129\n\x13destination_address\x18\
-    \x02\x20\x01(\x0b2\x08.AddressR\x12destinationAddress\x12!\n\x0creceive_\
-    time\x18\x03\x20\x01(\x04R\x0breceiveTime\x12&\n\x07payload\x18\x04\x20\
-    \x01(\x0b2\x0c.PingPayloadR\x07payload\x12\x10\n\x03ttl\x18\x05\x20\x01(\
-    \rR\x03ttl\"\xb7\x01\n\x0bPingPayload\x12\x17\n\x07task_id\x18\x01\x20\
-    \x01(\rR\x06taskId\x12#\n\rtransmit_time\x18\x02\x20\x01(\x04R\x0ctransm\
-    itTime\x12/\n\x0esource_address\x18\x03\x20\x01(\x0b2\x08.AddressR\rsour\
-    ceAddress\x129\n\x13destination_address\x18\x04\x20\x01(\x0b2\x08.Addres\
-    sR\x12destinationAddress2\xeb\x01\n\x0bVerfploeter\x12\x1f\n\x07connect\
-    \x12\t.Metadata\x1a\x05.Task\"\00\x01\x12\x20\n\x07do_task\x12\r.Schedul\
-    eTask\x1a\x04.Ack\"\0\x12%\n\x0clist_clients\x12\x06.Empty\x1a\x0b.Clien\
-    tList\"\0\x12\"\n\x0bsend_result\x12\x0b.TaskResult\x1a\x04.Ack\"\0\x12,\
-    \n\x10subscribe_result\x12\x07.TaskId\x1a\x0b.TaskResult\"\00\x01\x12\
-    \x20\n\rtask_finished\x12\x07.TaskId\x1a\x04.Ack\"\0J\xb0\x17\n\x06\x12\
-    \x04\0\0Y\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x06\0\x12\x04\
-    \x02\0\t\x01\n\n\n\x03\x06\0\x01\x12\x03\x02\x08\x13\n\x0b\n\x04\x06\0\
-    \x02\0\x12\x03\x03\x042\n\x0c\n\x05\x06\0\x02\0\x01\x12\x03\x03\x08\x0f\
-    \n\x0c\n\x05\x06\0\x02\0\x02\x12\x03\x03\x10\x18\n\x0c\n\x05\x06\0\x02\0\
-    \x06\x12\x03\x03#)\n\x0c\n\x05\x06\0\x02\0\x03\x12\x03\x03*.\n\x0b\n\x04\
-    \x06\0\x02\x01\x12\x03\x04\x04.\n\x0c\n\x05\x06\0\x02\x01\x01\x12\x03\
-    \x04\x08\x0f\n\x0c\n\x05\x06\0\x02\x01\x02\x12\x03\x04\x10\x1c\n\x0c\n\
-    \x05\x06\0\x02\x01\x03\x12\x03\x04'*\n\x0b\n\x04\x06\0\x02\x02\x12\x03\
-    \x05\x043\n\x0c\n\x05\x06\0\x02\x02\x01\x12\x03\x05\x08\x14\n\x0c\n\x05\
-    \x06\0\x02\x02\x02\x12\x03\x05\x15\x1a\n\x0c\n\x05\x06\0\x02\x02\x03\x12\
-    \x03\x05%/\n\x0b\n\x04\x06\0\x02\x03\x12\x03\x06\x040\n\x0c\n\x05\x06\0\
-    \x02\x03\x01\x12\x03\x06\x08\x13\n\x0c\n\x05\x06\0\x02\x03\x02\x12\x03\
-    \x06\x14\x1e\n\x0c\n\x05\x06\0\x02\x03\x03\x12\x03\x06),\n\x0b\n\x04\x06\
-    \0\x02\x04\x12\x03\x07\x04?\n\x0c\n\x05\x06\0\x02\x04\x01\x12\x03\x07\
-    \x08\x18\n\x0c\n\x05\x06\0\x02\x04\x02\x12\x03\x07\x19\x1f\n\x0c\n\x05\
-    \x06\0\x02\x04\x06\x12\x03\x07*0\n\x0c\n\x05\x06\0\x02\x04\x03\x12\x03\
-    \x071;\n\x0b\n\x04\x06\0\x02\x05\x12\x03\x08\x04.\n\x0c\n\x05\x06\0\x02\
-    \x05\x01\x12\x03\x08\x08\x15\n\x0c\n\x05\x06\0\x02\x05\x02\x12\x03\x08\
-    \x16\x1c\n\x0c\n\x05\x06\0\x02\x05\x03\x12\x03\x08'*\n\t\n\x02\x04\0\x12\
-    \x03\x0b\0\x10\n\n\n\x03\x04\0\x01\x12\x03\x0b\x08\r\n\n\n\x02\x04\x01\
-    \x12\x04\r\0\x11\x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x0b\n\x0b\n\x04\
-    \x04\x01\x02\0\x12\x03\x0e\x04\x17\n\r\n\x05\x04\x01\x02\0\x04\x12\x04\
-    \x0e\x04\r\r\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\x0e\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\x0e\x15\x16\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0f\x04\x15\n\r\n\
-    \x05\x04\x01\x02\x01\x04\x12\x04\x0f\x04\x0e\x17\n\x0c\n\x05\x04\x01\x02\
-    \x01\x05\x12\x03\x0f\x04\x08\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0f\
-    \t\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0f\x13\x14\n\x0b\n\x04\
-    \x04\x01\x02\x02\x12\x03\x10\x04\x1d\n\r\n\x05\x04\x01\x02\x02\x04\x12\
-    \x04\x10\x04\x0f\x15\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x10\x04\n\n\
-    \x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x10\x0b\x18\n\x0c\n\x05\x04\x01\
-    \x02\x02\x03\x12\x03\x10\x1b\x1c\n\t\n\x02\x04\x02\x12\x03\x13\0&\n\n\n\
-    \x03\x04\x02\x01\x12\x03\x13\x08\x0e\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
-    \x13\x11$\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x13\x11\x10\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03\x13\x11\x17\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03\x13\x18\x1f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x13\"#\n\n\n\x02\
-    \x04\x03\x12\x04\x15\0\x1a\x01\n\n\n\x03\x04\x03\x01\x12\x03\x15\x08\x14\
-    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x16\x04\x16\n\r\n\x05\x04\x03\x02\0\
-    \x04\x12\x04\x16\x04\x15\x16\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x16\
-    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x16\x0b\x11\n\x0c\n\x05\x04\
-    \x03\x02\0\x03\x12\x03\x16\x14\x15\n\x0c\n\x04\x04\x03\x08\0\x12\x04\x17\
-    \x04\x19\x05\n\x0c\n\x05\x04\x03\x08\0\x01\x12\x03\x17\n\x0e\n\x0b\n\x04\
-    \x04\x03\x02\x01\x12\x03\x18\x08\x16\n\x0c\n\x05\x04\x03\x02\x01\x06\x12\
-    \x03\x18\x08\x0c\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x18\r\x11\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x03\x18\x14\x15\n\n\n\x02\x04\x04\x12\x04\
-    \x1c\0\x1e\x01\n\n\n\x03\x04\x04\x01\x12\x03\x1c\x08\x12\n\x0b\n\x04\x04\
-    \x04\x02\0\x12\x03\x1d\x04\x20\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1d\
-    \x04\x0c\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03\x1d\r\x13\n\x0c\n\x05\x04\
-    \x04\x02\0\x01\x12\x03\x1d\x14\x1b\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\
-    \x1d\x1e\x1f\n\n\n\x02\x04\x05\x12\x04\x20\0#\x01\n\n\n\x03\x04\x05\x01\
-    \x12\x03\x20\x08\x0e\n\x0b\n\x04\x04\x05\x02\0\x12\x03!\x04\x15\n\r\n\
-    \x05\x04\x05\x02\0\x04\x12\x04!\x04\x20\x10\n\x0c\n\x05\x04\x05\x02\0\
-    \x05\x12\x03!\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03!\x0b\x10\n\x0c\
-    \n\x05\x04\x05\x02\0\x03\x12\x03!\x13\x14\n\x0b\n\x04\x04\x05\x02\x01\
-    \x12\x03\"\x04\x1a\n\r\n\x05\x04\x05\x02\x01\x04\x12\x04\"\x04!\x15\n\
-    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x03\"\x04\x0c\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03\"\r\x15\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\"\x18\
-    \x19\n\n\n\x02\x04\x06\x12\x04%\0+\x01\n\n\n\x03\x04\x06\x01\x12\x03%\
-    \x08\x0c\n\x0b\n\x04\x04\x06\x02\0\x12\x03&\x04\x17\n\r\n\x05\x04\x06\
-    \x02\0\x04\x12\x04&\x04%\x0e\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03&\x04\
-    \n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03&\x0b\x12\n\x0c\n\x05\x04\x06\
-    \x02\0\x03\x12\x03&\x15\x16\n\x0c\n\x04\x04\x06\x08\0\x12\x04'\x04*\x05\
-    \n\x0c\n\x05\x04\x06\x08\0\x01\x12\x03'\n\x0e\n\x0b\n\x04\x04\x06\x02\
-    \x01\x12\x03(\x08\x16\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03(\x08\x0c\n\
-    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03(\r\x11\n\x0c\n\x05\x04\x06\x02\
-    \x01\x03\x12\x03(\x14\x15\n\x0b\n\x04\x04\x06\x02\x02\x12\x03)\x08\x18\n\
-    \x0c\n\x05\x04\x06\x02\x02\x06\x12\x03)\x08\r\n\x0c\n\x05\x04\x06\x02\
-    \x02\x01\x12\x03)\x0e\x13\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03)\x16\
-    \x17\n\n\n\x02\x04\x07\x12\x04-\00\x01\n\n\n\x03\x04\x07\x01\x12\x03-\
-    \x08\x10\n\x0b\n\x04\x04\x07\x02\0\x12\x03.\x04\x18\n\r\n\x05\x04\x07\
-    \x02\0\x04\x12\x04.\x04-\x12\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03.\x04\
-    \n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03.\x0b\x13\n\x0c\n\x05\x04\x07\
-    \x02\0\x03\x12\x03.\x16\x17\n\x0b\n\x04\x04\x07\x02\x01\x12\x03/\x04\x17\
-    \n\r\n\x05\x04\x07\x02\x01\x04\x12\x04/\x04.\x18\n\x0c\n\x05\x04\x07\x02\
-    \x01\x05\x12\x03/\x04\n\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x03/\x0b\x12\
-    \n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03/\x15\x16\n\n\n\x02\x04\x08\x12\
-    \x042\05\x01\n\n\n\x03\x04\x08\x01\x12\x032\x08\x0c\n\x0b\n\x04\x04\x08\
-    \x02\0\x12\x033\x04\x1f\n\r\n\x05\x04\x08\x02\0\x04\x12\x043\x042\x0e\n\
-    \x0c\n\x05\x04\x08\x02\0\x06\x12\x033\x04\x0b\n\x0c\n\x05\x04\x08\x02\0\
-    \x01\x12\x033\x0c\x1a\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x033\x1d\x1e\n\
-    \x0b\n\x04\x04\x08\x02\x01\x12\x034\x04/\n\x0c\n\x05\x04\x08\x02\x01\x04\
-    \x12\x034\x04\x0c\n\x0c\n\x05\x04\x08\x02\x01\x06\x12\x034\r\x14\n\x0c\n\
-    \x05\x04\x08\x02\x01\x01\x12\x034\x15*\n\x0c\n\x05\x04\x08\x02\x01\x03\
-    \x12\x034-.\n\n\n\x02\x04\t\x12\x048\0=\x01\n\n\n\x03\x04\t\x01\x12\x038\
-    \x08\x0f\n\x0c\n\x04\x04\t\x08\0\x12\x049\x04<\x05\n\x0c\n\x05\x04\t\x08\
-    \0\x01\x12\x039\n\x0f\n\x0b\n\x04\x04\t\x02\0\x12\x03:\x08\x16\n\x0c\n\
-    \x05\x04\t\x02\0\x05\x12\x03:\x08\x0e\n\x0c\n\x05\x04\t\x02\0\x01\x12\
-    \x03:\x0f\x11\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03:\x14\x15\n\x0b\n\x04\
-    \x04\t\x02\x01\x12\x03;\x08\x15\n\x0c\n\x05\x04\t\x02\x01\x05\x12\x03;\
-    \x08\r\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03;\x0e\x10\n\x0c\n\x05\x04\t\
-    \x02\x01\x03\x12\x03;\x13\x14\n\n\n\x02\x04\n\x12\x04?\0D\x01\n\n\n\x03\
-    \x04\n\x01\x12\x03?\x08\x12\n\x0b\n\x04\x04\n\x02\0\x12\x03@\x04\x17\n\r\
-    \n\x05\x04\n\x02\0\x04\x12\x04@\x04?\x14\n\x0c\n\x05\x04\n\x02\0\x05\x12\
-    \x03@\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03@\x0b\x12\n\x0c\n\x05\x04\
-    \n\x02\0\x03\x12\x03@\x15\x16\n\x0b\n\x04\x04\n\x02\x01\x12\x03A\x04\x16\
-    \n\r\n\x05\x04\n\x02\x01\x04\x12\x04A\x04@\x17\n\x0c\n\x05\x04\n\x02\x01\
-    \x06\x12\x03A\x04\n\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03A\x0b\x11\n\x0c\
-    \n\x05\x04\n\x02\x01\x03\x12\x03A\x14\x15\n\x0b\n\x04\x04\n\x02\x02\x12\
-    \x03B\x04$\n\x0c\n\x05\x04\n\x02\x02\x04\x12\x03B\x04\x0c\n\x0c\n\x05\
-    \x04\n\x02\x02\x06\x12\x03B\r\x13\n\x0c\n\x05\x04\n\x02\x02\x01\x12\x03B\
-    \x14\x1f\n\x0c\n\x05\x04\n\x02\x02\x03\x12\x03B\"#\n\x0b\n\x04\x04\n\x02\
-    \x03\x12\x03C\x04\x19\n\r\n\x05\x04\n\x02\x03\x04\x12\x04C\x04B$\n\x0c\n\
-    \x05\x04\n\x02\x03\x05\x12\x03C\x04\x08\n\x0c\n\x05\x04\n\x02\x03\x01\
-    \x12\x03C\t\x14\n\x0c\n\x05\x04\n\x02\x03\x03\x12\x03C\x17\x18\n\n\n\x02\
-    \x04\x0b\x12\x04F\0J\x01\n\n\n\x03\x04\x0b\x01\x12\x03F\x08\x0e\n\x0c\n\
-    \x04\x04\x0b\x08\0\x12\x04G\x04I\x05\n\x0c\n\x05\x04\x0b\x08\0\x01\x12\
-    \x03G\n\x0f\n\x0b\n\x04\x04\x0b\x02\0\x12\x03H\x08\x1c\n\x0c\n\x05\x04\
-    \x0b\x02\0\x06\x12\x03H\x08\x12\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03H\
-    \x13\x17\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03H\x1a\x1b\n\n\n\x02\x04\
-    \x0c\x12\x04L\0R\x01\n\n\n\x03\x04\x0c\x01\x12\x03L\x08\x12\n\x0b\n\x04\
-    \x04\x0c\x02\0\x12\x03M\x04\x1f\n\r\n\x05\x04\x0c\x02\0\x04\x12\x04M\x04\
-    L\x14\n\x0c\n\x05\x04\x0c\x02\0\x06\x12\x03M\x04\x0b\n\x0c\n\x05\x04\x0c\
-    \x02\0\x01\x12\x03M\x0c\x1a\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03M\x1d\
-    \x1e\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03N\x04$\n\r\n\x05\x04\x0c\x02\x01\
-    \x04\x12\x04N\x04M\x1f\n\x0c\n\x05\x04\x0c\x02\x01\x06\x12\x03N\x04\x0b\
-    \n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03N\x0c\x1f\n\x0c\n\x05\x04\x0c\
-    \x02\x01\x03\x12\x03N\"#\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03O\x04\x1c\n\
-    \r\n\x05\x04\x0c\x02\x02\x04\x12\x04O\x04N$\n\x0c\n\x05\x04\x0c\x02\x02\
-    \x05\x12\x03O\x04\n\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03O\x0b\x17\n\
-    \x0c\n\x05\x04\x0c\x02\x02\x03\x12\x03O\x1a\x1b\n\x0b\n\x04\x04\x0c\x02\
-    \x03\x12\x03P\x04\x1c\n\r\n\x05\x04\x0c\x02\x03\x04\x12\x04P\x04O\x1c\n\
-    \x0c\n\x05\x04\x0c\x02\x03\x06\x12\x03P\x04\x0f\n\x0c\n\x05\x04\x0c\x02\
-    \x03\x01\x12\x03P\x10\x17\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03P\x1a\
-    \x1b\n\x0b\n\x04\x04\x0c\x02\x04\x12\x03Q\x04\x13\n\r\n\x05\x04\x0c\x02\
-    \x04\x04\x12\x04Q\x04P\x1c\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03Q\x04\
-    \n\n\x0c\n\x05\x04\x0c\x02\x04\x01\x12\x03Q\x0b\x0e\n\x0c\n\x05\x04\x0c\
-    \x02\x04\x03\x12\x03Q\x11\x12\n\n\n\x02\x04\r\x12\x04T\0Y\x01\n\n\n\x03\
-    \x04\r\x01\x12\x03T\x08\x13\n\x0b\n\x04\x04\r\x02\0\x12\x03U\x04\x17\n\r\
-    \n\x05\x04\r\x02\0\x04\x12\x04U\x04T\x15\n\x0c\n\x05\x04\r\x02\0\x05\x12\
-    \x03U\x04\n\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03U\x0b\x12\n\x0c\n\x05\x04\
-    \r\x02\0\x03\x12\x03U\x15\x16\n\x0b\n\x04\x04\r\x02\x01\x12\x03V\x04\x1d\
-    \n\r\n\x05\x04\r\x02\x01\x04\x12\x04V\x04U\x17\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03V\x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03V\x0b\x18\n\x0c\
-    \n\x05\x04\r\x02\x01\x03\x12\x03V\x1b\x1c\n\x0b\n\x04\x04\r\x02\x02\x12\
-    \x03W\x04\x1f\n\r\n\x05\x04\r\x02\x02\x04\x12\x04W\x04V\x1d\n\x0c\n\x05\
-    \x04\r\x02\x02\x06\x12\x03W\x04\x0b\n\x0c\n\x05\x04\r\x02\x02\x01\x12\
-    \x03W\x0c\x1a\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03W\x1d\x1e\n\x0b\n\x04\
-    \x04\r\x02\x03\x12\x03X\x04$\n\r\n\x05\x04\r\x02\x03\x04\x12\x04X\x04W\
-    \x1f\n\x0c\n\x05\x04\r\x02\x03\x06\x12\x03X\x04\x0b\n\x0c\n\x05\x04\r\
-    \x02\x03\x01\x12\x03X\x0c\x1f\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03X\"#b\
-    \x06proto3\
+    askId\"@\n\rResultRequest\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06ta\
+    skId\x12\x16\n\x06offset\x18\x02\x20\x01(\rR\x06offset\"\xfc\x01\n\x0cSc\
+    heduleTask\x12\x1f\n\x06client\x18\x01\x20\x01(\x0b2\x07.ClientR\x06clie\
+    nt\x12\x1b\n\x04ping\x18\x02\x20\x01(\x0b2\x05.PingH\0R\x04ping\x12\"\n\
+    \x07tcp_syn\x18\x03\x20\x01(\x0b2\x07.TcpSynH\0R\x06tcpSyn\x12\x18\n\x03\
+    dns\x18\x04\x20\x01(\x0b2\x04.DnsH\0R\x03dns\x12\x12\n\x04seed\x18\x05\
+    \x20\x01(\x04R\x04seed\x121\n\x0cdestinations\x18\x06\x20\x01(\x0b2\r.De\
+    stinationsR\x0cdestinations\x12!\n\x0cgrace_period\x18\x07\x20\x01(\rR\
+    \x0bgracePeriodB\x06\n\x04data\"\xd0\x01\n\x10ScheduleCampaign\x12\x1f\n\
+    \x06client\x18\x01\x20\x01(\x0b2\x07.ClientR\x06client\x12\x19\n\x04ping\
+    \x18\x02\x20\x01(\x0b2\x05.PingR\x04ping\x12\x16\n\x06rotate\x18\x03\x20\
+    \x01(\x08R\x06rotate\x12\x12\n\x04seed\x18\x04\x20\x01(\x04R\x04seed\x12\
+    1\n\x0cdestinations\x18\x05\x20\x01(\x0b2\r.DestinationsR\x0cdestination\
+    s\x12!\n\x0cgrace_period\x18\x06\x20\x01(\rR\x0bgracePeriod\"\x90\x02\n\
+    \x08Schedule\x12\x1f\n\x0bschedule_id\x18\x01\x20\x01(\rR\nscheduleId\
+    \x12!\n\x04task\x18\x02\x20\x01(\x0b2\r.ScheduleTaskR\x04task\x12\x12\n\
+    \x04cron\x18\x03\x20\x01(\tR\x04cron\x12\x1a\n\x08interval\x18\x04\x20\
+    \x01(\x04R\x08interval\x12\x19\n\x08start_at\x18\x05\x20\x01(\x04R\x07st\
+    artAt\x12\x19\n\x08next_run\x18\x06\x20\x01(\x04R\x07nextRun\x12\x14\n\
+    \x05owner\x18\x07\x20\x01(\tR\x05owner\x12\x20\n\x04runs\x18\x08\x20\x03\
+    (\x0b2\x0c.ScheduleRunR\x04runs\x12\"\n\x0cdestinations\x18\t\x20\x01(\r\
+    R\x0cdestinations\"j\n\x0bScheduleRun\x12\x17\n\x07task_id\x18\x01\x20\
+    \x01(\rR\x06taskId\x12\x1d\n\nstart_time\x18\x02\x20\x01(\x04R\tstartTim\
+    e\x12#\n\rerror_message\x18\x03\x20\x01(\tR\x0cerrorMessage\"7\n\x0cSche\
+    duleList\x12'\n\tschedules\x18\x01\x20\x03(\x0b2\t.ScheduleR\tschedules\
+    \"-\n\nScheduleId\x12\x1f\n\x0bschedule_id\x18\x01\x20\x01(\rR\nschedule\
+    Id\"/\n\nClientList\x12!\n\x07clients\x18\x01\x20\x03(\x0b2\x07.ClientR\
+    \x07clients\"i\n\x06Client\x12\x14\n\x05index\x18\x01\x20\x01(\rR\x05ind\
+    ex\x12%\n\x08metadata\x18\x02\x20\x01(\x0b2\t.MetadataR\x08metadata\x12\
+    \"\n\x05clock\x18\x03\x20\x01(\x0b2\x0c.ClockOffsetR\x05clock\"\xf6\x02\
+    \n\x04Task\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06taskId\x12\x1b\n\
+    \x04ping\x18\x02\x20\x01(\x0b2\x05.PingH\0R\x04ping\x12\x1e\n\x05empty\
+    \x18\x03\x20\x01(\x0b2\x06.EmptyH\0R\x05empty\x12\x1e\n\x04keys\x18\x04\
+    \x20\x01(\x0b2\x08.KeyListH\0R\x04keys\x12\x20\n\x06cancel\x18\x05\x20\
+    \x01(\x0b2\x06.EmptyH\0R\x06cancel\x12\"\n\x07tcp_syn\x18\x06\x20\x01(\
+    \x0b2\x07.TcpSynH\0R\x06tcpSyn\x12\x18\n\x03dns\x18\x07\x20\x01(\x0b2\
+    \x04.DnsH\0R\x03dns\x123\n\x0cdestinations\x18\t\x20\x01(\x0b2\r.Destina\
+    tionsH\0R\x0cdestinations\x12$\n\x08shutdown\x18\n\x20\x01(\x0b2\x06.Emp\
+    tyH\0R\x08shutdown\x12\x12\n\x04seed\x18\x08\x20\x01(\x04R\x04seed\x12!\
+    \n\x0cgrace_period\x18\x0b\x20\x01(\rR\x0bgracePeriodB\x06\n\x04data\"\
+    \xab\x02\n\nTaskStatus\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06taskI\
+    d\x12\x1f\n\x0bcampaign_id\x18\x02\x20\x01(\rR\ncampaignId\x12\x1f\n\x06\
+    client\x18\x03\x20\x01(\x0b2\x07.ClientR\x06client\x12\x14\n\x05state\
+    \x18\x04\x20\x01(\tR\x05state\x12\"\n\x0cdestinations\x18\x05\x20\x01(\r\
+    R\x0cdestinations\x12+\n\x11destinations_sent\x18\x06\x20\x01(\rR\x10des\
+    tinationsSent\x12\x1d\n\nstart_time\x18\x07\x20\x01(\x04R\tstartTime\x12\
+    \x12\n\x04rate\x18\x08\x20\x01(\rR\x04rate\x12\x12\n\x04seed\x18\t\x20\
+    \x01(\x04R\x04seed\x12\x14\n\x05owner\x18\n\x20\x01(\tR\x05owner\"3\n\
+    \x0eTaskStatusList\x12!\n\x05tasks\x18\x01\x20\x03(\x0b2\x0b.TaskStatusR\
+    \x05tasks\"h\n\x0cTaskProgress\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\
+    \x06taskId\x12+\n\x11destinations_sent\x18\x02\x20\x01(\rR\x10destinatio\
+    nsSent\x12\x12\n\x04rate\x18\x03\x20\x01(\rR\x04rate\"\xb8\x01\n\x08Meta\
+    data\x12\x1a\n\x08hostname\x18\x01\x20\x01(\tR\x08hostname\x12\x18\n\x07\
+    version\x18\x02\x20\x01(\tR\x07version\x12\x1b\n\tclient_id\x18\x03\x20\
+    \x01(\tR\x08clientId\x12\x12\n\x04site\x18\x04\x20\x01(\tR\x04site\x12\
+    \x1a\n\x08location\x18\x05\x20\x01(\tR\x08location\x12)\n\x10announced_p\
+    refix\x18\x06\x20\x01(\tR\x0fannouncedPrefix\"c\n\x04Ping\x12/\n\x0esour\
+    ce_address\x18\x01\x20\x01(\x0b2\x08.AddressR\rsourceAddress\x12\x16\n\
+    \x03key\x18\x03\x20\x01(\x0b2\x04.KeyR\x03key\x12\x12\n\x04rate\x18\x04\
+    \x20\x01(\rR\x04rate\"\x90\x01\n\x06TcpSyn\x12/\n\x0esource_address\x18\
+    \x01\x20\x01(\x0b2\x08.AddressR\rsourceAddress\x12\x16\n\x03key\x18\x03\
+    \x20\x01(\x0b2\x04.KeyR\x03key\x12\x12\n\x04rate\x18\x04\x20\x01(\rR\x04\
+    rate\x12)\n\x10destination_port\x18\x05\x20\x01(\rR\x0fdestinationPort\"\
+    \x85\x01\n\x03Dns\x12/\n\x0esource_address\x18\x01\x20\x01(\x0b2\x08.Add\
+    ressR\rsourceAddress\x12\x16\n\x03key\x18\x03\x20\x01(\x0b2\x04.KeyR\x03\
+    key\x12\x12\n\x04rate\x18\x04\x20\x01(\rR\x04rate\x12!\n\x0cquery_domain\
+    \x18\x05\x20\x01(\tR\x0bqueryDomain\"J\n\x0cDestinations\x12&\n\taddress\
+    es\x18\x01\x20\x03(\x0b2\x08.AddressR\taddresses\x12\x12\n\x04last\x18\
+    \x02\x20\x01(\x08R\x04last\"4\n\x03Key\x12\x15\n\x06key_id\x18\x01\x20\
+    \x01(\rR\x05keyId\x12\x16\n\x06secret\x18\x02\x20\x01(\tR\x06secret\"#\n\
+    \x07KeyList\x12\x18\n\x04keys\x18\x01\x20\x03(\x0b2\x04.KeyR\x04keys\"6\
+    \n\x07Address\x12\x10\n\x02v4\x18\x01\x20\x01(\rH\0R\x02v4\x12\x10\n\x02\
+    v6\x18\x02\x20\x01(\x0cH\0R\x02v6B\x07\n\x05value\"\xf3\x02\n\nTaskResul\
+    t\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06taskId\x12\x1f\n\x06client\
+    \x18\x02\x20\x01(\x0b2\x07.ClientR\x06client\x12(\n\x0bresult_list\x18\
+    \x03\x20\x03(\x0b2\x07.ResultR\nresultList\x12\x1f\n\x0bis_finished\x18\
+    \x04\x20\x01(\x08R\nisFinished\x12\x1f\n\x0bcampaign_id\x18\x05\x20\x01(\
+    \rR\ncampaignId\x129\n\x11transmitter_clock\x18\x06\x20\x01(\x0b2\x0c.Cl\
+    ockOffsetR\x10transmitterClock\x123\n\x0ereceiver_clock\x18\x07\x20\x01(\
+    \x0b2\x0c.ClockOffsetR\rreceiverClock\x12'\n\x0finvalid_replies\x18\x08\
+    \x20\x01(\rR\x0einvalidReplies\x12&\n\x07summary\x18\t\x20\x01(\x0b2\x0c\
+    .TaskSummaryR\x07summary\"\x88\x01\n\x0bTaskSummary\x12\x1f\n\x0bprobes_\
+    sent\x18\x01\x20\x01(\rR\nprobesSent\x12/\n\x0csite_replies\x18\x02\x20\
+    \x03(\x0b2\x0c.SiteRepliesR\x0bsiteReplies\x12'\n\x0finvalid_replies\x18\
+    \x03\x20\x01(\rR\x0einvalidReplies\";\n\x0bSiteReplies\x12\x12\n\x04site\
+    \x18\x01\x20\x01(\tR\x04site\x12\x18\n\x07replies\x18\x02\x20\x01(\rR\
+    \x07replies\"\x9f\x01\n\tClockSync\x120\n\x14client_transmit_time\x18\
+    \x01\x20\x01(\x04R\x12clientTransmitTime\x12.\n\x13server_receive_time\
+    \x18\x02\x20\x01(\x04R\x11serverReceiveTime\x120\n\x14server_transmit_ti\
+    me\x18\x03\x20\x01(\x04R\x12serverTransmitTime\"X\n\x0bClockOffset\x12\
+    \x1b\n\tclient_id\x18\x01\x20\x01(\tR\x08clientId\x12\x16\n\x06offset\
+    \x18\x02\x20\x01(\x03R\x06offset\x12\x14\n\x05delay\x18\x03\x20\x01(\x04\
+    R\x05delay\"t\n\x06Result\x12!\n\x04ping\x18\x01\x20\x01(\x0b2\x0b.PingR\
+    esultH\0R\x04ping\x12\x1e\n\x03tcp\x18\x02\x20\x01(\x0b2\n.TcpResultH\0R\
+    \x03tcp\x12\x1e\n\x03dns\x18\x03\x20\x01(\x0b2\n.DnsResultH\0R\x03dnsB\
+    \x07\n\x05value\"\xd5\x01\n\nPingResult\x12/\n\x0esource_address\x18\x01\
+    \x20\x01(\x0b2\x08.AddressR\rsourceAddress\x129\n\x13destination_address\
+    \x18\x02\x20\x01(\x0b2\x08.AddressR\x12destinationAddress\x12!\n\x0crece\
+    ive_time\x18\x03\x20\x01(\x04R\x0breceiveTime\x12&\n\x07payload\x18\x04\
+    \x20\x01(\x0b2\x0c.PingPayloadR\x07payload\x12\x10\n\x03ttl\x18\x05\x20\
+    \x01(\rR\x03ttl\"\xa1\x02\n\tTcpResult\x12/\n\x0esource_address\x18\x01\
+    \x20\x01(\x0b2\x08.AddressR\rsourceAddress\x129\n\x13destination_address\
+    \x18\x02\x20\x01(\x0b2\x08.AddressR\x12destinationAddress\x12!\n\x0crece\
+    ive_time\x18\x03\x20\x01(\x04R\x0breceiveTime\x12\x10\n\x03ttl\x18\x04\
+    \x20\x01(\rR\x03ttl\x12\x17\n\x07task_id\x18\x05\x20\x01(\rR\x06taskId\
+    \x12#\n\rtransmit_time\x18\x06\x20\x01(\x04R\x0ctransmitTime\x12\x1f\n\
+    \x0bsource_port\x18\x07\x20\x01(\rR\nsourcePort\x12\x14\n\x05flags\x18\
+    \x08\x20\x01(\rR\x05flags\"\xea\x01\n\tDnsResult\x12/\n\x0esource_addres\
+    s\x18\x01\x20\x01(\x0b2\x08.AddressR\rsourceAddress\x129\n\x13destinatio\
+    n_address\x18\x02\x20\x01(\x0b2\x08.AddressR\x12destinationAddress\x12!\
+    \n\x0creceive_time\x18\x03\x20\x01(\x04R\x0breceiveTime\x12&\n\x07payloa\
+    d\x18\x04\x20\x01(\x0b2\x0c.PingPayloadR\x07payload\x12\x10\n\x03ttl\x18\
+    \x05\x20\x01(\rR\x03ttl\x12\x14\n\x05rcode\x18\x06\x20\x01(\rR\x05rcode\
+    \"\xce\x01\n\x0bPingPayload\x12\x17\n\x07task_id\x18\x01\x20\x01(\rR\x06\
+    taskId\x12#\n\rtransmit_time\x18\x02\x20\x01(\x04R\x0ctransmitTime\x12/\
+    \n\x0esource_address\x18\x03\x20\x01(\x0b2\x08.AddressR\rsourceAddress\
+    \x129\n\x13destination_address\x18\x04\x20\x01(\x0b2\x08.AddressR\x12des\
+    tinationAddress\x12\x15\n\x06key_id\x18\x05\x20\x01(\rR\x05keyId2\xb2\
+    \x05\n\x0bVerfploeter\x12\x1f\n\x07connect\x12\t.Metadata\x1a\x05.Task\"\
+    \00\x01\x12\"\n\x07do_task\x12\r.ScheduleTask\x1a\x04.Ack\"\0(\x01\x12*\
+    \n\x0bdo_campaign\x12\x11.ScheduleCampaign\x1a\x04.Ack\"\0(\x01\x12%\n\
+    \x0clist_clients\x12\x06.Empty\x1a\x0b.ClientList\"\0\x12\"\n\x0bsend_re\
+    sult\x12\x0b.TaskResult\x1a\x04.Ack\"\0\x12,\n\x10subscribe_result\x12\
+    \x07.TaskId\x1a\x0b.TaskResult\"\00\x01\x12\x20\n\rtask_finished\x12\x07\
+    .TaskId\x1a\x04.Ack\"\0\x12.\n\x0bget_results\x12\x0e.ResultRequest\x1a\
+    \x0b.TaskResult\"\00\x01\x12'\n\nlist_tasks\x12\x06.Empty\x1a\x0f.TaskSt\
+    atusList\"\0\x12)\n\x0fget_task_status\x12\x07.TaskId\x1a\x0b.TaskStatus\
+    \"\0\x12\x1e\n\x0bcancel_task\x12\x07.TaskId\x1a\x04.Ack\"\0\x12&\n\rtas\
+    k_progress\x12\r.TaskProgress\x1a\x04.Ack\"\0\x12&\n\nsync_clock\x12\n.C\
+    lockSync\x1a\n.ClockSync\"\0\x12+\n\x13report_clock_offset\x12\x0c.Clock\
+    Offset\x1a\x04.Ack\"\0\x12#\n\x0cadd_schedule\x12\t.Schedule\x1a\x04.Ack\
+    \"\0(\x01\x12)\n\x0elist_schedules\x12\x06.Empty\x1a\r.ScheduleList\"\0\
+    \x12&\n\x0fremove_schedule\x12\x0b.ScheduleId\x1a\x04.Ack\"\0J\x92\\\n\
+    \x07\x12\x05\0\0\x87\x02\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
+    \x06\0\x12\x04\x02\0\x14\x01\n\n\n\x03\x06\0\x01\x12\x03\x02\x08\x13\n\
+    \x0b\n\x04\x06\0\x02\0\x12\x03\x03\x042\n\x0c\n\x05\x06\0\x02\0\x01\x12\
+    \x03\x03\x08\x0f\n\x0c\n\x05\x06\0\x02\0\x02\x12\x03\x03\x10\x18\n\x0c\n\
+    \x05\x06\0\x02\0\x06\x12\x03\x03#)\n\x0c\n\x05\x06\0\x02\0\x03\x12\x03\
+    \x03*.\n\x0b\n\x04\x06\0\x02\x01\x12\x03\x04\x045\n\x0c\n\x05\x06\0\x02\
+    \x01\x01\x12\x03\x04\x08\x0f\n\x0c\n\x05\x06\0\x02\x01\x05\x12\x03\x04\
+    \x10\x16\n\x0c\n\x05\x06\0\x02\x01\x02\x12\x03\x04\x17#\n\x0c\n\x05\x06\
+    \0\x02\x01\x03\x12\x03\x04.1\n\x0b\n\x04\x06\0\x02\x02\x12\x03\x05\x04=\
+    \n\x0c\n\x05\x06\0\x02\x02\x01\x12\x03\x05\x08\x13\n\x0c\n\x05\x06\0\x02\
+    \x02\x05\x12\x03\x05\x14\x1a\n\x0c\n\x05\x06\0\x02\x02\x02\x12\x03\x05\
+    \x1b+\n\x0c\n\x05\x06\0\x02\x02\x03\x12\x03\x0569\n\x0b\n\x04\x06\0\x02\
+    \x03\x12\x03\x06\x043\n\x0c\n\x05\x06\0\x02\x03\x01\x12\x03\x06\x08\x14\
+    \n\x0c\n\x05\x06\0\x02\x03\x02\x12\x03\x06\x15\x1a\n\x0c\n\x05\x06\0\x02\
+    \x03\x03\x12\x03\x06%/\n\x0b\n\x04\x06\0\x02\x04\x12\x03\x07\x040\n\x0c\
+    \n\x05\x06\0\x02\x04\x01\x12\x03\x07\x08\x13\n\x0c\n\x05\x06\0\x02\x04\
+    \x02\x12\x03\x07\x14\x1e\n\x0c\n\x05\x06\0\x02\x04\x03\x12\x03\x07),\n\
+    \x0b\n\x04\x06\0\x02\x05\x12\x03\x08\x04?\n\x0c\n\x05\x06\0\x02\x05\x01\
+    \x12\x03\x08\x08\x18\n\x0c\n\x05\x06\0\x02\x05\x02\x12\x03\x08\x19\x1f\n\
+    \x0c\n\x05\x06\0\x02\x05\x06\x12\x03\x08*0\n\x0c\n\x05\x06\0\x02\x05\x03\
+    \x12\x03\x081;\n\x0b\n\x04\x06\0\x02\x06\x12\x03\t\x04.\n\x0c\n\x05\x06\
+    \0\x02\x06\x01\x12\x03\t\x08\x15\n\x0c\n\x05\x06\0\x02\x06\x02\x12\x03\t\
+    \x16\x1c\n\x0c\n\x05\x06\0\x02\x06\x03\x12\x03\t'*\n\x0b\n\x04\x06\0\x02\
+    \x07\x12\x03\n\x04A\n\x0c\n\x05\x06\0\x02\x07\x01\x12\x03\n\x08\x13\n\
+    \x0c\n\x05\x06\0\x02\x07\x02\x12\x03\n\x14!\n\x0c\n\x05\x06\0\x02\x07\
+    \x06\x12\x03\n,2\n\x0c\n\x05\x06\0\x02\x07\x03\x12\x03\n3=\n\x0b\n\x04\
+    \x06\0\x02\x08\x12\x03\x0b\x045\n\x0c\n\x05\x06\0\x02\x08\x01\x12\x03\
+    \x0b\x08\x12\n\x0c\n\x05\x06\0\x02\x08\x02\x12\x03\x0b\x13\x18\n\x0c\n\
+    \x05\x06\0\x02\x08\x03\x12\x03\x0b#1\n\x0b\n\x04\x06\0\x02\t\x12\x03\x0c\
+    \x047\n\x0c\n\x05\x06\0\x02\t\x01\x12\x03\x0c\x08\x17\n\x0c\n\x05\x06\0\
+    \x02\t\x02\x12\x03\x0c\x18\x1e\n\x0c\n\x05\x06\0\x02\t\x03\x12\x03\x0c)3\
+    \n\x0b\n\x04\x06\0\x02\n\x12\x03\r\x04,\n\x0c\n\x05\x06\0\x02\n\x01\x12\
+    \x03\r\x08\x13\n\x0c\n\x05\x06\0\x02\n\x02\x12\x03\r\x14\x1a\n\x0c\n\x05\
+    \x06\0\x02\n\x03\x12\x03\r%(\n\x0b\n\x04\x06\0\x02\x0b\x12\x03\x0e\x044\
+    \n\x0c\n\x05\x06\0\x02\x0b\x01\x12\x03\x0e\x08\x15\n\x0c\n\x05\x06\0\x02\
+    \x0b\x02\x12\x03\x0e\x16\"\n\x0c\n\x05\x06\0\x02\x0b\x03\x12\x03\x0e-0\n\
+    \x0b\n\x04\x06\0\x02\x0c\x12\x03\x0f\x044\n\x0c\n\x05\x06\0\x02\x0c\x01\
+    \x12\x03\x0f\x08\x12\n\x0c\n\x05\x06\0\x02\x0c\x02\x12\x03\x0f\x13\x1c\n\
+    \x0c\n\x05\x06\0\x02\x0c\x03\x12\x03\x0f'0\n\x0b\n\x04\x06\0\x02\r\x12\
+    \x03\x10\x049\n\x0c\n\x05\x06\0\x02\r\x01\x12\x03\x10\x08\x1b\n\x0c\n\
+    \x05\x06\0\x02\r\x02\x12\x03\x10\x1c'\n\x0c\n\x05\x06\0\x02\r\x03\x12\
+    \x03\x1025\n\x0b\n\x04\x06\0\x02\x0e\x12\x03\x11\x046\n\x0c\n\x05\x06\0\
+    \x02\x0e\x01\x12\x03\x11\x08\x14\n\x0c\n\x05\x06\0\x02\x0e\x05\x12\x03\
+    \x11\x15\x1b\n\x0c\n\x05\x06\0\x02\x0e\x02\x12\x03\x11\x1c$\n\x0c\n\x05\
+    \x06\0\x02\x0e\x03\x12\x03\x11/2\n\x0b\n\x04\x06\0\x02\x0f\x12\x03\x12\
+    \x047\n\x0c\n\x05\x06\0\x02\x0f\x01\x12\x03\x12\x08\x16\n\x0c\n\x05\x06\
+    \0\x02\x0f\x02\x12\x03\x12\x17\x1c\n\x0c\n\x05\x06\0\x02\x0f\x03\x12\x03\
+    \x12'3\n\x0b\n\x04\x06\0\x02\x10\x12\x03\x13\x044\n\x0c\n\x05\x06\0\x02\
+    \x10\x01\x12\x03\x13\x08\x17\n\x0c\n\x05\x06\0\x02\x10\x02\x12\x03\x13\
+    \x18\"\n\x0c\n\x05\x06\0\x02\x10\x03\x12\x03\x13-0\n\t\n\x02\x04\0\x12\
+    \x03\x16\0\x10\n\n\n\x03\x04\0\x01\x12\x03\x16\x08\r\n\n\n\x02\x04\x01\
+    \x12\x04\x18\0\x1c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x18\x08\x0b\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\x19\x04\x17\n\r\n\x05\x04\x01\x02\0\x04\x12\
+    \x04\x19\x04\x18\r\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x19\x04\n\n\x0c\
+    \n\x05\x04\x01\x02\0\x01\x12\x03\x19\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\
+    \x03\x12\x03\x19\x15\x16\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x1a\x04\x15\
+    \n\r\n\x05\x04\x01\x02\x01\x04\x12\x04\x1a\x04\x19\x17\n\x0c\n\x05\x04\
+    \x01\x02\x01\x05\x12\x03\x1a\x04\x08\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03\x1a\t\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x1a\x13\x14\n\x0b\
+    \n\x04\x04\x01\x02\x02\x12\x03\x1b\x04\x1d\n\r\n\x05\x04\x01\x02\x02\x04\
+    \x12\x04\x1b\x04\x1a\x15\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x1b\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x1b\x0b\x18\n\x0c\n\x05\x04\
+    \x01\x02\x02\x03\x12\x03\x1b\x1b\x1c\n\t\n\x02\x04\x02\x12\x03\x1e\0&\n\
+    \n\n\x03\x04\x02\x01\x12\x03\x1e\x08\x0e\n\x0b\n\x04\x04\x02\x02\0\x12\
+    \x03\x1e\x11$\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03\x1e\x11\x10\n\x0c\n\
+    \x05\x04\x02\x02\0\x05\x12\x03\x1e\x11\x17\n\x0c\n\x05\x04\x02\x02\0\x01\
+    \x12\x03\x1e\x18\x1f\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x1e\"#\n\n\n\
+    \x02\x04\x03\x12\x04\x20\0#\x01\n\n\n\x03\x04\x03\x01\x12\x03\x20\x08\
+    \x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03!\x04\x17\n\r\n\x05\x04\x03\x02\0\
+    \x04\x12\x04!\x04\x20\x17\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03!\x04\n\n\
+    \x0c\n\x05\x04\x03\x02\0\x01\x12\x03!\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\
+    \x03\x12\x03!\x15\x16\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\"\x04\x16\n\r\
+    \n\x05\x04\x03\x02\x01\x04\x12\x04\"\x04!\x17\n\x0c\n\x05\x04\x03\x02\
+    \x01\x05\x12\x03\"\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\"\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\"\x14\x15\n\n\n\x02\x04\x04\
+    \x12\x04%\0/\x01\n\n\n\x03\x04\x04\x01\x12\x03%\x08\x14\n\x0b\n\x04\x04\
+    \x04\x02\0\x12\x03&\x04\x16\n\r\n\x05\x04\x04\x02\0\x04\x12\x04&\x04%\
+    \x16\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03&\x04\n\n\x0c\n\x05\x04\x04\
+    \x02\0\x01\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03&\x14\
+    \x15\n\x0c\n\x04\x04\x04\x08\0\x12\x04'\x04+\x05\n\x0c\n\x05\x04\x04\x08\
+    \0\x01\x12\x03'\n\x0e\n\x0b\n\x04\x04\x04\x02\x01\x12\x03(\x08\x16\n\x0c\
+    \n\x05\x04\x04\x02\x01\x06\x12\x03(\x08\x0c\n\x0c\n\x05\x04\x04\x02\x01\
+    \x01\x12\x03(\r\x11\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03(\x14\x15\n\
+    \x0b\n\x04\x04\x04\x02\x02\x12\x03)\x08\x1b\n\x0c\n\x05\x04\x04\x02\x02\
+    \x06\x12\x03)\x08\x0e\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03)\x0f\x16\n\
+    \x0c\n\x05\x04\x04\x02\x02\x03\x12\x03)\x19\x1a\n\x0b\n\x04\x04\x04\x02\
+    \x03\x12\x03*\x08\x14\n\x0c\n\x05\x04\x04\x02\x03\x06\x12\x03*\x08\x0b\n\
+    \x0c\n\x05\x04\x04\x02\x03\x01\x12\x03*\x0c\x0f\n\x0c\n\x05\x04\x04\x02\
+    \x03\x03\x12\x03*\x12\x13\n\x0b\n\x04\x04\x04\x02\x04\x12\x03,\x04\x14\n\
+    \r\n\x05\x04\x04\x02\x04\x04\x12\x04,\x04+\x05\n\x0c\n\x05\x04\x04\x02\
+    \x04\x05\x12\x03,\x04\n\n\x0c\n\x05\x04\x04\x02\x04\x01\x12\x03,\x0b\x0f\
+    \n\x0c\n\x05\x04\x04\x02\x04\x03\x12\x03,\x12\x13\n\x0b\n\x04\x04\x04\
+    \x02\x05\x12\x03-\x04\"\n\r\n\x05\x04\x04\x02\x05\x04\x12\x04-\x04,\x14\
+    \n\x0c\n\x05\x04\x04\x02\x05\x06\x12\x03-\x04\x10\n\x0c\n\x05\x04\x04\
+    \x02\x05\x01\x12\x03-\x11\x1d\n\x0c\n\x05\x04\x04\x02\x05\x03\x12\x03-\
+    \x20!\n\x0b\n\x04\x04\x04\x02\x06\x12\x03.\x04\x1c\n\r\n\x05\x04\x04\x02\
+    \x06\x04\x12\x04.\x04-\"\n\x0c\n\x05\x04\x04\x02\x06\x05\x12\x03.\x04\n\
+    \n\x0c\n\x05\x04\x04\x02\x06\x01\x12\x03.\x0b\x17\n\x0c\n\x05\x04\x04\
+    \x02\x06\x03\x12\x03.\x1a\x1b\n\n\n\x02\x04\x05\x12\x041\08\x01\n\n\n\
+    \x03\x04\x05\x01\x12\x031\x08\x18\n\x0b\n\x04\x04\x05\x02\0\x12\x032\x04\
+    \x16\n\r\n\x05\x04\x05\x02\0\x04\x12\x042\x041\x1a\n\x0c\n\x05\x04\x05\
+    \x02\0\x06\x12\x032\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x032\x0b\x11\
+    \n\x0c\n\x05\x04\x05\x02\0\x03\x12\x032\x14\x15\n\x0b\n\x04\x04\x05\x02\
+    \x01\x12\x033\x04\x12\n\r\n\x05\x04\x05\x02\x01\x04\x12\x043\x042\x16\n\
+    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x033\x04\x08\n\x0c\n\x05\x04\x05\x02\
+    \x01\x01\x12\x033\t\r\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x033\x10\x11\n\
+    \x0b\n\x04\x04\x05\x02\x02\x12\x034\x04\x14\n\r\n\x05\x04\x05\x02\x02\
+    \x04\x12\x044\x043\x12\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x034\x04\x08\
+    \n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x034\t\x0f\n\x0c\n\x05\x04\x05\x02\
+    \x02\x03\x12\x034\x12\x13\n\x0b\n\x04\x04\x05\x02\x03\x12\x035\x04\x14\n\
+    \r\n\x05\x04\x05\x02\x03\x04\x12\x045\x044\x14\n\x0c\n\x05\x04\x05\x02\
+    \x03\x05\x12\x035\x04\n\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x035\x0b\x0f\
+    \n\x0c\n\x05\x04\x05\x02\x03\x03\x12\x035\x12\x13\n\x0b\n\x04\x04\x05\
+    \x02\x04\x12\x036\x04\"\n\r\n\x05\x04\x05\x02\x04\x04\x12\x046\x045\x14\
+    \n\x0c\n\x05\x04\x05\x02\x04\x06\x12\x036\x04\x10\n\x0c\n\x05\x04\x05\
+    \x02\x04\x01\x12\x036\x11\x1d\n\x0c\n\x05\x04\x05\x02\x04\x03\x12\x036\
+    \x20!\n\x0b\n\x04\x04\x05\x02\x05\x12\x037\x04\x1c\n\r\n\x05\x04\x05\x02\
+    \x05\x04\x12\x047\x046\"\n\x0c\n\x05\x04\x05\x02\x05\x05\x12\x037\x04\n\
+    \n\x0c\n\x05\x04\x05\x02\x05\x01\x12\x037\x0b\x17\n\x0c\n\x05\x04\x05\
+    \x02\x05\x03\x12\x037\x1a\x1b\n\n\n\x02\x04\x06\x12\x04:\0D\x01\n\n\n\
+    \x03\x04\x06\x01\x12\x03:\x08\x10\n\x0b\n\x04\x04\x06\x02\0\x12\x03;\x04\
+    \x1b\n\r\n\x05\x04\x06\x02\0\x04\x12\x04;\x04:\x12\n\x0c\n\x05\x04\x06\
+    \x02\0\x05\x12\x03;\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03;\x0b\x16\
+    \n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03;\x19\x1a\n\x0b\n\x04\x04\x06\x02\
+    \x01\x12\x03<\x04\x1a\n\r\n\x05\x04\x06\x02\x01\x04\x12\x04<\x04;\x1b\n\
+    \x0c\n\x05\x04\x06\x02\x01\x06\x12\x03<\x04\x10\n\x0c\n\x05\x04\x06\x02\
+    \x01\x01\x12\x03<\x11\x15\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03<\x18\
+    \x19\n\x0b\n\x04\x04\x06\x02\x02\x12\x03=\x04\x14\n\r\n\x05\x04\x06\x02\
+    \x02\x04\x12\x04=\x04<\x1a\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03=\x04\
+    \n\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03=\x0b\x0f\n\x0c\n\x05\x04\x06\
+    \x02\x02\x03\x12\x03=\x12\x13\n\x0b\n\x04\x04\x06\x02\x03\x12\x03>\x04\
+    \x18\n\r\n\x05\x04\x06\x02\x03\x04\x12\x04>\x04=\x14\n\x0c\n\x05\x04\x06\
+    \x02\x03\x05\x12\x03>\x04\n\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x03>\x0b\
+    \x13\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\x03>\x16\x17\n\x0b\n\x04\x04\
+    \x06\x02\x04\x12\x03?\x04\x18\n\r\n\x05\x04\x06\x02\x04\x04\x12\x04?\x04\
+    >\x18\n\x0c\n\x05\x04\x06\x02\x04\x05\x12\x03?\x04\n\n\x0c\n\x05\x04\x06\
+    \x02\x04\x01\x12\x03?\x0b\x13\n\x0c\n\x05\x04\x06\x02\x04\x03\x12\x03?\
+    \x16\x17\n\x0b\n\x04\x04\x06\x02\x05\x12\x03@\x04\x18\n\r\n\x05\x04\x06\
+    \x02\x05\x04\x12\x04@\x04?\x18\n\x0c\n\x05\x04\x06\x02\x05\x05\x12\x03@\
+    \x04\n\n\x0c\n\x05\x04\x06\x02\x05\x01\x12\x03@\x0b\x13\n\x0c\n\x05\x04\
+    \x06\x02\x05\x03\x12\x03@\x16\x17\n\x0b\n\x04\x04\x06\x02\x06\x12\x03A\
+    \x04\x15\n\r\n\x05\x04\x06\x02\x06\x04\x12\x04A\x04@\x18\n\x0c\n\x05\x04\
+    \x06\x02\x06\x05\x12\x03A\x04\n\n\x0c\n\x05\x04\x06\x02\x06\x01\x12\x03A\
+    \x0b\x10\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x03A\x13\x14\n\x0b\n\x04\
+    \x04\x06\x02\x07\x12\x03B\x04\"\n\x0c\n\x05\x04\x06\x02\x07\x04\x12\x03B\
+    \x04\x0c\n\x0c\n\x05\x04\x06\x02\x07\x06\x12\x03B\r\x18\n\x0c\n\x05\x04\
+    \x06\x02\x07\x01\x12\x03B\x19\x1d\n\x0c\n\x05\x04\x06\x02\x07\x03\x12\
+    \x03B\x20!\n\x0b\n\x04\x04\x06\x02\x08\x12\x03C\x04\x1c\n\r\n\x05\x04\
+    \x06\x02\x08\x04\x12\x04C\x04B\"\n\x0c\n\x05\x04\x06\x02\x08\x05\x12\x03\
+    C\x04\n\n\x0c\n\x05\x04\x06\x02\x08\x01\x12\x03C\x0b\x17\n\x0c\n\x05\x04\
+    \x06\x02\x08\x03\x12\x03C\x1a\x1b\n\n\n\x02\x04\x07\x12\x04F\0J\x01\n\n\
+    \n\x03\x04\x07\x01\x12\x03F\x08\x13\n\x0b\n\x04\x04\x07\x02\0\x12\x03G\
+    \x04\x17\n\r\n\x05\x04\x07\x02\0\x04\x12\x04G\x04F\x15\n\x0c\n\x05\x04\
+    \x07\x02\0\x05\x12\x03G\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03G\x0b\
+    \x12\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03G\x15\x16\n\x0b\n\x04\x04\x07\
+    \x02\x01\x12\x03H\x04\x1a\n\r\n\x05\x04\x07\x02\x01\x04\x12\x04H\x04G\
+    \x17\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03H\x04\n\n\x0c\n\x05\x04\x07\
+    \x02\x01\x01\x12\x03H\x0b\x15\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x03H\
+    \x18\x19\n\x0b\n\x04\x04\x07\x02\x02\x12\x03I\x04\x1d\n\r\n\x05\x04\x07\
+    \x02\x02\x04\x12\x04I\x04H\x1a\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x03I\
+    \x04\n\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03I\x0b\x18\n\x0c\n\x05\x04\
+    \x07\x02\x02\x03\x12\x03I\x1b\x1c\n\n\n\x02\x04\x08\x12\x04L\0N\x01\n\n\
+    \n\x03\x04\x08\x01\x12\x03L\x08\x14\n\x0b\n\x04\x04\x08\x02\0\x12\x03M\
+    \x04$\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03M\x04\x0c\n\x0c\n\x05\x04\x08\
+    \x02\0\x06\x12\x03M\r\x15\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03M\x16\x1f\
+    \n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03M\"#\n\t\n\x02\x04\t\x12\x03P\0.\n\
+    \n\n\x03\x04\t\x01\x12\x03P\x08\x12\n\x0b\n\x04\x04\t\x02\0\x12\x03P\x15\
+    ,\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03P\x15\x14\n\x0c\n\x05\x04\t\x02\0\
+    \x05\x12\x03P\x15\x1b\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03P\x1c'\n\x0c\n\
+    \x05\x04\t\x02\0\x03\x12\x03P*+\n\n\n\x02\x04\n\x12\x04R\0T\x01\n\n\n\
+    \x03\x04\n\x01\x12\x03R\x08\x12\n\x0b\n\x04\x04\n\x02\0\x12\x03S\x04\x20\
+    \n\x0c\n\x05\x04\n\x02\0\x04\x12\x03S\x04\x0c\n\x0c\n\x05\x04\n\x02\0\
+    \x06\x12\x03S\r\x13\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03S\x14\x1b\n\x0c\n\
+    \x05\x04\n\x02\0\x03\x12\x03S\x1e\x1f\n\n\n\x02\x04\x0b\x12\x04V\0Z\x01\
+    \n\n\n\x03\x04\x0b\x01\x12\x03V\x08\x0e\n\x0b\n\x04\x04\x0b\x02\0\x12\
+    \x03W\x04\x15\n\r\n\x05\x04\x0b\x02\0\x04\x12\x04W\x04V\x10\n\x0c\n\x05\
+    \x04\x0b\x02\0\x05\x12\x03W\x04\n\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03W\
+    \x0b\x10\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03W\x13\x14\n\x0b\n\x04\x04\
+    \x0b\x02\x01\x12\x03X\x04\x1a\n\r\n\x05\x04\x0b\x02\x01\x04\x12\x04X\x04\
+    W\x15\n\x0c\n\x05\x04\x0b\x02\x01\x06\x12\x03X\x04\x0c\n\x0c\n\x05\x04\
+    \x0b\x02\x01\x01\x12\x03X\r\x15\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03X\
+    \x18\x19\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03Y\x04\x1a\n\r\n\x05\x04\x0b\
+    \x02\x02\x04\x12\x04Y\x04X\x1a\n\x0c\n\x05\x04\x0b\x02\x02\x06\x12\x03Y\
+    \x04\x0f\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x03Y\x10\x15\n\x0c\n\x05\
+    \x04\x0b\x02\x02\x03\x12\x03Y\x18\x19\n\n\n\x02\x04\x0c\x12\x04\\\0j\x01\
+    \n\n\n\x03\x04\x0c\x01\x12\x03\\\x08\x0c\n\x0b\n\x04\x04\x0c\x02\0\x12\
+    \x03]\x04\x17\n\r\n\x05\x04\x0c\x02\0\x04\x12\x04]\x04\\\x0e\n\x0c\n\x05\
+    \x04\x0c\x02\0\x05\x12\x03]\x04\n\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03]\
+    \x0b\x12\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03]\x15\x16\n\x0c\n\x04\x04\
+    \x0c\x08\0\x12\x04^\x04g\x05\n\x0c\n\x05\x04\x0c\x08\0\x01\x12\x03^\n\
+    \x0e\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03_\x08\x16\n\x0c\n\x05\x04\x0c\
+    \x02\x01\x06\x12\x03_\x08\x0c\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03_\r\
+    \x11\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03_\x14\x15\n\x0b\n\x04\x04\
+    \x0c\x02\x02\x12\x03`\x08\x18\n\x0c\n\x05\x04\x0c\x02\x02\x06\x12\x03`\
+    \x08\r\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03`\x0e\x13\n\x0c\n\x05\x04\
+    \x0c\x02\x02\x03\x12\x03`\x16\x17\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03a\
+    \x08\x19\n\x0c\n\x05\x04\x0c\x02\x03\x06\x12\x03a\x08\x0f\n\x0c\n\x05\
+    \x04\x0c\x02\x03\x01\x12\x03a\x10\x14\n\x0c\n\x05\x04\x0c\x02\x03\x03\
+    \x12\x03a\x17\x18\n\x0b\n\x04\x04\x0c\x02\x04\x12\x03b\x08\x19\n\x0c\n\
+    \x05\x04\x0c\x02\x04\x06\x12\x03b\x08\r\n\x0c\n\x05\x04\x0c\x02\x04\x01\
+    \x12\x03b\x0e\x14\n\x0c\n\x05\x04\x0c\x02\x04\x03\x12\x03b\x17\x18\n\x0b\
+    \n\x04\x04\x0c\x02\x05\x12\x03c\x08\x1b\n\x0c\n\x05\x04\x0c\x02\x05\x06\
+    \x12\x03c\x08\x0e\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03c\x0f\x16\n\x0c\
+    \n\x05\x04\x0c\x02\x05\x03\x12\x03c\x19\x1a\n\x0b\n\x04\x04\x0c\x02\x06\
+    \x12\x03d\x08\x14\n\x0c\n\x05\x04\x0c\x02\x06\x06\x12\x03d\x08\x0b\n\x0c\
+    \n\x05\x04\x0c\x02\x06\x01\x12\x03d\x0c\x0f\n\x0c\n\x05\x04\x0c\x02\x06\
+    \x03\x12\x03d\x12\x13\n\x0b\n\x04\x04\x0c\x02\x07\x12\x03e\x08&\n\x0c\n\
+    \x05\x04\x0c\x02\x07\x06\x12\x03e\x08\x14\n\x0c\n\x05\x04\x0c\x02\x07\
+    \x01\x12\x03e\x15!\n\x0c\n\x05\x04\x0c\x02\x07\x03\x12\x03e$%\n\x0b\n\
+    \x04\x04\x0c\x02\x08\x12\x03f\x08\x1c\n\x0c\n\x05\x04\x0c\x02\x08\x06\
+    \x12\x03f\x08\r\n\x0c\n\x05\x04\x0c\x02\x08\x01\x12\x03f\x0e\x16\n\x0c\n\
+    \x05\x04\x0c\x02\x08\x03\x12\x03f\x19\x1b\n\x0b\n\x04\x04\x0c\x02\t\x12\
+    \x03h\x04\x14\n\r\n\x05\x04\x0c\x02\t\x04\x12\x04h\x04g\x05\n\x0c\n\x05\
+    \x04\x0c\x02\t\x05\x12\x03h\x04\n\n\x0c\n\x05\x04\x0c\x02\t\x01\x12\x03h\
+    \x0b\x0f\n\x0c\n\x05\x04\x0c\x02\t\x03\x12\x03h\x12\x13\n\x0b\n\x04\x04\
+    \x0c\x02\n\x12\x03i\x04\x1d\n\r\n\x05\x04\x0c\x02\n\x04\x12\x04i\x04h\
+    \x14\n\x0c\n\x05\x04\x0c\x02\n\x05\x12\x03i\x04\n\n\x0c\n\x05\x04\x0c\
+    \x02\n\x01\x12\x03i\x0b\x17\n\x0c\n\x05\x04\x0c\x02\n\x03\x12\x03i\x1a\
+    \x1c\n\n\n\x02\x04\r\x12\x04l\0x\x01\n\n\n\x03\x04\r\x01\x12\x03l\x08\
+    \x12\n\x0b\n\x04\x04\r\x02\0\x12\x03m\x04\x17\n\r\n\x05\x04\r\x02\0\x04\
+    \x12\x04m\x04l\x14\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03m\x04\n\n\x0c\n\
+    \x05\x04\r\x02\0\x01\x12\x03m\x0b\x12\n\x0c\n\x05\x04\r\x02\0\x03\x12\
+    \x03m\x15\x16\n\x0b\n\x04\x04\r\x02\x01\x12\x03n\x04\x1b\n\r\n\x05\x04\r\
+    \x02\x01\x04\x12\x04n\x04m\x17\n\x0c\n\x05\x04\r\x02\x01\x05\x12\x03n\
+    \x04\n\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03n\x0b\x16\n\x0c\n\x05\x04\r\
+    \x02\x01\x03\x12\x03n\x19\x1a\n\x0b\n\x04\x04\r\x02\x02\x12\x03o\x04\x16\
+    \n\r\n\x05\x04\r\x02\x02\x04\x12\x04o\x04n\x1b\n\x0c\n\x05\x04\r\x02\x02\
+    \x06\x12\x03o\x04\n\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03o\x0b\x11\n\x0c\
+    \n\x05\x04\r\x02\x02\x03\x12\x03o\x14\x15\n\x0b\n\x04\x04\r\x02\x03\x12\
+    \x03p\x04\x15\n\r\n\x05\x04\r\x02\x03\x04\x12\x04p\x04o\x16\n\x0c\n\x05\
+    \x04\r\x02\x03\x05\x12\x03p\x04\n\n\x0c\n\x05\x04\r\x02\x03\x01\x12\x03p\
+    \x0b\x10\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03p\x13\x14\n\x0b\n\x04\x04\
+    \r\x02\x04\x12\x03q\x04\x1c\n\r\n\x05\x04\r\x02\x04\x04\x12\x04q\x04p\
+    \x15\n\x0c\n\x05\x04\r\x02\x04\x05\x12\x03q\x04\n\n\x0c\n\x05\x04\r\x02\
+    \x04\x01\x12\x03q\x0b\x17\n\x0c\n\x05\x04\r\x02\x04\x03\x12\x03q\x1a\x1b\
+    \n\x0b\n\x04\x04\r\x02\x05\x12\x03r\x04!\n\r\n\x05\x04\r\x02\x05\x04\x12\
+    \x04r\x04q\x1c\n\x0c\n\x05\x04\r\x02\x05\x05\x12\x03r\x04\n\n\x0c\n\x05\
+    \x04\r\x02\x05\x01\x12\x03r\x0b\x1c\n\x0c\n\x05\x04\r\x02\x05\x03\x12\
+    \x03r\x1f\x20\n\x0b\n\x04\x04\r\x02\x06\x12\x03s\x04\x1a\n\r\n\x05\x04\r\
+    \x02\x06\x04\x12\x04s\x04r!\n\x0c\n\x05\x04\r\x02\x06\x05\x12\x03s\x04\n\
+    \n\x0c\n\x05\x04\r\x02\x06\x01\x12\x03s\x0b\x15\n\x0c\n\x05\x04\r\x02\
+    \x06\x03\x12\x03s\x18\x19\n\x0b\n\x04\x04\r\x02\x07\x12\x03t\x04\x14\n\r\
+    \n\x05\x04\r\x02\x07\x04\x12\x04t\x04s\x1a\n\x0c\n\x05\x04\r\x02\x07\x05\
+    \x12\x03t\x04\n\n\x0c\n\x05\x04\r\x02\x07\x01\x12\x03t\x0b\x0f\n\x0c\n\
+    \x05\x04\r\x02\x07\x03\x12\x03t\x12\x13\n\x0b\n\x04\x04\r\x02\x08\x12\
+    \x03u\x04\x14\n\r\n\x05\x04\r\x02\x08\x04\x12\x04u\x04t\x14\n\x0c\n\x05\
+    \x04\r\x02\x08\x05\x12\x03u\x04\n\n\x0c\n\x05\x04\r\x02\x08\x01\x12\x03u\
+    \x0b\x0f\n\x0c\n\x05\x04\r\x02\x08\x03\x12\x03u\x12\x13\nX\n\x04\x04\r\
+    \x02\t\x12\x03w\x04\x16\x1aK\x20Identity\x20of\x20the\x20user\x20that\
+    \x20scheduled\x20the\x20task,\x20empty\x20without\x20access\x20tokens\n\
+    \n\r\n\x05\x04\r\x02\t\x04\x12\x04w\x04u\x14\n\x0c\n\x05\x04\r\x02\t\x05\
+    \x12\x03w\x04\n\n\x0c\n\x05\x04\r\x02\t\x01\x12\x03w\x0b\x10\n\x0c\n\x05\
+    \x04\r\x02\t\x03\x12\x03w\x13\x15\n\n\n\x02\x04\x0e\x12\x04z\0|\x01\n\n\
+    \n\x03\x04\x0e\x01\x12\x03z\x08\x16\n\x0b\n\x04\x04\x0e\x02\0\x12\x03{\
+    \x04\"\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\x03{\x04\x0c\n\x0c\n\x05\x04\
+    \x0e\x02\0\x06\x12\x03{\r\x17\n\x0c\n\x05\x04\x0e\x02\0\x01\x12\x03{\x18\
+    \x1d\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03{\x20!\n\x0b\n\x02\x04\x0f\x12\
+    \x05~\0\x82\x01\x01\n\n\n\x03\x04\x0f\x01\x12\x03~\x08\x14\n\x0b\n\x04\
+    \x04\x0f\x02\0\x12\x03\x7f\x04\x17\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\
+    \x7f\x04~\x16\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03\x7f\x04\n\n\x0c\n\
+    \x05\x04\x0f\x02\0\x01\x12\x03\x7f\x0b\x12\n\x0c\n\x05\x04\x0f\x02\0\x03\
+    \x12\x03\x7f\x15\x16\n\x0c\n\x04\x04\x0f\x02\x01\x12\x04\x80\x01\x04!\n\
+    \x0e\n\x05\x04\x0f\x02\x01\x04\x12\x05\x80\x01\x04\x7f\x17\n\r\n\x05\x04\
+    \x0f\x02\x01\x05\x12\x04\x80\x01\x04\n\n\r\n\x05\x04\x0f\x02\x01\x01\x12\
+    \x04\x80\x01\x0b\x1c\n\r\n\x05\x04\x0f\x02\x01\x03\x12\x04\x80\x01\x1f\
+    \x20\n\x0c\n\x04\x04\x0f\x02\x02\x12\x04\x81\x01\x04\x14\n\x0f\n\x05\x04\
+    \x0f\x02\x02\x04\x12\x06\x81\x01\x04\x80\x01!\n\r\n\x05\x04\x0f\x02\x02\
+    \x05\x12\x04\x81\x01\x04\n\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\x81\x01\
+    \x0b\x0f\n\r\n\x05\x04\x0f\x02\x02\x03\x12\x04\x81\x01\x12\x13\n\x0c\n\
+    \x02\x04\x10\x12\x06\x84\x01\0\x8b\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\
+    \x04\x84\x01\x08\x10\n\x0c\n\x04\x04\x10\x02\0\x12\x04\x85\x01\x04\x18\n\
+    \x0f\n\x05\x04\x10\x02\0\x04\x12\x06\x85\x01\x04\x84\x01\x12\n\r\n\x05\
+    \x04\x10\x02\0\x05\x12\x04\x85\x01\x04\n\n\r\n\x05\x04\x10\x02\0\x01\x12\
+    \x04\x85\x01\x0b\x13\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\x85\x01\x16\x17\
+    \n\x0c\n\x04\x04\x10\x02\x01\x12\x04\x86\x01\x04\x17\n\x0f\n\x05\x04\x10\
+    \x02\x01\x04\x12\x06\x86\x01\x04\x85\x01\x18\n\r\n\x05\x04\x10\x02\x01\
+    \x05\x12\x04\x86\x01\x04\n\n\r\n\x05\x04\x10\x02\x01\x01\x12\x04\x86\x01\
+    \x0b\x12\n\r\n\x05\x04\x10\x02\x01\x03\x12\x04\x86\x01\x15\x16\n\x0c\n\
+    \x04\x04\x10\x02\x02\x12\x04\x87\x01\x04\x19\n\x0f\n\x05\x04\x10\x02\x02\
+    \x04\x12\x06\x87\x01\x04\x86\x01\x17\n\r\n\x05\x04\x10\x02\x02\x05\x12\
+    \x04\x87\x01\x04\n\n\r\n\x05\x04\x10\x02\x02\x01\x12\x04\x87\x01\x0b\x14\
+    \n\r\n\x05\x04\x10\x02\x02\x03\x12\x04\x87\x01\x17\x18\n\x0c\n\x04\x04\
+    \x10\x02\x03\x12\x04\x88\x01\x04\x14\n\x0f\n\x05\x04\x10\x02\x03\x04\x12\
+    \x06\x88\x01\x04\x87\x01\x19\n\r\n\x05\x04\x10\x02\x03\x05\x12\x04\x88\
+    \x01\x04\n\n\r\n\x05\x04\x10\x02\x03\x01\x12\x04\x88\x01\x0b\x0f\n\r\n\
+    \x05\x04\x10\x02\x03\x03\x12\x04\x88\x01\x12\x13\n\x0c\n\x04\x04\x10\x02\
+    \x04\x12\x04\x89\x01\x04\x18\n\x0f\n\x05\x04\x10\x02\x04\x04\x12\x06\x89\
+    \x01\x04\x88\x01\x14\n\r\n\x05\x04\x10\x02\x04\x05\x12\x04\x89\x01\x04\n\
+    \n\r\n\x05\x04\x10\x02\x04\x01\x12\x04\x89\x01\x0b\x13\n\r\n\x05\x04\x10\
+    \x02\x04\x03\x12\x04\x89\x01\x16\x17\n\x0c\n\x04\x04\x10\x02\x05\x12\x04\
+    \x8a\x01\x04\x20\n\x0f\n\x05\x04\x10\x02\x05\x04\x12\x06\x8a\x01\x04\x89\
+    \x01\x18\n\r\n\x05\x04\x10\x02\x05\x05\x12\x04\x8a\x01\x04\n\n\r\n\x05\
+    \x04\x10\x02\x05\x01\x12\x04\x8a\x01\x0b\x1b\n\r\n\x05\x04\x10\x02\x05\
+    \x03\x12\x04\x8a\x01\x1e\x1f\n\x0c\n\x02\x04\x11\x12\x06\x8d\x01\0\x91\
+    \x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\x8d\x01\x08\x0c\n\x0c\n\x04\x04\
+    \x11\x02\0\x12\x04\x8e\x01\x04\x1f\n\x0f\n\x05\x04\x11\x02\0\x04\x12\x06\
+    \x8e\x01\x04\x8d\x01\x0e\n\r\n\x05\x04\x11\x02\0\x06\x12\x04\x8e\x01\x04\
+    \x0b\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\x8e\x01\x0c\x1a\n\r\n\x05\x04\
+    \x11\x02\0\x03\x12\x04\x8e\x01\x1d\x1e\n\x0c\n\x04\x04\x11\x02\x01\x12\
+    \x04\x8f\x01\x04\x10\n\x0f\n\x05\x04\x11\x02\x01\x04\x12\x06\x8f\x01\x04\
+    \x8e\x01\x1f\n\r\n\x05\x04\x11\x02\x01\x06\x12\x04\x8f\x01\x04\x07\n\r\n\
+    \x05\x04\x11\x02\x01\x01\x12\x04\x8f\x01\x08\x0b\n\r\n\x05\x04\x11\x02\
+    \x01\x03\x12\x04\x8f\x01\x0e\x0f\n\x0c\n\x04\x04\x11\x02\x02\x12\x04\x90\
+    \x01\x04\x14\n\x0f\n\x05\x04\x11\x02\x02\x04\x12\x06\x90\x01\x04\x8f\x01\
+    \x10\n\r\n\x05\x04\x11\x02\x02\x05\x12\x04\x90\x01\x04\n\n\r\n\x05\x04\
+    \x11\x02\x02\x01\x12\x04\x90\x01\x0b\x0f\n\r\n\x05\x04\x11\x02\x02\x03\
+    \x12\x04\x90\x01\x12\x13\n\x0c\n\x02\x04\x12\x12\x06\x93\x01\0\x98\x01\
+    \x01\n\x0b\n\x03\x04\x12\x01\x12\x04\x93\x01\x08\x0e\n\x0c\n\x04\x04\x12\
+    \x02\0\x12\x04\x94\x01\x04\x1f\n\x0f\n\x05\x04\x12\x02\0\x04\x12\x06\x94\
+    \x01\x04\x93\x01\x10\n\r\n\x05\x04\x12\x02\0\x06\x12\x04\x94\x01\x04\x0b\
+    \n\r\n\x05\x04\x12\x02\0\x01\x12\x04\x94\x01\x0c\x1a\n\r\n\x05\x04\x12\
+    \x02\0\x03\x12\x04\x94\x01\x1d\x1e\n\x0c\n\x04\x04\x12\x02\x01\x12\x04\
+    \x95\x01\x04\x10\n\x0f\n\x05\x04\x12\x02\x01\x04\x12\x06\x95\x01\x04\x94\
+    \x01\x1f\n\r\n\x05\x04\x12\x02\x01\x06\x12\x04\x95\x01\x04\x07\n\r\n\x05\
+    \x04\x12\x02\x01\x01\x12\x04\x95\x01\x08\x0b\n\r\n\x05\x04\x12\x02\x01\
+    \x03\x12\x04\x95\x01\x0e\x0f\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\x96\x01\
+    \x04\x14\n\x0f\n\x05\x04\x12\x02\x02\x04\x12\x06\x96\x01\x04\x95\x01\x10\
+    \n\r\n\x05\x04\x12\x02\x02\x05\x12\x04\x96\x01\x04\n\n\r\n\x05\x04\x12\
+    \x02\x02\x01\x12\x04\x96\x01\x0b\x0f\n\r\n\x05\x04\x12\x02\x02\x03\x12\
+    \x04\x96\x01\x12\x13\n\x0c\n\x04\x04\x12\x02\x03\x12\x04\x97\x01\x04\x20\
+    \n\x0f\n\x05\x04\x12\x02\x03\x04\x12\x06\x97\x01\x04\x96\x01\x14\n\r\n\
+    \x05\x04\x12\x02\x03\x05\x12\x04\x97\x01\x04\n\n\r\n\x05\x04\x12\x02\x03\
+    \x01\x12\x04\x97\x01\x0b\x1b\n\r\n\x05\x04\x12\x02\x03\x03\x12\x04\x97\
+    \x01\x1e\x1f\n\x0c\n\x02\x04\x13\x12\x06\x9a\x01\0\x9f\x01\x01\n\x0b\n\
+    \x03\x04\x13\x01\x12\x04\x9a\x01\x08\x0b\n\x0c\n\x04\x04\x13\x02\0\x12\
+    \x04\x9b\x01\x04\x1f\n\x0f\n\x05\x04\x13\x02\0\x04\x12\x06\x9b\x01\x04\
+    \x9a\x01\r\n\r\n\x05\x04\x13\x02\0\x06\x12\x04\x9b\x01\x04\x0b\n\r\n\x05\
+    \x04\x13\x02\0\x01\x12\x04\x9b\x01\x0c\x1a\n\r\n\x05\x04\x13\x02\0\x03\
+    \x12\x04\x9b\x01\x1d\x1e\n\x0c\n\x04\x04\x13\x02\x01\x12\x04\x9c\x01\x04\
+    \x10\n\x0f\n\x05\x04\x13\x02\x01\x04\x12\x06\x9c\x01\x04\x9b\x01\x1f\n\r\
+    \n\x05\x04\x13\x02\x01\x06\x12\x04\x9c\x01\x04\x07\n\r\n\x05\x04\x13\x02\
+    \x01\x01\x12\x04\x9c\x01\x08\x0b\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\
+    \x9c\x01\x0e\x0f\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\x9d\x01\x04\x14\n\
+    \x0f\n\x05\x04\x13\x02\x02\x04\x12\x06\x9d\x01\x04\x9c\x01\x10\n\r\n\x05\
+    \x04\x13\x02\x02\x05\x12\x04\x9d\x01\x04\n\n\r\n\x05\x04\x13\x02\x02\x01\
+    \x12\x04\x9d\x01\x0b\x0f\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\x9d\x01\
+    \x12\x13\n\x0c\n\x04\x04\x13\x02\x03\x12\x04\x9e\x01\x04\x1c\n\x0f\n\x05\
+    \x04\x13\x02\x03\x04\x12\x06\x9e\x01\x04\x9d\x01\x14\n\r\n\x05\x04\x13\
+    \x02\x03\x05\x12\x04\x9e\x01\x04\n\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\
+    \x9e\x01\x0b\x17\n\r\n\x05\x04\x13\x02\x03\x03\x12\x04\x9e\x01\x1a\x1b\n\
+    \x0c\n\x02\x04\x14\x12\x06\xa1\x01\0\xa4\x01\x01\n\x0b\n\x03\x04\x14\x01\
+    \x12\x04\xa1\x01\x08\x14\n\x0c\n\x04\x04\x14\x02\0\x12\x04\xa2\x01\x04#\
+    \n\r\n\x05\x04\x14\x02\0\x04\x12\x04\xa2\x01\x04\x0c\n\r\n\x05\x04\x14\
+    \x02\0\x06\x12\x04\xa2\x01\r\x14\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\xa2\
+    \x01\x15\x1e\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xa2\x01!\"\n\x0c\n\x04\
+    \x04\x14\x02\x01\x12\x04\xa3\x01\x04\x12\n\x0f\n\x05\x04\x14\x02\x01\x04\
+    \x12\x06\xa3\x01\x04\xa2\x01#\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\xa3\
+    \x01\x04\x08\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\xa3\x01\t\r\n\r\n\x05\
+    \x04\x14\x02\x01\x03\x12\x04\xa3\x01\x10\x11\n\x0c\n\x02\x04\x15\x12\x06\
+    \xa6\x01\0\xa9\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\xa6\x01\x08\x0b\n\
+    \x0c\n\x04\x04\x15\x02\0\x12\x04\xa7\x01\x04\x16\n\x0f\n\x05\x04\x15\x02\
+    \0\x04\x12\x06\xa7\x01\x04\xa6\x01\r\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\
+    \xa7\x01\x04\n\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xa7\x01\x0b\x11\n\r\n\
+    \x05\x04\x15\x02\0\x03\x12\x04\xa7\x01\x14\x15\n\x0c\n\x04\x04\x15\x02\
+    \x01\x12\x04\xa8\x01\x04\x16\n\x0f\n\x05\x04\x15\x02\x01\x04\x12\x06\xa8\
+    \x01\x04\xa7\x01\x16\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xa8\x01\x04\n\
+    \n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\xa8\x01\x0b\x11\n\r\n\x05\x04\x15\
+    \x02\x01\x03\x12\x04\xa8\x01\x14\x15\n\x0c\n\x02\x04\x16\x12\x06\xab\x01\
+    \0\xad\x01\x01\n\x0b\n\x03\x04\x16\x01\x12\x04\xab\x01\x08\x0f\n\x0c\n\
+    \x04\x04\x16\x02\0\x12\x04\xac\x01\x04\x1a\n\r\n\x05\x04\x16\x02\0\x04\
+    \x12\x04\xac\x01\x04\x0c\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\xac\x01\r\
+    \x10\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\xac\x01\x11\x15\n\r\n\x05\x04\
+    \x16\x02\0\x03\x12\x04\xac\x01\x18\x19\n\x0c\n\x02\x04\x17\x12\x06\xb0\
+    \x01\0\xb5\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\x04\xb0\x01\x08\x0f\n\x0e\
+    \n\x04\x04\x17\x08\0\x12\x06\xb1\x01\x04\xb4\x01\x05\n\r\n\x05\x04\x17\
+    \x08\0\x01\x12\x04\xb1\x01\n\x0f\n\x0c\n\x04\x04\x17\x02\0\x12\x04\xb2\
+    \x01\x08\x16\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xb2\x01\x08\x0e\n\r\n\
+    \x05\x04\x17\x02\0\x01\x12\x04\xb2\x01\x0f\x11\n\r\n\x05\x04\x17\x02\0\
+    \x03\x12\x04\xb2\x01\x14\x15\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xb3\x01\
+    \x08\x15\n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xb3\x01\x08\r\n\r\n\x05\
+    \x04\x17\x02\x01\x01\x12\x04\xb3\x01\x0e\x10\n\r\n\x05\x04\x17\x02\x01\
+    \x03\x12\x04\xb3\x01\x13\x14\n\x0c\n\x02\x04\x18\x12\x06\xb7\x01\0\xc1\
+    \x01\x01\n\x0b\n\x03\x04\x18\x01\x12\x04\xb7\x01\x08\x12\n\x0c\n\x04\x04\
+    \x18\x02\0\x12\x04\xb8\x01\x04\x17\n\x0f\n\x05\x04\x18\x02\0\x04\x12\x06\
+    \xb8\x01\x04\xb7\x01\x14\n\r\n\x05\x04\x18\x02\0\x05\x12\x04\xb8\x01\x04\
+    \n\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xb8\x01\x0b\x12\n\r\n\x05\x04\x18\
+    \x02\0\x03\x12\x04\xb8\x01\x15\x16\n\x0c\n\x04\x04\x18\x02\x01\x12\x04\
+    \xb9\x01\x04\x16\n\x0f\n\x05\x04\x18\x02\x01\x04\x12\x06\xb9\x01\x04\xb8\
+    \x01\x17\n\r\n\x05\x04\x18\x02\x01\x06\x12\x04\xb9\x01\x04\n\n\r\n\x05\
+    \x04\x18\x02\x01\x01\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x01\
+    \x03\x12\x04\xb9\x01\x14\x15\n\x0c\n\x04\x04\x18\x02\x02\x12\x04\xba\x01\
+    \x04$\n\r\n\x05\x04\x18\x02\x02\x04\x12\x04\xba\x01\x04\x0c\n\r\n\x05\
+    \x04\x18\x02\x02\x06\x12\x04\xba\x01\r\x13\n\r\n\x05\x04\x18\x02\x02\x01\
+    \x12\x04\xba\x01\x14\x1f\n\r\n\x05\x04\x18\x02\x02\x03\x12\x04\xba\x01\"\
+    #\n\x0c\n\x04\x04\x18\x02\x03\x12\x04\xbb\x01\x04\x19\n\x0f\n\x05\x04\
+    \x18\x02\x03\x04\x12\x06\xbb\x01\x04\xba\x01$\n\r\n\x05\x04\x18\x02\x03\
+    \x05\x12\x04\xbb\x01\x04\x08\n\r\n\x05\x04\x18\x02\x03\x01\x12\x04\xbb\
+    \x01\t\x14\n\r\n\x05\x04\x18\x02\x03\x03\x12\x04\xbb\x01\x17\x18\n\x0c\n\
+    \x04\x04\x18\x02\x04\x12\x04\xbc\x01\x04\x1b\n\x0f\n\x05\x04\x18\x02\x04\
+    \x04\x12\x06\xbc\x01\x04\xbb\x01\x19\n\r\n\x05\x04\x18\x02\x04\x05\x12\
+    \x04\xbc\x01\x04\n\n\r\n\x05\x04\x18\x02\x04\x01\x12\x04\xbc\x01\x0b\x16\
+    \n\r\n\x05\x04\x18\x02\x04\x03\x12\x04\xbc\x01\x19\x1a\n\x0c\n\x04\x04\
+    \x18\x02\x05\x12\x04\xbd\x01\x04&\n\x0f\n\x05\x04\x18\x02\x05\x04\x12\
+    \x06\xbd\x01\x04\xbc\x01\x1b\n\r\n\x05\x04\x18\x02\x05\x06\x12\x04\xbd\
+    \x01\x04\x0f\n\r\n\x05\x04\x18\x02\x05\x01\x12\x04\xbd\x01\x10!\n\r\n\
+    \x05\x04\x18\x02\x05\x03\x12\x04\xbd\x01$%\n\x0c\n\x04\x04\x18\x02\x06\
+    \x12\x04\xbe\x01\x04#\n\x0f\n\x05\x04\x18\x02\x06\x04\x12\x06\xbe\x01\
+    \x04\xbd\x01&\n\r\n\x05\x04\x18\x02\x06\x06\x12\x04\xbe\x01\x04\x0f\n\r\
+    \n\x05\x04\x18\x02\x06\x01\x12\x04\xbe\x01\x10\x1e\n\r\n\x05\x04\x18\x02\
+    \x06\x03\x12\x04\xbe\x01!\"\n\x0c\n\x04\x04\x18\x02\x07\x12\x04\xbf\x01\
+    \x04\x1f\n\x0f\n\x05\x04\x18\x02\x07\x04\x12\x06\xbf\x01\x04\xbe\x01#\n\
+    \r\n\x05\x04\x18\x02\x07\x05\x12\x04\xbf\x01\x04\n\n\r\n\x05\x04\x18\x02\
+    \x07\x01\x12\x04\xbf\x01\x0b\x1a\n\r\n\x05\x04\x18\x02\x07\x03\x12\x04\
+    \xbf\x01\x1d\x1e\n\x0c\n\x04\x04\x18\x02\x08\x12\x04\xc0\x01\x04\x1c\n\
+    \x0f\n\x05\x04\x18\x02\x08\x04\x12\x06\xc0\x01\x04\xbf\x01\x1f\n\r\n\x05\
+    \x04\x18\x02\x08\x06\x12\x04\xc0\x01\x04\x0f\n\r\n\x05\x04\x18\x02\x08\
+    \x01\x12\x04\xc0\x01\x10\x17\n\r\n\x05\x04\x18\x02\x08\x03\x12\x04\xc0\
+    \x01\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\xc3\x01\0\xc7\x01\x01\n\x0b\n\
+    \x03\x04\x19\x01\x12\x04\xc3\x01\x08\x13\n\x0c\n\x04\x04\x19\x02\0\x12\
+    \x04\xc4\x01\x04\x1b\n\x0f\n\x05\x04\x19\x02\0\x04\x12\x06\xc4\x01\x04\
+    \xc3\x01\x15\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\xc4\x01\x04\n\n\r\n\x05\
+    \x04\x19\x02\0\x01\x12\x04\xc4\x01\x0b\x16\n\r\n\x05\x04\x19\x02\0\x03\
+    \x12\x04\xc4\x01\x19\x1a\n\x0c\n\x04\x04\x19\x02\x01\x12\x04\xc5\x01\x04\
+    *\n\r\n\x05\x04\x19\x02\x01\x04\x12\x04\xc5\x01\x04\x0c\n\r\n\x05\x04\
+    \x19\x02\x01\x06\x12\x04\xc5\x01\r\x18\n\r\n\x05\x04\x19\x02\x01\x01\x12\
+    \x04\xc5\x01\x19%\n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\xc5\x01()\n\x0c\
+    \n\x04\x04\x19\x02\x02\x12\x04\xc6\x01\x04\x1f\n\x0f\n\x05\x04\x19\x02\
+    \x02\x04\x12\x06\xc6\x01\x04\xc5\x01*\n\r\n\x05\x04\x19\x02\x02\x05\x12\
+    \x04\xc6\x01\x04\n\n\r\n\x05\x04\x19\x02\x02\x01\x12\x04\xc6\x01\x0b\x1a\
+    \n\r\n\x05\x04\x19\x02\x02\x03\x12\x04\xc6\x01\x1d\x1e\n\x0c\n\x02\x04\
+    \x1a\x12\x06\xc9\x01\0\xcc\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xc9\
+    \x01\x08\x13\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\xca\x01\x04\x14\n\x0f\n\
+    \x05\x04\x1a\x02\0\x04\x12\x06\xca\x01\x04\xc9\x01\x15\n\r\n\x05\x04\x1a\
+    \x02\0\x05\x12\x04\xca\x01\x04\n\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xca\
+    \x01\x0b\x0f\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\xca\x01\x12\x13\n\x0c\n\
+    \x04\x04\x1a\x02\x01\x12\x04\xcb\x01\x04\x17\n\x0f\n\x05\x04\x1a\x02\x01\
+    \x04\x12\x06\xcb\x01\x04\xca\x01\x14\n\r\n\x05\x04\x1a\x02\x01\x05\x12\
+    \x04\xcb\x01\x04\n\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\xcb\x01\x0b\x12\
+    \n\r\n\x05\x04\x1a\x02\x01\x03\x12\x04\xcb\x01\x15\x16\n\x0c\n\x02\x04\
+    \x1b\x12\x06\xce\x01\0\xd2\x01\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\xce\
+    \x01\x08\x11\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xcf\x01\x04$\n\x0f\n\x05\
+    \x04\x1b\x02\0\x04\x12\x06\xcf\x01\x04\xce\x01\x13\n\r\n\x05\x04\x1b\x02\
+    \0\x05\x12\x04\xcf\x01\x04\n\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\xcf\x01\
+    \x0b\x1f\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xcf\x01\"#\n\x0c\n\x04\x04\
+    \x1b\x02\x01\x12\x04\xd0\x01\x04#\n\x0f\n\x05\x04\x1b\x02\x01\x04\x12\
+    \x06\xd0\x01\x04\xcf\x01$\n\r\n\x05\x04\x1b\x02\x01\x05\x12\x04\xd0\x01\
+    \x04\n\n\r\n\x05\x04\x1b\x02\x01\x01\x12\x04\xd0\x01\x0b\x1e\n\r\n\x05\
+    \x04\x1b\x02\x01\x03\x12\x04\xd0\x01!\"\n\x0c\n\x04\x04\x1b\x02\x02\x12\
+    \x04\xd1\x01\x04$\n\x0f\n\x05\x04\x1b\x02\x02\x04\x12\x06\xd1\x01\x04\
+    \xd0\x01#\n\r\n\x05\x04\x1b\x02\x02\x05\x12\x04\xd1\x01\x04\n\n\r\n\x05\
+    \x04\x1b\x02\x02\x01\x12\x04\xd1\x01\x0b\x1f\n\r\n\x05\x04\x1b\x02\x02\
+    \x03\x12\x04\xd1\x01\"#\n\x0c\n\x02\x04\x1c\x12\x06\xd4\x01\0\xd8\x01\
+    \x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\xd4\x01\x08\x13\n\x0c\n\x04\x04\x1c\
+    \x02\0\x12\x04\xd5\x01\x04\x19\n\x0f\n\x05\x04\x1c\x02\0\x04\x12\x06\xd5\
+    \x01\x04\xd4\x01\x15\n\r\n\x05\x04\x1c\x02\0\x05\x12\x04\xd5\x01\x04\n\n\
+    \r\n\x05\x04\x1c\x02\0\x01\x12\x04\xd5\x01\x0b\x14\n\r\n\x05\x04\x1c\x02\
+    \0\x03\x12\x04\xd5\x01\x17\x18\n\x0c\n\x04\x04\x1c\x02\x01\x12\x04\xd6\
+    \x01\x04\x15\n\x0f\n\x05\x04\x1c\x02\x01\x04\x12\x06\xd6\x01\x04\xd5\x01\
+    \x19\n\r\n\x05\x04\x1c\x02\x01\x05\x12\x04\xd6\x01\x04\t\n\r\n\x05\x04\
+    \x1c\x02\x01\x01\x12\x04\xd6\x01\n\x10\n\r\n\x05\x04\x1c\x02\x01\x03\x12\
+    \x04\xd6\x01\x13\x14\n\x0c\n\x04\x04\x1c\x02\x02\x12\x04\xd7\x01\x04\x15\
+    \n\x0f\n\x05\x04\x1c\x02\x02\x04\x12\x06\xd7\x01\x04\xd6\x01\x15\n\r\n\
+    \x05\x04\x1c\x02\x02\x05\x12\x04\xd7\x01\x04\n\n\r\n\x05\x04\x1c\x02\x02\
+    \x01\x12\x04\xd7\x01\x0b\x10\n\r\n\x05\x04\x1c\x02\x02\x03\x12\x04\xd7\
+    \x01\x13\x14\n\x0c\n\x02\x04\x1d\x12\x06\xda\x01\0\xe0\x01\x01\n\x0b\n\
+    \x03\x04\x1d\x01\x12\x04\xda\x01\x08\x0e\n\x0e\n\x04\x04\x1d\x08\0\x12\
+    \x06\xdb\x01\x04\xdf\x01\x05\n\r\n\x05\x04\x1d\x08\0\x01\x12\x04\xdb\x01\
+    \n\x0f\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\xdc\x01\x08\x1c\n\r\n\x05\x04\
+    \x1d\x02\0\x06\x12\x04\xdc\x01\x08\x12\n\r\n\x05\x04\x1d\x02\0\x01\x12\
+    \x04\xdc\x01\x13\x17\n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\xdc\x01\x1a\x1b\
+    \n\x0c\n\x04\x04\x1d\x02\x01\x12\x04\xdd\x01\x08\x1a\n\r\n\x05\x04\x1d\
+    \x02\x01\x06\x12\x04\xdd\x01\x08\x11\n\r\n\x05\x04\x1d\x02\x01\x01\x12\
+    \x04\xdd\x01\x12\x15\n\r\n\x05\x04\x1d\x02\x01\x03\x12\x04\xdd\x01\x18\
+    \x19\n\x0c\n\x04\x04\x1d\x02\x02\x12\x04\xde\x01\x08\x1a\n\r\n\x05\x04\
+    \x1d\x02\x02\x06\x12\x04\xde\x01\x08\x11\n\r\n\x05\x04\x1d\x02\x02\x01\
+    \x12\x04\xde\x01\x12\x15\n\r\n\x05\x04\x1d\x02\x02\x03\x12\x04\xde\x01\
+    \x18\x19\n\x0c\n\x02\x04\x1e\x12\x06\xe2\x01\0\xe9\x01\x01\n\x0b\n\x03\
+    \x04\x1e\x01\x12\x04\xe2\x01\x08\x12\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\
+    \xe3\x01\x04\x1f\n\x0f\n\x05\x04\x1e\x02\0\x04\x12\x06\xe3\x01\x04\xe2\
+    \x01\x14\n\r\n\x05\x04\x1e\x02\0\x06\x12\x04\xe3\x01\x04\x0b\n\r\n\x05\
+    \x04\x1e\x02\0\x01\x12\x04\xe3\x01\x0c\x1a\n\r\n\x05\x04\x1e\x02\0\x03\
+    \x12\x04\xe3\x01\x1d\x1e\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\xe4\x01\x04\
+    $\n\x0f\n\x05\x04\x1e\x02\x01\x04\x12\x06\xe4\x01\x04\xe3\x01\x1f\n\r\n\
+    \x05\x04\x1e\x02\x01\x06\x12\x04\xe4\x01\x04\x0b\n\r\n\x05\x04\x1e\x02\
+    \x01\x01\x12\x04\xe4\x01\x0c\x1f\n\r\n\x05\x04\x1e\x02\x01\x03\x12\x04\
+    \xe4\x01\"#\n\x0c\n\x04\x04\x1e\x02\x02\x12\x04\xe5\x01\x04\x1c\n\x0f\n\
+    \x05\x04\x1e\x02\x02\x04\x12\x06\xe5\x01\x04\xe4\x01$\n\r\n\x05\x04\x1e\
+    \x02\x02\x05\x12\x04\xe5\x01\x04\n\n\r\n\x05\x04\x1e\x02\x02\x01\x12\x04\
+    \xe5\x01\x0b\x17\n\r\n\x05\x04\x1e\x02\x02\x03\x12\x04\xe5\x01\x1a\x1b\n\
+    \x0c\n\x04\x04\x1e\x02\x03\x12\x04\xe6\x01\x04\x1c\n\x0f\n\x05\x04\x1e\
+    \x02\x03\x04\x12\x06\xe6\x01\x04\xe5\x01\x1c\n\r\n\x05\x04\x1e\x02\x03\
+    \x06\x12\x04\xe6\x01\x04\x0f\n\r\n\x05\x04\x1e\x02\x03\x01\x12\x04\xe6\
+    \x01\x10\x17\n\r\n\x05\x04\x1e\x02\x03\x03\x12\x04\xe6\x01\x1a\x1b\nJ\n\
+    \x04\x04\x1e\x02\x04\x12\x04\xe8\x01\x04\x13\x1a<\x20TTL\x20or\x20hop\
+    \x20limit\x20of\x20the\x20reply,\x200\x20if\x20unknown\x20(IPv6\x20repli\
+    es)\n\n\x0f\n\x05\x04\x1e\x02\x04\x04\x12\x06\xe8\x01\x04\xe6\x01\x1c\n\
+    \r\n\x05\x04\x1e\x02\x04\x05\x12\x04\xe8\x01\x04\n\n\r\n\x05\x04\x1e\x02\
+    \x04\x01\x12\x04\xe8\x01\x0b\x0e\n\r\n\x05\x04\x1e\x02\x04\x03\x12\x04\
+    \xe8\x01\x11\x12\n\x0c\n\x02\x04\x1f\x12\x06\xeb\x01\0\xf5\x01\x01\n\x0b\
+    \n\x03\x04\x1f\x01\x12\x04\xeb\x01\x08\x11\n\x0c\n\x04\x04\x1f\x02\0\x12\
+    \x04\xec\x01\x04\x1f\n\x0f\n\x05\x04\x1f\x02\0\x04\x12\x06\xec\x01\x04\
+    \xeb\x01\x13\n\r\n\x05\x04\x1f\x02\0\x06\x12\x04\xec\x01\x04\x0b\n\r\n\
+    \x05\x04\x1f\x02\0\x01\x12\x04\xec\x01\x0c\x1a\n\r\n\x05\x04\x1f\x02\0\
+    \x03\x12\x04\xec\x01\x1d\x1e\n\x0c\n\x04\x04\x1f\x02\x01\x12\x04\xed\x01\
+    \x04$\n\x0f\n\x05\x04\x1f\x02\x01\x04\x12\x06\xed\x01\x04\xec\x01\x1f\n\
+    \r\n\x05\x04\x1f\x02\x01\x06\x12\x04\xed\x01\x04\x0b\n\r\n\x05\x04\x1f\
+    \x02\x01\x01\x12\x04\xed\x01\x0c\x1f\n\r\n\x05\x04\x1f\x02\x01\x03\x12\
+    \x04\xed\x01\"#\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xee\x01\x04\x1c\n\
+    \x0f\n\x05\x04\x1f\x02\x02\x04\x12\x06\xee\x01\x04\xed\x01$\n\r\n\x05\
+    \x04\x1f\x02\x02\x05\x12\x04\xee\x01\x04\n\n\r\n\x05\x04\x1f\x02\x02\x01\
+    \x12\x04\xee\x01\x0b\x17\n\r\n\x05\x04\x1f\x02\x02\x03\x12\x04\xee\x01\
+    \x1a\x1b\nJ\n\x04\x04\x1f\x02\x03\x12\x04\xf0\x01\x04\x13\x1a<\x20TTL\
+    \x20or\x20hop\x20limit\x20of\x20the\x20reply,\x200\x20if\x20unknown\x20(\
+    IPv6\x20replies)\n\n\x0f\n\x05\x04\x1f\x02\x03\x04\x12\x06\xf0\x01\x04\
+    \xee\x01\x1c\n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\xf0\x01\x04\n\n\r\n\
+    \x05\x04\x1f\x02\x03\x01\x12\x04\xf0\x01\x0b\x0e\n\r\n\x05\x04\x1f\x02\
+    \x03\x03\x12\x04\xf0\x01\x11\x12\n\x0c\n\x04\x04\x1f\x02\x04\x12\x04\xf1\
+    \x01\x04\x17\n\x0f\n\x05\x04\x1f\x02\x04\x04\x12\x06\xf1\x01\x04\xf0\x01\
+    \x13\n\r\n\x05\x04\x1f\x02\x04\x05\x12\x04\xf1\x01\x04\n\n\r\n\x05\x04\
+    \x1f\x02\x04\x01\x12\x04\xf1\x01\x0b\x12\n\r\n\x05\x04\x1f\x02\x04\x03\
+    \x12\x04\xf1\x01\x15\x16\n\x0c\n\x04\x04\x1f\x02\x05\x12\x04\xf2\x01\x04\
+    \x1d\n\x0f\n\x05\x04\x1f\x02\x05\x04\x12\x06\xf2\x01\x04\xf1\x01\x17\n\r\
+    \n\x05\x04\x1f\x02\x05\x05\x12\x04\xf2\x01\x04\n\n\r\n\x05\x04\x1f\x02\
+    \x05\x01\x12\x04\xf2\x01\x0b\x18\n\r\n\x05\x04\x1f\x02\x05\x03\x12\x04\
+    \xf2\x01\x1b\x1c\n\x0c\n\x04\x04\x1f\x02\x06\x12\x04\xf3\x01\x04\x1b\n\
+    \x0f\n\x05\x04\x1f\x02\x06\x04\x12\x06\xf3\x01\x04\xf2\x01\x1d\n\r\n\x05\
+    \x04\x1f\x02\x06\x05\x12\x04\xf3\x01\x04\n\n\r\n\x05\x04\x1f\x02\x06\x01\
+    \x12\x04\xf3\x01\x0b\x16\n\r\n\x05\x04\x1f\x02\x06\x03\x12\x04\xf3\x01\
+    \x19\x1a\n\x0c\n\x04\x04\x1f\x02\x07\x12\x04\xf4\x01\x04\x15\n\x0f\n\x05\
+    \x04\x1f\x02\x07\x04\x12\x06\xf4\x01\x04\xf3\x01\x1b\n\r\n\x05\x04\x1f\
+    \x02\x07\x05\x12\x04\xf4\x01\x04\n\n\r\n\x05\x04\x1f\x02\x07\x01\x12\x04\
+    \xf4\x01\x0b\x10\n\r\n\x05\x04\x1f\x02\x07\x03\x12\x04\xf4\x01\x13\x14\n\
+    \x0c\n\x02\x04\x20\x12\x06\xf7\x01\0\xff\x01\x01\n\x0b\n\x03\x04\x20\x01\
+    \x12\x04\xf7\x01\x08\x11\n\x0c\n\x04\x04\x20\x02\0\x12\x04\xf8\x01\x04\
+    \x1f\n\x0f\n\x05\x04\x20\x02\0\x04\x12\x06\xf8\x01\x04\xf7\x01\x13\n\r\n\
+    \x05\x04\x20\x02\0\x06\x12\x04\xf8\x01\x04\x0b\n\r\n\x05\x04\x20\x02\0\
+    \x01\x12\x04\xf8\x01\x0c\x1a\n\r\n\x05\x04\x20\x02\0\x03\x12\x04\xf8\x01\
+    \x1d\x1e\n\x0c\n\x04\x04\x20\x02\x01\x12\x04\xf9\x01\x04$\n\x0f\n\x05\
+    \x04\x20\x02\x01\x04\x12\x06\xf9\x01\x04\xf8\x01\x1f\n\r\n\x05\x04\x20\
+    \x02\x01\x06\x12\x04\xf9\x01\x04\x0b\n\r\n\x05\x04\x20\x02\x01\x01\x12\
+    \x04\xf9\x01\x0c\x1f\n\r\n\x05\x04\x20\x02\x01\x03\x12\x04\xf9\x01\"#\n\
+    \x0c\n\x04\x04\x20\x02\x02\x12\x04\xfa\x01\x04\x1c\n\x0f\n\x05\x04\x20\
+    \x02\x02\x04\x12\x06\xfa\x01\x04\xf9\x01$\n\r\n\x05\x04\x20\x02\x02\x05\
+    \x12\x04\xfa\x01\x04\n\n\r\n\x05\x04\x20\x02\x02\x01\x12\x04\xfa\x01\x0b\
+    \x17\n\r\n\x05\x04\x20\x02\x02\x03\x12\x04\xfa\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x20\x02\x03\x12\x04\xfb\x01\x04\x1c\n\x0f\n\x05\x04\x20\x02\x03\x04\
+    \x12\x06\xfb\x01\x04\xfa\x01\x1c\n\r\n\x05\x04\x20\x02\x03\x06\x12\x04\
+    \xfb\x01\x04\x0f\n\r\n\x05\x04\x20\x02\x03\x01\x12\x04\xfb\x01\x10\x17\n\
+    \r\n\x05\x04\x20\x02\x03\x03\x12\x04\xfb\x01\x1a\x1b\nJ\n\x04\x04\x20\
+    \x02\x04\x12\x04\xfd\x01\x04\x13\x1a<\x20TTL\x20or\x20hop\x20limit\x20of\
+    \x20the\x20reply,\x200\x20if\x20unknown\x20(IPv6\x20replies)\n\n\x0f\n\
+    \x05\x04\x20\x02\x04\x04\x12\x06\xfd\x01\x04\xfb\x01\x1c\n\r\n\x05\x04\
+    \x20\x02\x04\x05\x12\x04\xfd\x01\x04\n\n\r\n\x05\x04\x20\x02\x04\x01\x12\
+    \x04\xfd\x01\x0b\x0e\n\r\n\x05\x04\x20\x02\x04\x03\x12\x04\xfd\x01\x11\
+    \x12\n\x0c\n\x04\x04\x20\x02\x05\x12\x04\xfe\x01\x04\x15\n\x0f\n\x05\x04\
+    \x20\x02\x05\x04\x12\x06\xfe\x01\x04\xfd\x01\x13\n\r\n\x05\x04\x20\x02\
+    \x05\x05\x12\x04\xfe\x01\x04\n\n\r\n\x05\x04\x20\x02\x05\x01\x12\x04\xfe\
+    \x01\x0b\x10\n\r\n\x05\x04\x20\x02\x05\x03\x12\x04\xfe\x01\x13\x14\n\x0c\
+    \n\x02\x04!\x12\x06\x81\x02\0\x87\x02\x01\n\x0b\n\x03\x04!\x01\x12\x04\
+    \x81\x02\x08\x13\n\x0c\n\x04\x04!\x02\0\x12\x04\x82\x02\x04\x17\n\x0f\n\
+    \x05\x04!\x02\0\x04\x12\x06\x82\x02\x04\x81\x02\x15\n\r\n\x05\x04!\x02\0\
+    \x05\x12\x04\x82\x02\x04\n\n\r\n\x05\x04!\x02\0\x01\x12\x04\x82\x02\x0b\
+    \x12\n\r\n\x05\x04!\x02\0\x03\x12\x04\x82\x02\x15\x16\n\x0c\n\x04\x04!\
+    \x02\x01\x12\x04\x83\x02\x04\x1d\n\x0f\n\x05\x04!\x02\x01\x04\x12\x06\
+    \x83\x02\x04\x82\x02\x17\n\r\n\x05\x04!\x02\x01\x05\x12\x04\x83\x02\x04\
+    \n\n\r\n\x05\x04!\x02\x01\x01\x12\x04\x83\x02\x0b\x18\n\r\n\x05\x04!\x02\
+    \x01\x03\x12\x04\x83\x02\x1b\x1c\n\x0c\n\x04\x04!\x02\x02\x12\x04\x84\
+    \x02\x04\x1f\n\x0f\n\x05\x04!\x02\x02\x04\x12\x06\x84\x02\x04\x83\x02\
+    \x1d\n\r\n\x05\x04!\x02\x02\x06\x12\x04\x84\x02\x04\x0b\n\r\n\x05\x04!\
+    \x02\x02\x01\x12\x04\x84\x02\x0c\x1a\n\r\n\x05\x04!\x02\x02\x03\x12\x04\
+    \x84\x02\x1d\x1e\n\x0c\n\x04\x04!\x02\x03\x12\x04\x85\x02\x04$\n\x0f\n\
+    \x05\x04!\x02\x03\x04\x12\x06\x85\x02\x04\x84\x02\x1f\n\r\n\x05\x04!\x02\
+    \x03\x06\x12\x04\x85\x02\x04\x0b\n\r\n\x05\x04!\x02\x03\x01\x12\x04\x85\
+    \x02\x0c\x1f\n\r\n\x05\x04!\x02\x03\x03\x12\x04\x85\x02\"#\n\x0c\n\x04\
+    \x04!\x02\x04\x12\x04\x86\x02\x04\x16\n\x0f\n\x05\x04!\x02\x04\x04\x12\
+    \x06\x86\x02\x04\x85\x02$\n\r\n\x05\x04!\x02\x04\x05\x12\x04\x86\x02\x04\
+    \n\n\r\n\x05\x04!\x02\x04\x01\x12\x04\x86\x02\x0b\x11\n\r\n\x05\x04!\x02\
+    \x04\x03\x12\x04\x86\x02\x14\x15b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -26,12 +26,19 @@ const METHOD_VERFPLOETER_CONNECT: ::grpcio::Method<super::verfploeter::Metadata,
 };
 
 const METHOD_VERFPLOETER_DO_TASK: ::grpcio::Method<super::verfploeter::ScheduleTask, super::verfploeter::Ack> = ::grpcio::Method {
-    ty: ::grpcio::MethodType::Unary,
+    ty: ::grpcio::MethodType::ClientStreaming,
     name: "/Verfploeter/do_task",
     req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_VERFPLOETER_DO_CAMPAIGN: ::grpcio::Method<super::verfploeter::ScheduleCampaign, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ClientStreaming,
+    name: "/Verfploeter/do_campaign",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 const METHOD_VERFPLOETER_LIST_CLIENTS: ::grpcio::Method<super::verfploeter::Empty, super::verfploeter::ClientList> = ::grpcio::Method {
     ty: ::grpcio::MethodType::Unary,
     name: "/Verfploeter/list_clients",
@@ -60,6 +67,76 @@ const METHOD_VERFPLOETER_TASK_FINISHED: ::grpcio::Method<super::verfploeter::Tas
     resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
 };
 
+const METHOD_VERFPLOETER_GET_RESULTS: ::grpcio::Method<super::verfploeter::ResultRequest, super::verfploeter::TaskResult> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ServerStreaming,
+    name: "/Verfploeter/get_results",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_LIST_TASKS: ::grpcio::Method<super::verfploeter::Empty, super::verfploeter::TaskStatusList> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/list_tasks",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_GET_TASK_STATUS: ::grpcio::Method<super::verfploeter::TaskId, super::verfploeter::TaskStatus> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/get_task_status",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_CANCEL_TASK: ::grpcio::Method<super::verfploeter::TaskId, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/cancel_task",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_TASK_PROGRESS: ::grpcio::Method<super::verfploeter::TaskProgress, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/task_progress",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_SYNC_CLOCK: ::grpcio::Method<super::verfploeter::ClockSync, super::verfploeter::ClockSync> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/sync_clock",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_REPORT_CLOCK_OFFSET: ::grpcio::Method<super::verfploeter::ClockOffset, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/report_clock_offset",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_ADD_SCHEDULE: ::grpcio::Method<super::verfploeter::Schedule, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::ClientStreaming,
+    name: "/Verfploeter/add_schedule",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_LIST_SCHEDULES: ::grpcio::Method<super::verfploeter::Empty, super::verfploeter::ScheduleList> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/list_schedules",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
+const METHOD_VERFPLOETER_REMOVE_SCHEDULE: ::grpcio::Method<super::verfploeter::ScheduleId, super::verfploeter::Ack> = ::grpcio::Method {
+    ty: ::grpcio::MethodType::Unary,
+    name: "/Verfploeter/remove_schedule",
+    req_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+    resp_mar: ::grpcio::Marshaller { ser: ::grpcio::pb_ser, de: ::grpcio::pb_de },
+};
+
 pub struct VerfploeterClient {
     client: ::grpcio::Client,
 }
@@ -79,20 +156,20 @@ impl VerfploeterClient {
         self.connect_opt(req, ::grpcio::CallOption::default())
     }
 
-    pub fn do_task_opt(&self, req: &super::verfploeter::ScheduleTask, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::Ack> {
-        self.client.unary_call(&METHOD_VERFPLOETER_DO_TASK, req, opt)
+    pub fn do_task_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::ScheduleTask>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.client.client_streaming(&METHOD_VERFPLOETER_DO_TASK, opt)
     }
 
-    pub fn do_task(&self, req: &super::verfploeter::ScheduleTask) -> ::grpcio::Result<super::verfploeter::Ack> {
-        self.do_task_opt(req, ::grpcio::CallOption::default())
+    pub fn do_task(&self) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::ScheduleTask>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.do_task_opt(::grpcio::CallOption::default())
     }
 
-    pub fn do_task_async_opt(&self, req: &super::verfploeter::ScheduleTask, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
-        self.client.unary_call_async(&METHOD_VERFPLOETER_DO_TASK, req, opt)
+    pub fn do_campaign_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::ScheduleCampaign>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.client.client_streaming(&METHOD_VERFPLOETER_DO_CAMPAIGN, opt)
     }
 
-    pub fn do_task_async(&self, req: &super::verfploeter::ScheduleTask) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
-        self.do_task_async_opt(req, ::grpcio::CallOption::default())
+    pub fn do_campaign(&self) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::ScheduleCampaign>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.do_campaign_opt(::grpcio::CallOption::default())
     }
 
     pub fn list_clients_opt(&self, req: &super::verfploeter::Empty, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::ClientList> {
@@ -150,6 +227,150 @@ impl VerfploeterClient {
     pub fn task_finished_async(&self, req: &super::verfploeter::TaskId) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
         self.task_finished_async_opt(req, ::grpcio::CallOption::default())
     }
+
+    pub fn get_results_opt(&self, req: &super::verfploeter::ResultRequest, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::verfploeter::TaskResult>> {
+        self.client.server_streaming(&METHOD_VERFPLOETER_GET_RESULTS, req, opt)
+    }
+
+    pub fn get_results(&self, req: &super::verfploeter::ResultRequest) -> ::grpcio::Result<::grpcio::ClientSStreamReceiver<super::verfploeter::TaskResult>> {
+        self.get_results_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_tasks_opt(&self, req: &super::verfploeter::Empty, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::TaskStatusList> {
+        self.client.unary_call(&METHOD_VERFPLOETER_LIST_TASKS, req, opt)
+    }
+
+    pub fn list_tasks(&self, req: &super::verfploeter::Empty) -> ::grpcio::Result<super::verfploeter::TaskStatusList> {
+        self.list_tasks_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_tasks_async_opt(&self, req: &super::verfploeter::Empty, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::TaskStatusList>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_LIST_TASKS, req, opt)
+    }
+
+    pub fn list_tasks_async(&self, req: &super::verfploeter::Empty) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::TaskStatusList>> {
+        self.list_tasks_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_task_status_opt(&self, req: &super::verfploeter::TaskId, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::TaskStatus> {
+        self.client.unary_call(&METHOD_VERFPLOETER_GET_TASK_STATUS, req, opt)
+    }
+
+    pub fn get_task_status(&self, req: &super::verfploeter::TaskId) -> ::grpcio::Result<super::verfploeter::TaskStatus> {
+        self.get_task_status_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn get_task_status_async_opt(&self, req: &super::verfploeter::TaskId, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::TaskStatus>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_GET_TASK_STATUS, req, opt)
+    }
+
+    pub fn get_task_status_async(&self, req: &super::verfploeter::TaskId) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::TaskStatus>> {
+        self.get_task_status_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn cancel_task_opt(&self, req: &super::verfploeter::TaskId, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.client.unary_call(&METHOD_VERFPLOETER_CANCEL_TASK, req, opt)
+    }
+
+    pub fn cancel_task(&self, req: &super::verfploeter::TaskId) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.cancel_task_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn cancel_task_async_opt(&self, req: &super::verfploeter::TaskId, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_CANCEL_TASK, req, opt)
+    }
+
+    pub fn cancel_task_async(&self, req: &super::verfploeter::TaskId) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.cancel_task_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn task_progress_opt(&self, req: &super::verfploeter::TaskProgress, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.client.unary_call(&METHOD_VERFPLOETER_TASK_PROGRESS, req, opt)
+    }
+
+    pub fn task_progress(&self, req: &super::verfploeter::TaskProgress) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.task_progress_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn task_progress_async_opt(&self, req: &super::verfploeter::TaskProgress, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_TASK_PROGRESS, req, opt)
+    }
+
+    pub fn task_progress_async(&self, req: &super::verfploeter::TaskProgress) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.task_progress_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn sync_clock_opt(&self, req: &super::verfploeter::ClockSync, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::ClockSync> {
+        self.client.unary_call(&METHOD_VERFPLOETER_SYNC_CLOCK, req, opt)
+    }
+
+    pub fn sync_clock(&self, req: &super::verfploeter::ClockSync) -> ::grpcio::Result<super::verfploeter::ClockSync> {
+        self.sync_clock_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn sync_clock_async_opt(&self, req: &super::verfploeter::ClockSync, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::ClockSync>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_SYNC_CLOCK, req, opt)
+    }
+
+    pub fn sync_clock_async(&self, req: &super::verfploeter::ClockSync) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::ClockSync>> {
+        self.sync_clock_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn report_clock_offset_opt(&self, req: &super::verfploeter::ClockOffset, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.client.unary_call(&METHOD_VERFPLOETER_REPORT_CLOCK_OFFSET, req, opt)
+    }
+
+    pub fn report_clock_offset(&self, req: &super::verfploeter::ClockOffset) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.report_clock_offset_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn report_clock_offset_async_opt(&self, req: &super::verfploeter::ClockOffset, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_REPORT_CLOCK_OFFSET, req, opt)
+    }
+
+    pub fn report_clock_offset_async(&self, req: &super::verfploeter::ClockOffset) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.report_clock_offset_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn add_schedule_opt(&self, opt: ::grpcio::CallOption) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::Schedule>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.client.client_streaming(&METHOD_VERFPLOETER_ADD_SCHEDULE, opt)
+    }
+
+    pub fn add_schedule(&self) -> ::grpcio::Result<(::grpcio::ClientCStreamSender<super::verfploeter::Schedule>, ::grpcio::ClientCStreamReceiver<super::verfploeter::Ack>)> {
+        self.add_schedule_opt(::grpcio::CallOption::default())
+    }
+
+    pub fn list_schedules_opt(&self, req: &super::verfploeter::Empty, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::ScheduleList> {
+        self.client.unary_call(&METHOD_VERFPLOETER_LIST_SCHEDULES, req, opt)
+    }
+
+    pub fn list_schedules(&self, req: &super::verfploeter::Empty) -> ::grpcio::Result<super::verfploeter::ScheduleList> {
+        self.list_schedules_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn list_schedules_async_opt(&self, req: &super::verfploeter::Empty, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::ScheduleList>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_LIST_SCHEDULES, req, opt)
+    }
+
+    pub fn list_schedules_async(&self, req: &super::verfploeter::Empty) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::ScheduleList>> {
+        self.list_schedules_async_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn remove_schedule_opt(&self, req: &super::verfploeter::ScheduleId, opt: ::grpcio::CallOption) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.client.unary_call(&METHOD_VERFPLOETER_REMOVE_SCHEDULE, req, opt)
+    }
+
+    pub fn remove_schedule(&self, req: &super::verfploeter::ScheduleId) -> ::grpcio::Result<super::verfploeter::Ack> {
+        self.remove_schedule_opt(req, ::grpcio::CallOption::default())
+    }
+
+    pub fn remove_schedule_async_opt(&self, req: &super::verfploeter::ScheduleId, opt: ::grpcio::CallOption) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.client.unary_call_async(&METHOD_VERFPLOETER_REMOVE_SCHEDULE, req, opt)
+    }
+
+    pub fn remove_schedule_async(&self, req: &super::verfploeter::ScheduleId) -> ::grpcio::Result<::grpcio::ClientUnaryReceiver<super::verfploeter::Ack>> {
+        self.remove_schedule_async_opt(req, ::grpcio::CallOption::default())
+    }
     pub fn spawn<F>(&self, f: F) where F: ::futures::Future<Item = (), Error = ()> + Send + 'static {
         self.client.spawn(f)
     }
@@ -157,11 +378,22 @@ impl VerfploeterClient {
 
 pub trait Verfploeter {
     fn connect(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::Metadata, sink: ::grpcio::ServerStreamingSink<super::verfploeter::Task>);
-    fn do_task(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::ScheduleTask, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
+    fn do_task(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::verfploeter::ScheduleTask>, sink: ::grpcio::ClientStreamingSink<super::verfploeter::Ack>);
+    fn do_campaign(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::verfploeter::ScheduleCampaign>, sink: ::grpcio::ClientStreamingSink<super::verfploeter::Ack>);
     fn list_clients(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::Empty, sink: ::grpcio::UnarySink<super::verfploeter::ClientList>);
     fn send_result(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskResult, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
     fn subscribe_result(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskId, sink: ::grpcio::ServerStreamingSink<super::verfploeter::TaskResult>);
     fn task_finished(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskId, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
+    fn get_results(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::ResultRequest, sink: ::grpcio::ServerStreamingSink<super::verfploeter::TaskResult>);
+    fn list_tasks(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::Empty, sink: ::grpcio::UnarySink<super::verfploeter::TaskStatusList>);
+    fn get_task_status(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskId, sink: ::grpcio::UnarySink<super::verfploeter::TaskStatus>);
+    fn cancel_task(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskId, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
+    fn task_progress(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::TaskProgress, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
+    fn sync_clock(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::ClockSync, sink: ::grpcio::UnarySink<super::verfploeter::ClockSync>);
+    fn report_clock_offset(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::ClockOffset, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
+    fn add_schedule(&mut self, ctx: ::grpcio::RpcContext, stream: ::grpcio::RequestStream<super::verfploeter::Schedule>, sink: ::grpcio::ClientStreamingSink<super::verfploeter::Ack>);
+    fn list_schedules(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::Empty, sink: ::grpcio::UnarySink<super::verfploeter::ScheduleList>);
+    fn remove_schedule(&mut self, ctx: ::grpcio::RpcContext, req: super::verfploeter::ScheduleId, sink: ::grpcio::UnarySink<super::verfploeter::Ack>);
 }
 
 pub fn create_verfploeter<S: Verfploeter + Send + Clone + 'static>(s: S) -> ::grpcio::Service {
@@ -171,10 +403,14 @@ pub fn create_verfploeter<S: Verfploeter + Send + Clone + 'static>(s: S) -> ::gr
         instance.connect(ctx, req, resp)
     });
     let mut instance = s.clone();
-    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_DO_TASK, move |ctx, req, resp| {
+    builder = builder.add_client_streaming_handler(&METHOD_VERFPLOETER_DO_TASK, move |ctx, req, resp| {
         instance.do_task(ctx, req, resp)
     });
     let mut instance = s.clone();
+    builder = builder.add_client_streaming_handler(&METHOD_VERFPLOETER_DO_CAMPAIGN, move |ctx, req, resp| {
+        instance.do_campaign(ctx, req, resp)
+    });
+    let mut instance = s.clone();
     builder = builder.add_unary_handler(&METHOD_VERFPLOETER_LIST_CLIENTS, move |ctx, req, resp| {
         instance.list_clients(ctx, req, resp)
     });
@@ -190,5 +426,45 @@ pub fn create_verfploeter<S: Verfploeter + Send + Clone + 'static>(s: S) -> ::gr
     builder = builder.add_unary_handler(&METHOD_VERFPLOETER_TASK_FINISHED, move |ctx, req, resp| {
         instance.task_finished(ctx, req, resp)
     });
+    let mut instance = s.clone();
+    builder = builder.add_server_streaming_handler(&METHOD_VERFPLOETER_GET_RESULTS, move |ctx, req, resp| {
+        instance.get_results(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_LIST_TASKS, move |ctx, req, resp| {
+        instance.list_tasks(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_GET_TASK_STATUS, move |ctx, req, resp| {
+        instance.get_task_status(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_CANCEL_TASK, move |ctx, req, resp| {
+        instance.cancel_task(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_TASK_PROGRESS, move |ctx, req, resp| {
+        instance.task_progress(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_SYNC_CLOCK, move |ctx, req, resp| {
+        instance.sync_clock(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_REPORT_CLOCK_OFFSET, move |ctx, req, resp| {
+        instance.report_clock_offset(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_client_streaming_handler(&METHOD_VERFPLOETER_ADD_SCHEDULE, move |ctx, req, resp| {
+        instance.add_schedule(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_LIST_SCHEDULES, move |ctx, req, resp| {
+        instance.list_schedules(ctx, req, resp)
+    });
+    let mut instance = s.clone();
+    builder = builder.add_unary_handler(&METHOD_VERFPLOETER_REMOVE_SCHEDULE, move |ctx, req, resp| {
+        instance.remove_schedule(ctx, req, resp)
+    });
     builder.build()
 }
@@ -0,0 +1,93 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server authentication (auth.rs)
+//!----------------------------------------------------------------------------
+//! Clients and CLI users identify themselves with an access token, which they
+//! send in the `verfploeter-token` header of every call. The token file of the
+//! server assigns an identity to each token, one per line:
+//!
+//!   <identity> <token>
+//!
+//! Empty lines and lines starting with `#` are ignored. grpcio 0.4 does not
+//! expose the certificate a peer presents, so with mutual TLS the client CA
+//! decides who may connect and the token who the peer is.
+//!----------------------------------------------------------------------------
+
+use grpcio::RpcContext;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Header (gRPC metadata key) that carries the access token of a call
+pub const TOKEN_HEADER: &str = "verfploeter-token";
+
+/// Identities of clients and CLI users, by access token
+pub struct Tokens {
+    identities: HashMap<String, String>,
+}
+
+impl Tokens {
+    pub fn load(token_file: &Path) -> Result<Tokens, String> {
+        let content = fs::read_to_string(token_file)
+            .map_err(|e| format!("unable to read token file {}: {}", token_file.display(), e))?;
+        parse_tokens(&content)
+    }
+
+    pub fn identity(&self, token: &str) -> Option<&str> {
+        self.identities.get(token).map(|i| i.as_str())
+    }
+}
+
+/// Identity of the peer of a call, or None if it did not send a known access token
+pub fn peer_identity(ctx: &RpcContext, tokens: &Tokens) -> Option<String> {
+    ctx.request_headers()
+        .iter()
+        .find(|(key, _)| *key == TOKEN_HEADER)
+        .and_then(|(_, value)| std::str::from_utf8(value).ok())
+        .and_then(|token| tokens.identity(token))
+        .map(|identity| identity.to_string())
+}
+
+fn parse_tokens(content: &str) -> Result<Tokens, String> {
+    let mut identities = HashMap::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        match fields.as_slice() {
+            [identity, token] => {
+                if identities
+                    .insert(token.to_string(), identity.to_string())
+                    .is_some()
+                {
+                    return Err(format!("duplicate token on line {} of token file", idx + 1));
+                }
+            }
+            _ => return Err(format!("invalid line {} of token file", idx + 1)),
+        }
+    }
+    Ok(Tokens { identities })
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod token_file {
+    use super::*;
+
+    #[test]
+    fn tokens_map_to_identities() {
+        let tokens = parse_tokens("# clients\nams s3cr3t\n\nalice hunter2\n").unwrap();
+        assert_eq!(tokens.identity("s3cr3t"), Some("ams"));
+        assert_eq!(tokens.identity("hunter2"), Some("alice"));
+        assert_eq!(tokens.identity("ams"), None);
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        assert!(parse_tokens("alice").is_err());
+        assert!(parse_tokens("alice hunter2 extra").is_err());
+        assert!(parse_tokens("alice hunter2\nbob hunter2").is_err());
+    }
+}
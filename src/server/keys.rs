@@ -0,0 +1,107 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server payload keys (keys.rs)
+//!----------------------------------------------------------------------------
+//! Secrets used by the clients to sign and verify probe payloads.
+//!
+//! The key file has one key per line, `<key_id> <secret>`, empty lines and
+//! lines starting with `#` are ignored. The last key in the file signs new
+//! tasks, the other keys are still accepted for replies, so a new key can be
+//! appended and the old one removed once replies to it are no longer expected.
+//! The file is read for every task, so keys can be rotated without a restart.
+//!----------------------------------------------------------------------------
+
+use crate::schema::verfploeter::{Key, KeyList};
+use protobuf::RepeatedField;
+use std::fs;
+use std::path::Path;
+
+/// Secret used when no key file is configured
+const DEFAULT_SECRET: &str = "test-secret";
+
+/// Loads the keys from a key file, or the default key when there is none
+pub fn load_keys(key_file: Option<&Path>) -> Result<KeyList, String> {
+    let key_file = match key_file {
+        Some(key_file) => key_file,
+        None => {
+            let mut key = Key::new();
+            key.set_secret(DEFAULT_SECRET.to_string());
+            let mut keys = KeyList::new();
+            keys.mut_keys().push(key);
+            return Ok(keys);
+        }
+    };
+
+    let content = fs::read_to_string(key_file)
+        .map_err(|e| format!("unable to read key file {}: {}", key_file.display(), e))?;
+    parse_keys(&content)
+}
+
+fn parse_keys(content: &str) -> Result<KeyList, String> {
+    let mut keys: Vec<Key> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut fields = line.splitn(2, char::is_whitespace);
+        let key_id = fields
+            .next()
+            .unwrap()
+            .parse::<u32>()
+            .map_err(|_| format!("invalid key id on line {} of key file", idx + 1))?;
+        let secret = fields.next().map(str::trim).unwrap_or("");
+        if secret.is_empty() {
+            return Err(format!("missing secret on line {} of key file", idx + 1));
+        }
+        if keys.iter().any(|k| k.get_key_id() == key_id) {
+            return Err(format!("duplicate key id {} in key file", key_id));
+        }
+
+        let mut key = Key::new();
+        key.set_key_id(key_id);
+        key.set_secret(secret.to_string());
+        keys.push(key);
+    }
+
+    if keys.is_empty() {
+        return Err("key file does not contain any keys".to_string());
+    }
+    let mut key_list = KeyList::new();
+    key_list.set_keys(RepeatedField::from_vec(keys));
+    Ok(key_list)
+}
+
+/// Returns the key that signs new tasks
+pub fn signing_key(keys: &KeyList) -> &Key {
+    keys.get_keys().last().expect("key list is never empty")
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod key_file {
+    use super::*;
+
+    #[test]
+    fn last_key_signs() {
+        let keys = parse_keys("# old key\n1 first secret\n\n2 second\n").unwrap();
+        assert_eq!(keys.get_keys().len(), 2);
+        assert_eq!(keys.get_keys()[0].get_secret(), "first secret");
+        assert_eq!(signing_key(&keys).get_key_id(), 2);
+        assert_eq!(signing_key(&keys).get_secret(), "second");
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        assert!(parse_keys("one secret").is_err());
+        assert!(parse_keys("1").is_err());
+        assert!(parse_keys("1 a\n1 b").is_err());
+        assert!(parse_keys("# nothing").is_err());
+    }
+
+    #[test]
+    fn default_key_without_file() {
+        let keys = load_keys(None).unwrap();
+        assert_eq!(signing_key(&keys).get_secret(), DEFAULT_SECRET);
+    }
+}
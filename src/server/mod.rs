@@ -8,32 +8,77 @@
 #![allow(unused_imports)]
 
 use super::schema::verfploeter::{
-    Ack, Client, ClientList, Empty, Metadata, ScheduleTask, Task, TaskId, TaskResult,
+    Ack, Address, Client, ClientList, ClockOffset, ClockSync, Destinations, Empty, Key, Metadata,
+    Ping, ResultRequest, Schedule, ScheduleCampaign, ScheduleId, ScheduleList, ScheduleRun,
+    ScheduleTask, Task, TaskId, TaskProgress, TaskResult, TaskStatus, TaskStatusList,
 };
 use super::schema::verfploeter_grpc::{self, Verfploeter};
 use futures::sync::mpsc::{channel, Sender};
 use futures::*;
 use grpcio::ServerCredentialsBuilder;
 use grpcio::{
-    ChannelBuilder, Environment, RpcContext, Server as GrpcServer, ServerBuilder,
-    ServerStreamingSink, UnarySink,
+    ClientStreamingSink, Environment, RequestStream, RpcContext, RpcStatus, RpcStatusCode,
+    Server as GrpcServer, ServerBuilder, ServerStreamingSink, UnarySink,
 };
 use protobuf::RepeatedField;
-use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hasher};
 use std::ops::AddAssign;
-use std::sync::{Arc, Mutex, RwLock};
-use std::time::Duration;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::runtime::Runtime;
 use tokio::timer::Interval;
+use crate::{DESTINATION_CHUNK_SIZE, RESULT_FLUSH_INTERVAL};
+
+mod auth;
+pub mod keys;
+mod policy;
+mod registry;
+pub mod schedule;
+mod store;
+pub use self::auth::TOKEN_HEADER;
+use self::policy::Role;
+use self::registry::TaskRegistry;
+use self::schedule::ScheduleStore;
+use self::store::ResultStore;
+
+/// Reason given to clients and CLI users when the server is shutting down
+const SHUTTING_DOWN: &str = "server is shutting down";
+
+/// Error of calls that need an identity but carry no valid access token
+const ACCESS_TOKEN_REQUIRED: &str = "access token required";
+
+/// Time to wait for calls to finish after the server stops, before they are cancelled
+const CALL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Time the final result of a task is held back after its client reported it finished, as
+/// the client sends the results of the last replies up to RESULT_FLUSH_INTERVAL later
+const FINAL_RESULT_DELAY: Duration = Duration::from_secs(RESULT_FLUSH_INTERVAL + 1);
+
+/// Time between checks for schedules that are due to run
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(1);
 
 pub struct Server {
     grpc_server: GrpcServer,
+    service: VerfploeterService,
+    shutdown_timeout: Duration,
 }
 
 pub struct ServerConfig {
     pub certificate: Option<Vec<u8>>,
     pub private_key: Option<Vec<u8>>,
+    pub client_ca: Option<Vec<u8>>,
+    pub token_file: Option<PathBuf>,
     pub port: u16,
+    pub results_directory: PathBuf,
+    pub key_file: Option<PathBuf>,
+    pub policy_file: Option<PathBuf>,
+    /// Time running tasks get to finish when the server shuts down
+    pub shutdown_timeout: Duration,
 }
 
 #[derive(Debug)]
@@ -42,26 +87,96 @@ pub struct Connection {
     pub metadata: Metadata,
 }
 
+/// A campaign performs the same ping from one or more clients, one after the other.
+/// Results of all its tasks are tagged with the campaign id, which is taken from the
+/// task id sequence so it can be subscribed to like any other task.
+#[derive(Debug)]
+struct Campaign {
+    ping: Ping,
+    destinations: Arc<Vec<Address>>,
+    pending_clients: Vec<u32>,
+    seed: u64,
+    grace_period: u32,
+    /// Identity of the user that scheduled the campaign
+    owner: String,
+}
+
+/// State of a task while the CLI uploads its destinations, with the role of the CLI user
+/// (and their identity, until the task starts)
+enum TaskUpload {
+    Waiting(Role, String),
+    Started {
+        task_id: u32,
+        tx: Sender<Task>,
+        role: Role,
+        destinations: usize,
+    },
+    Failed(String),
+}
+
 #[derive(Clone)]
 struct VerfploeterService {
     connection_manager: Arc<ConnectionManager>,
     subscription_list: Arc<RwLock<HashMap<u32, Vec<Sender<TaskResult>>>>>,
     current_task_id: Arc<Mutex<u32>>, // todo: replace this with AtomicU32 when it stabilizes
+    campaign_list: Arc<RwLock<HashMap<u32, Campaign>>>,
+    campaign_tasks: Arc<RwLock<HashMap<u32, u32>>>, // task id -> campaign id
+    clock_offsets: Arc<RwLock<HashMap<String, ClockOffset>>>, // client id -> clock offset
+    result_store: Arc<ResultStore>,
+    schedule_store: Arc<ScheduleStore>,
+    task_registry: Arc<TaskRegistry>,
+    finishing: Arc<Mutex<HashSet<u32>>>, // tasks whose final result is held back
+    key_file: Option<PathBuf>,
+    policy_file: Option<PathBuf>,
+    tokens: Option<Arc<auth::Tokens>>, // identities by access token
+    shutting_down: Arc<AtomicBool>,
     runtime: Arc<Runtime>,
 }
 
 impl Server {
     pub fn new(config: &ServerConfig) -> Server {
         let connection_manager = Arc::new(ConnectionManager::new());
+        let result_store = ResultStore::open(&config.results_directory)
+            .expect("Unable to open result store");
+        let schedule_store = ScheduleStore::open(config.results_directory.join("schedules"))
+            .expect("Unable to open schedule store");
+
+        // Continue numbering after the tasks already in the store, task id 0 is never used
+        // so a campaign id of 0 means a task is not part of a campaign
+        let first_task_id = result_store
+            .last_task_id()
+            .expect("Unable to read result store")
+            .map_or(1, |id| id + 1);
+        info!(
+            "storing results in {}, next task id: {}",
+            config.results_directory.display(),
+            first_task_id
+        );
+
         let s = VerfploeterService {
             connection_manager,
             subscription_list: Arc::new(RwLock::new(HashMap::new())),
-            current_task_id: Arc::new(Mutex::new(0)),
+            current_task_id: Arc::new(Mutex::new(first_task_id)),
+            campaign_list: Arc::new(RwLock::new(HashMap::new())),
+            campaign_tasks: Arc::new(RwLock::new(HashMap::new())),
+            clock_offsets: Arc::new(RwLock::new(HashMap::new())),
+            result_store: Arc::new(result_store),
+            schedule_store: Arc::new(schedule_store),
+            task_registry: Arc::new(TaskRegistry::new()),
+            finishing: Arc::new(Mutex::new(HashSet::new())),
+            key_file: config.key_file.clone(),
+            policy_file: config.policy_file.clone(),
+            tokens: config.token_file.as_ref().map(|token_file| {
+                Arc::new(auth::Tokens::load(token_file).expect("Unable to load token file"))
+            }),
+            shutting_down: Arc::new(AtomicBool::new(false)),
             runtime: Arc::new(Runtime::new().unwrap()),
         };
 
         if config.certificate.is_some() && config.private_key.is_some() {
             Server::create_secure_server(s, config)
+        } else if config.client_ca.is_some() {
+            panic!("Verifying client certificates requires a certificate and private key");
         } else {
             Server::create_insecure_server(s, config)
         }
@@ -70,42 +185,43 @@ impl Server {
     fn create_server_builder(s: VerfploeterService) -> ServerBuilder {
         let env = Arc::new(Environment::new(10));
 
-        let channel_args = ChannelBuilder::new(Arc::clone(&env))
-            .max_receive_message_len(100 * 1024 * 1024)
-            .max_send_message_len(100 * 1024 * 1024)
-            .build_args();
-
         let service = verfploeter_grpc::create_verfploeter(s);
 
-        ServerBuilder::new(env)
-            .channel_args(channel_args)
-            .register_service(service)
+        ServerBuilder::new(env).register_service(service)
     }
 
     fn create_secure_server(s: VerfploeterService, config: &ServerConfig) -> Server {
         info!("creating a secure server (SSL enabled)");
         // Setup credentials
-        let credentials = ServerCredentialsBuilder::new()
-            .add_cert(
-                config.certificate.clone().unwrap(),
-                config.private_key.clone().unwrap(),
-            )
-            .build();
+        let mut credentials = ServerCredentialsBuilder::new().add_cert(
+            config.certificate.clone().unwrap(),
+            config.private_key.clone().unwrap(),
+        );
+        // Clients and CLI users must present a certificate signed by the client CA
+        if let Some(client_ca) = config.client_ca.clone() {
+            info!("verifying client certificates (mutual TLS)");
+            credentials = credentials.root_cert(client_ca, true);
+        }
+        let credentials = credentials.build();
         Server {
-            grpc_server: Server::create_server_builder(s)
+            grpc_server: Server::create_server_builder(s.clone())
                 .bind_secure("0.0.0.0", config.port, credentials)
                 .build()
                 .unwrap(),
+            service: s,
+            shutdown_timeout: config.shutdown_timeout,
         }
     }
 
     fn create_insecure_server(s: VerfploeterService, config: &ServerConfig) -> Server {
         info!("creating an insecure server (SSL disabled)");
         Server {
-            grpc_server: Server::create_server_builder(s)
+            grpc_server: Server::create_server_builder(s.clone())
                 .bind("0.0.0.0", config.port)
                 .build()
                 .unwrap(),
+            service: s,
+            shutdown_timeout: config.shutdown_timeout,
         }
     }
 
@@ -115,10 +231,715 @@ impl Server {
         for &(ref host, port) in self.grpc_server.bind_addrs() {
             info!("Listening on {}:{}", host, port);
         }
+
+        thread::Builder::new()
+            .name("scheduler".to_string())
+            .spawn({
+                let service = self.service.clone();
+                move || service.run_schedules()
+            })
+            .expect("Unable to start scheduler");
+    }
+
+    /// Stops the server: new tasks are refused, running tasks get until the shutdown
+    /// timeout to finish, then clients and CLI users are told the server is going away
+    /// and the results received so far are written to disk
+    pub fn shutdown(&mut self) {
+        let service = &self.service;
+        service.shutting_down.store(true, Ordering::SeqCst);
+
+        let deadline = Instant::now() + self.shutdown_timeout;
+        let mut unfinished = service.task_registry.unfinished();
+        if !unfinished.is_empty() {
+            info!(
+                "waiting up to {} seconds for {} running task(s) to finish",
+                self.shutdown_timeout.as_secs(),
+                unfinished.len()
+            );
+        }
+        while !unfinished.is_empty() && Instant::now() < deadline {
+            thread::sleep(Duration::from_secs(1));
+            unfinished = service.task_registry.unfinished();
+        }
+        if !unfinished.is_empty() {
+            warn!("shutting down with unfinished tasks {:?}", unfinished);
+        }
+
+        service.finish_held_tasks();
+        service.disconnect_clients();
+        service.disconnect_all_subscribers();
+
+        // Wait for the calls to end now their streams are closed, the calls that remain
+        // are cancelled when the server is dropped
+        let (tx, rx) = mpsc::channel();
+        service
+            .runtime
+            .executor()
+            .spawn(self.grpc_server.shutdown().then(move |_| {
+                tx.send(()).ok();
+                Ok(())
+            }));
+        if rx.recv_timeout(CALL_SHUTDOWN_TIMEOUT).is_err() {
+            warn!("cancelling calls that did not end");
+        }
+
+        if let Err(e) = service.result_store.sync() {
+            error!("unable to write results to disk: {}", e);
+        }
     }
 }
 
 impl VerfploeterService {
+    fn next_task_id(&self) -> u32 {
+        let mut current_task_id = self.current_task_id.lock().unwrap();
+        let task_id = *current_task_id;
+        current_task_id.add_assign(1);
+        task_id
+    }
+
+    /// Sends the current keys to all clients, so they can verify replies to the next task,
+    /// and returns the key that task is signed with
+    fn distribute_keys(&self) -> Result<Key, String> {
+        let keys = keys::load_keys(self.key_file.as_ref().map(|p| p.as_path()))?;
+        for tx in self.connection_manager.get_all_client_tx() {
+            let mut t = Task::new();
+            t.set_keys(keys.clone());
+            if tx.send(t).wait().is_err() {
+                warn!("unable to send keys to client");
+            }
+        }
+        Ok(keys::signing_key(&keys).clone())
+    }
+
+    /// Sends the campaign ping to the next pending client that is still connected,
+    /// returns the task id or None when the campaign has no clients left
+    fn next_campaign_task(&self, campaign_id: u32) -> Option<u32> {
+        loop {
+            let (connection_id, mut ping, destinations, seed, grace_period, owner) = {
+                let mut campaigns = self.campaign_list.write().unwrap();
+                let campaign = campaigns.get_mut(&campaign_id)?;
+                if campaign.pending_clients.is_empty() {
+                    campaigns.remove(&campaign_id);
+                    return None;
+                }
+                (
+                    campaign.pending_clients.remove(0),
+                    campaign.ping.clone(),
+                    campaign.destinations.clone(),
+                    campaign.seed,
+                    campaign.grace_period,
+                    campaign.owner.clone(),
+                )
+            };
+
+            let (tx, client) = match (
+                self.connection_manager.get_client_tx_by_idx(connection_id),
+                self.connection_manager.get_client_by_idx(connection_id),
+            ) {
+                (Some(tx), Some(client)) => (tx, client),
+                _ => {
+                    warn!(
+                        "client {} left before its turn in campaign {}",
+                        connection_id, campaign_id
+                    );
+                    continue;
+                }
+            };
+
+            match self.distribute_keys() {
+                Ok(key) => ping.set_key(key),
+                Err(e) => {
+                    error!("unable to continue campaign {}: {}", campaign_id, e);
+                    self.campaign_list.write().unwrap().remove(&campaign_id);
+                    return None;
+                }
+            }
+
+            let task_id = self.next_task_id();
+            self.task_registry
+                .register(task_id, campaign_id, client, 0, ping.get_rate(), seed);
+            self.task_registry.set_owner(task_id, owner);
+            let mut t = Task::new();
+            t.set_task_id(task_id);
+            t.set_seed(seed);
+            t.set_grace_period(grace_period);
+            t.set_ping(ping);
+            self.campaign_tasks
+                .write()
+                .unwrap()
+                .insert(task_id, campaign_id);
+
+            debug!(
+                "sending task {} of campaign {} to client {}",
+                task_id, campaign_id, connection_id
+            );
+            if tx.clone().send(t).wait().is_ok() {
+                let sent = destinations.chunks(DESTINATION_CHUNK_SIZE).all(|chunk| {
+                    self.send_destinations(task_id, &tx, RepeatedField::from_slice(chunk), false)
+                }) && self.send_destinations(task_id, &tx, RepeatedField::new(), true);
+                if sent {
+                    return Some(task_id);
+                }
+                // The client left while receiving the destinations
+                self.task_registry.finish(task_id);
+                self.finish_task(task_id);
+            }
+            self.campaign_tasks.write().unwrap().remove(&task_id);
+            self.task_registry.unregister(task_id);
+            warn!("unable to send task {} to client {}", task_id, connection_id);
+        }
+    }
+
+    /// Last clock offset reported by a client
+    fn clock_offset(&self, client_id: &str) -> Option<ClockOffset> {
+        self.clock_offsets.read().unwrap().get(client_id).cloned()
+    }
+
+    /// Identity of the client or CLI user that made a call, taken from its access token.
+    /// None when the server has no token file or the token is unknown.
+    fn peer_identity(&self, ctx: &RpcContext) -> Option<String> {
+        self.tokens
+            .as_ref()
+            .and_then(|tokens| auth::peer_identity(ctx, tokens))
+    }
+
+    /// With access tokens, the identity of the client that made a call, which is its
+    /// client id and hostname. None when the server has no token file.
+    fn client_identity(&self, ctx: &RpcContext) -> Result<Option<String>, String> {
+        if self.tokens.is_none() {
+            return Ok(None);
+        }
+        self.peer_identity(ctx)
+            .map(Some)
+            .ok_or_else(|| ACCESS_TOKEN_REQUIRED.to_string())
+    }
+
+    /// With access tokens, checks that the client that made a call performs the task
+    fn check_performer(&self, ctx: &RpcContext, task_id: u32) -> Result<(), String> {
+        let performer = self
+            .task_registry
+            .get_status(task_id)
+            .map(|status| status.get_client().get_metadata().id().to_string());
+        match self.client_identity(ctx)? {
+            Some(ref identity) if performer.as_ref() != Some(identity) => {
+                Err(format!("task {} is not performed by {}", task_id, identity))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the user that made a call may cancel a task or campaign: the user that
+    /// scheduled it can, and so can users whose role may use every client performing it
+    fn check_cancel(&self, ctx: &RpcContext, task_id: u32) -> Result<(), String> {
+        let campaign = self
+            .campaign_list
+            .read()
+            .unwrap()
+            .get(&task_id)
+            .map(|c| (c.owner.clone(), c.pending_clients.clone()));
+        let running = match campaign {
+            Some(_) => self.task_registry.get_running_campaign_task(task_id),
+            None => Some(task_id),
+        }
+        .and_then(|id| self.task_registry.get_status(id));
+
+        let mut clients = running
+            .iter()
+            .map(|status| status.get_client().get_metadata().id().to_string())
+            .collect::<Vec<String>>();
+        let owner = match campaign {
+            Some((owner, pending_clients)) => {
+                clients.extend(pending_clients.into_iter().filter_map(|id| {
+                    let client = self.connection_manager.get_client_by_idx(id)?;
+                    Some(client.get_metadata().id().to_string())
+                }));
+                owner
+            }
+            None => running.map_or(String::new(), |s| s.get_owner().to_string()),
+        };
+
+        let identity = self.peer_identity(ctx);
+        if identity.as_ref().map_or(false, |identity| *identity == owner) {
+            return Ok(());
+        }
+        let role = self.user_role(identity)?;
+        clients
+            .iter()
+            .try_for_each(|client_id| role.check_client(client_id))
+    }
+
+    fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
+    /// Role of the CLI user with the given identity
+    fn user_role(&self, identity: Option<String>) -> Result<Role, String> {
+        policy::load_role(
+            self.policy_file.as_ref().map(|p| p.as_path()),
+            identity.as_ref().map(|i| i.as_str()),
+        )
+    }
+
+    /// Sends a probing task, without its destinations, to the client it names, and
+    /// returns the task id and the channel to the client to send the destinations to
+    fn start_task(
+        &self,
+        mut req: ScheduleTask,
+        role: &Role,
+        owner: String,
+    ) -> Result<(u32, Sender<Task>), String> {
+        if self.is_shutting_down() {
+            return Err(SHUTTING_DOWN.to_string());
+        }
+        if !req.has_ping() && !req.has_tcp_syn() && !req.has_dns() {
+            return Err("request does not contain a task".to_string());
+        }
+        let requested_rate = if req.has_tcp_syn() {
+            req.get_tcp_syn().get_rate()
+        } else if req.has_dns() {
+            req.get_dns().get_rate()
+        } else {
+            req.get_ping().get_rate()
+        };
+        let rate = role.check_rate(requested_rate)?;
+
+        let (tx, client) = self
+            .connection_id(req.get_client())
+            .and_then(|id| {
+                Some((
+                    self.connection_manager.get_client_tx_by_idx(id)?,
+                    self.connection_manager.get_client_by_idx(id)?,
+                ))
+            })
+            .ok_or_else(|| "client does not exist".to_string())?;
+        role.check_client(client.get_metadata().id())?;
+        let key = self.distribute_keys()?;
+
+        // obtain task id
+        let task_id = self.next_task_id();
+        let mut t = Task::new();
+        t.set_task_id(task_id);
+        let seed = match req.get_seed() {
+            0 => random_seed(),
+            seed => seed,
+        };
+        t.set_seed(seed);
+        t.set_grace_period(req.get_grace_period());
+
+        if req.has_tcp_syn() {
+            let mut tcp_syn = req.take_tcp_syn();
+            tcp_syn.set_key(key);
+            tcp_syn.set_rate(rate);
+            t.set_tcp_syn(tcp_syn);
+        } else if req.has_dns() {
+            let mut dns = req.take_dns();
+            dns.set_key(key);
+            dns.set_rate(rate);
+            t.set_dns(dns);
+        } else {
+            let mut ping = req.take_ping();
+            ping.set_key(key);
+            ping.set_rate(rate);
+            t.set_ping(ping);
+        }
+        self.task_registry
+            .register(task_id, 0, client, 0, rate, seed);
+        self.task_registry.set_owner(task_id, owner);
+
+        debug!("sending task to client");
+        if tx.clone().send(t).wait().is_err() {
+            self.task_registry.unregister(task_id);
+            self.finish_task(task_id);
+            return Err("client exists, but was unable to send task".to_string());
+        }
+        debug!("task sent");
+        Ok((task_id, tx))
+    }
+
+    /// Sends a chunk of destinations of a task to its client, the last chunk tells the
+    /// client that no more destinations follow
+    fn send_destinations(
+        &self,
+        task_id: u32,
+        tx: &Sender<Task>,
+        addresses: RepeatedField<Address>,
+        last: bool,
+    ) -> bool {
+        self.task_registry
+            .add_destinations(task_id, addresses.len() as u32);
+        let mut destinations = Destinations::new();
+        destinations.set_addresses(addresses);
+        destinations.set_last(last);
+        let mut t = Task::new();
+        t.set_task_id(task_id);
+        t.set_destinations(destinations);
+        tx.clone().send(t).wait().is_ok()
+    }
+
+    /// Handles the next message of a task upload, the first message describes the task,
+    /// every message can carry a chunk of destinations, which is forwarded to the client
+    /// right away so it can start probing before the upload is complete
+    fn upload_task(&self, upload: TaskUpload, request: grpcio::Result<ScheduleTask>) -> TaskUpload {
+        match (upload, request) {
+            (TaskUpload::Failed(e), _) => TaskUpload::Failed(e),
+            (TaskUpload::Waiting(..), Err(e)) => TaskUpload::Failed(e.to_string()),
+            (TaskUpload::Started { task_id, tx, .. }, Err(e)) => {
+                warn!("upload of task {} failed: {}", task_id, e);
+                self.abort_task(task_id, &tx);
+                TaskUpload::Failed(e.to_string())
+            }
+            (TaskUpload::Waiting(role, owner), Ok(mut req)) => {
+                let addresses = req.take_destinations().take_addresses();
+                match self.start_task(req, &role, owner) {
+                    Ok((task_id, tx)) => {
+                        self.upload_destinations(task_id, tx, role, 0, addresses)
+                    }
+                    Err(e) => TaskUpload::Failed(e),
+                }
+            }
+            (
+                TaskUpload::Started {
+                    task_id,
+                    tx,
+                    role,
+                    destinations,
+                },
+                Ok(mut req),
+            ) => {
+                let addresses = req.take_destinations().take_addresses();
+                self.upload_destinations(task_id, tx, role, destinations, addresses)
+            }
+        }
+    }
+
+    /// Forwards a chunk of destinations, unless the task then exceeds the maximum number
+    /// of destinations of the role, in which case it is aborted
+    fn upload_destinations(
+        &self,
+        task_id: u32,
+        tx: Sender<Task>,
+        role: Role,
+        destinations: usize,
+        addresses: RepeatedField<Address>,
+    ) -> TaskUpload {
+        let destinations = destinations + addresses.len();
+        if let Err(e) = role.check_destinations(destinations) {
+            warn!("aborting task {}: {}", task_id, e);
+            self.abort_task(task_id, &tx);
+            TaskUpload::Failed(e)
+        } else if addresses.is_empty() || self.send_destinations(task_id, &tx, addresses, false) {
+            TaskUpload::Started {
+                task_id,
+                tx,
+                role,
+                destinations,
+            }
+        } else {
+            warn!("client of task {} left while receiving destinations", task_id);
+            self.abort_task(task_id, &tx);
+            TaskUpload::Failed("client disconnected while receiving destinations".to_string())
+        }
+    }
+
+    /// Tells the client of an uploaded task that all its destinations have been sent
+    fn complete_upload(&self, upload: TaskUpload) -> Ack {
+        let mut ack = Ack::new();
+        ack.set_success(false);
+        match upload {
+            TaskUpload::Started { task_id, tx, .. } => {
+                ack.set_task_id(task_id);
+                if self.send_destinations(task_id, &tx, RepeatedField::new(), true) {
+                    ack.set_success(true);
+                } else {
+                    self.abort_task(task_id, &tx);
+                    ack.set_error_message(
+                        "client disconnected while receiving destinations".to_string(),
+                    );
+                }
+            }
+            TaskUpload::Waiting(..) => {
+                ack.set_error_message("request does not contain a task".to_string())
+            }
+            TaskUpload::Failed(e) => ack.set_error_message(e),
+        }
+        ack
+    }
+
+    /// Ends a task of which not all destinations could be uploaded
+    fn abort_task(&self, task_id: u32, tx: &Sender<Task>) {
+        self.task_registry.cancel(task_id);
+        let mut t = Task::new();
+        t.set_task_id(task_id);
+        t.set_cancel(Empty::new());
+        let delivered = tx.clone().send(t).wait().is_ok()
+            && self.send_destinations(task_id, tx, RepeatedField::new(), true);
+        if !delivered {
+            // The client is gone, so it will not report the task as finished
+            self.task_registry.finish(task_id);
+            self.finish_task(task_id);
+        }
+    }
+
+    /// Starts a campaign once all its destinations have been uploaded
+    fn start_campaign(
+        &self,
+        mut req: ScheduleCampaign,
+        destinations: Vec<Address>,
+        role: &Role,
+        owner: String,
+    ) -> Ack {
+        let mut ack = Ack::new();
+        ack.set_success(false);
+        if self.is_shutting_down() {
+            ack.set_error_message(SHUTTING_DOWN.to_string());
+            return ack;
+        }
+        let rate = role
+            .check_rate(req.get_ping().get_rate())
+            .and_then(|rate| role.check_destinations(destinations.len()).map(|_| rate));
+        match rate {
+            Ok(rate) => req.mut_ping().set_rate(rate),
+            Err(e) => {
+                ack.set_error_message(e);
+                return ack;
+            }
+        }
+
+        // Either rotate through all connected clients the role may use, or use the single
+        // requested client
+        let pending_clients = if req.get_rotate() {
+            self.connection_manager
+                .get_connection_ids()
+                .into_iter()
+                .filter(|id| {
+                    self.connection_manager
+                        .get_client_by_idx(*id)
+                        .map_or(false, |c| role.allows_client(c.get_metadata().id()))
+                })
+                .collect::<Vec<u32>>()
+        } else if !req.get_client().get_metadata().hostname.is_empty() {
+            self.connection_manager
+                .get_connection_id_by_name(&req.get_client().get_metadata().hostname)
+                .into_iter()
+                .collect()
+        } else {
+            let index = req.get_client().index;
+            self.connection_manager
+                .get_client_tx_by_idx(index)
+                .map(|_| index)
+                .into_iter()
+                .collect()
+        };
+
+        let denied_client = pending_clients.iter().find_map(|id| {
+            let client = self.connection_manager.get_client_by_idx(*id)?;
+            role.check_client(client.get_metadata().id()).err()
+        });
+        if let Some(e) = denied_client {
+            ack.set_error_message(e);
+        } else if pending_clients.is_empty() {
+            ack.set_error_message("client does not exist".to_string());
+        } else {
+            let campaign_id = self.next_task_id();
+            info!(
+                "starting campaign {} from {} client(s) to {} destinations",
+                campaign_id,
+                pending_clients.len(),
+                destinations.len()
+            );
+            self.campaign_list.write().unwrap().insert(
+                campaign_id,
+                Campaign {
+                    ping: req.take_ping(),
+                    destinations: Arc::new(destinations),
+                    pending_clients,
+                    seed: match req.get_seed() {
+                        0 => random_seed(),
+                        seed => seed,
+                    },
+                    grace_period: req.get_grace_period(),
+                    owner,
+                },
+            );
+            ack.set_task_id(campaign_id);
+            if self.next_campaign_task(campaign_id).is_some() {
+                ack.set_success(true);
+            } else {
+                ack.set_error_message("unable to send task to any client".to_string());
+                self.finish_task(campaign_id);
+            }
+        }
+        ack
+    }
+
+    /// Gets the connection of a client, either by hostname (if provided) or by index
+    fn connection_id(&self, client: &Client) -> Option<u32> {
+        if !client.get_metadata().hostname.is_empty() {
+            self.connection_manager
+                .get_connection_id_by_name(&client.get_metadata().hostname)
+        } else {
+            Some(client.index)
+        }
+    }
+
+    /// Adds a schedule once all its destinations have been uploaded, the role of the user
+    /// is checked now, and again for every run
+    fn create_schedule(
+        &self,
+        mut req: Schedule,
+        destinations: Vec<Address>,
+        role: &Role,
+        identity: Option<String>,
+    ) -> Ack {
+        let mut ack = Ack::new();
+        ack.set_success(false);
+        let task = req.get_task();
+        let requested_rate = if task.has_tcp_syn() {
+            task.get_tcp_syn().get_rate()
+        } else if task.has_dns() {
+            task.get_dns().get_rate()
+        } else {
+            task.get_ping().get_rate()
+        };
+        // The client is checked by its id like start_task does, a client that is not connected
+        // now by the name it is scheduled for
+        let client_id = self
+            .connection_id(task.get_client())
+            .and_then(|id| self.connection_manager.get_client_by_idx(id))
+            .map_or_else(
+                || task.get_client().get_metadata().hostname.clone(),
+                |client| client.get_metadata().id().to_string(),
+            );
+        let checked = if !task.has_ping() && !task.has_tcp_syn() && !task.has_dns() {
+            Err("request does not contain a task".to_string())
+        } else {
+            role.check_client(&client_id)
+                .and_then(|_| role.check_rate(requested_rate))
+                .and_then(|_| role.check_destinations(destinations.len()))
+                .and_then(|_| schedule::validate(&req))
+        };
+        if let Err(e) = checked {
+            ack.set_error_message(e);
+            return ack;
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        if req.get_start_at() == 0 {
+            req.set_start_at(now);
+        }
+        match schedule::next_run(&req, now.saturating_sub(1)) {
+            Some(next_run) => req.set_next_run(next_run),
+            None => {
+                ack.set_error_message("start time has passed".to_string());
+                return ack;
+            }
+        }
+        req.set_owner(identity.unwrap_or_default());
+        req.set_destinations(destinations.len() as u32);
+        req.mut_task()
+            .mut_destinations()
+            .set_addresses(RepeatedField::from_vec(destinations));
+        req.clear_runs();
+
+        match self.schedule_store.add(req) {
+            Ok(schedule_id) => {
+                info!("added schedule {}", schedule_id);
+                ack.set_task_id(schedule_id);
+                ack.set_success(true);
+            }
+            Err(e) => {
+                error!("unable to store schedule: {}", e);
+                ack.set_error_message("unable to store schedule".to_string());
+            }
+        }
+        ack
+    }
+
+    /// Starts the runs of schedules that are due, until the server shuts down
+    fn run_schedules(&self) {
+        while !self.is_shutting_down() {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            for schedule in self.schedule_store.due(now) {
+                let schedule_id = schedule.get_schedule_id();
+                let run = self.start_schedule_run(schedule, now);
+                if let Err(e) = self.schedule_store.record_run(schedule_id, run) {
+                    error!("unable to record run of schedule {}: {}", schedule_id, e);
+                }
+            }
+            thread::sleep(SCHEDULER_INTERVAL);
+        }
+    }
+
+    /// Starts a run of a schedule through the same path as a task uploaded by a CLI user,
+    /// under the role of the user that added the schedule. A run is skipped while the
+    /// previous run is still going.
+    fn start_schedule_run(&self, mut schedule: Schedule, now: u64) -> ScheduleRun {
+        let schedule_id = schedule.get_schedule_id();
+        let mut run = ScheduleRun::new();
+        run.set_start_time(now);
+
+        let previous_task_id = schedule.get_runs().last().map_or(0, |r| r.get_task_id());
+        let previous_client = self
+            .task_registry
+            .get_status(previous_task_id)
+            .map(|status| status.get_client().get_index());
+        if let Some(connection_id) = previous_client {
+            // A task whose client has left will not be reported finished
+            let connected = self.connection_manager.get_client_tx_by_idx(connection_id);
+            if connected.is_none()
+                && self.task_registry.unfinished_by(connection_id).contains(&previous_task_id)
+            {
+                self.complete_task(previous_task_id);
+            }
+        }
+        if previous_task_id != 0 && self.task_registry.unfinished().contains(&previous_task_id) {
+            warn!(
+                "skipping run of schedule {}, task {} is still running",
+                schedule_id, previous_task_id
+            );
+            run.set_error_message(format!("skipped, task {} was still running", previous_task_id));
+            return run;
+        }
+
+        let identity = match schedule.get_owner() {
+            "" => None,
+            owner => Some(owner.to_string()),
+        };
+        let mut upload = match self.user_role(identity) {
+            Ok(role) => TaskUpload::Waiting(role, schedule.get_owner().to_string()),
+            Err(e) => TaskUpload::Failed(e),
+        };
+        let mut task = schedule.take_task();
+        let destinations = task.take_destinations().take_addresses().into_vec();
+        upload = self.upload_task(upload, Ok(task));
+        for chunk in destinations.chunks(DESTINATION_CHUNK_SIZE) {
+            let mut req = ScheduleTask::new();
+            req.mut_destinations()
+                .set_addresses(RepeatedField::from_slice(chunk));
+            upload = self.upload_task(upload, Ok(req));
+        }
+
+        let ack = self.complete_upload(upload);
+        if ack.get_success() {
+            info!("started task {} for schedule {}", ack.get_task_id(), schedule_id);
+            run.set_task_id(ack.get_task_id());
+        } else {
+            warn!(
+                "unable to start run of schedule {}: {}",
+                schedule_id,
+                ack.get_error_message()
+            );
+            run.set_error_message(ack.get_error_message().to_string());
+        }
+        run
+    }
+
     fn register_subscriber(&mut self, task_id: u32, tx: Sender<TaskResult>) {
         debug!("registering subscriber for task id {}", task_id);
         let mut list = self.subscription_list.write().unwrap();
@@ -129,47 +950,243 @@ impl VerfploeterService {
         }
     }
 
-    fn get_subscribers(&self, task_id: u32) -> Option<Vec<Sender<TaskResult>>> {
-        let list = self.subscription_list.read().unwrap();
-        if let Some(subscribers) = list.get(&task_id) {
-            debug!(
-                "returning {} subscribers for task {}",
-                subscribers.len(),
-                task_id
-            );
-            return Some(subscribers.to_vec());
+    /// Ends the results of a task with a TaskResult that has is_finished set and carries
+    /// the totals of the task, marks the task as finished in the store, and closes the
+    /// streams of its subscribers
+    fn finish_task(&self, task_id: u32) {
+        if self.result_store.is_finished(task_id) {
+            return;
+        }
+        let campaign_id = self.campaign_tasks.read().unwrap().get(&task_id).cloned();
+        if let (Some(campaign_id), Some(status)) =
+            (campaign_id, self.task_registry.get_status(task_id))
+        {
+            self.task_registry
+                .add_probes(campaign_id, status.get_destinations_sent());
+        }
+
+        let mut final_result = TaskResult::new();
+        final_result.set_task_id(task_id);
+        final_result.set_is_finished(true);
+        final_result.set_summary(self.task_registry.summary(task_id));
+
+        // Finish the task in the store and take its subscribers while holding the
+        // subscription list, so get_results either reads the final result from the
+        // store or receives it live (see send_result)
+        let subscribers = {
+            let mut list = self.subscription_list.write().unwrap();
+            if let Err(e) = self.result_store.append(task_id, &final_result) {
+                error!("unable to store final result of task {}: {}", task_id, e);
+            }
+            if let Err(e) = self.result_store.finish(task_id) {
+                error!("unable to mark task {} as finished: {}", task_id, e);
+            }
+            list.remove(&task_id).unwrap_or_default()
+        };
+        self.task_registry.remove_tally(task_id);
+        debug!(
+            "disconnecting {} subscribers for task {}",
+            subscribers.len(),
+            task_id
+        );
+        subscribers
+            .into_iter()
+            .map(|s| s.send(final_result.clone()).wait())
+            .for_each(drop);
+    }
+
+    /// Finishes a task its client is done with, and moves its campaign on to the next client,
+    /// or ends the campaign when all clients had their turn. A task is completed only once,
+    /// a client may still report a task finished after its connection was lost.
+    fn complete_task(&self, task_id: u32) {
+        if !self.task_registry.unfinished().contains(&task_id) {
+            return;
+        }
+        self.task_registry.finish(task_id);
+        self.finish_task_later(task_id);
+
+        let campaign_id = self.campaign_tasks.read().unwrap().get(&task_id).cloned();
+        if let Some(campaign_id) = campaign_id {
+            if self.is_shutting_down() {
+                warn!("not continuing campaign {}, {}", campaign_id, SHUTTING_DOWN);
+            } else if self.next_campaign_task(campaign_id).is_none() {
+                info!("campaign {} finished", campaign_id);
+                self.finish_task_later(campaign_id);
+            }
+        }
+    }
+
+    /// Unregisters a client whose connection ended, and completes the tasks it was
+    /// performing, as it will not report them finished anymore (which would keep schedules
+    /// skipping their runs)
+    fn client_disconnected(&self, connection_id: u32) {
+        self.connection_manager.unregister_connection(connection_id);
+        for task_id in self.task_registry.unfinished_by(connection_id) {
+            warn!("client of task {} disconnected, finishing the task", task_id);
+            self.complete_task(task_id);
+        }
+    }
+
+    /// Finishes a task after FINAL_RESULT_DELAY, so the results its client sends after
+    /// reporting the task finished are stored (and counted for its campaign) before the
+    /// final result
+    fn finish_task_later(&self, task_id: u32) {
+        self.finishing.lock().unwrap().insert(task_id);
+        let service = self.clone();
+        thread::spawn(move || {
+            thread::sleep(FINAL_RESULT_DELAY);
+            if service.finishing.lock().unwrap().remove(&task_id) {
+                service.finish_held_task(task_id);
+            }
+        });
+    }
+
+    fn finish_held_task(&self, task_id: u32) {
+        self.finish_task(task_id);
+        self.campaign_tasks.write().unwrap().remove(&task_id);
+    }
+
+    /// Finishes the tasks whose final result is held back right away
+    fn finish_held_tasks(&self) {
+        let task_ids = self.finishing.lock().unwrap().drain().collect::<Vec<u32>>();
+        for task_id in task_ids {
+            self.finish_held_task(task_id);
         }
-        None
     }
 
-    fn disconnect_subscribers(&self, task_id: u32) {
+    /// Closes the result streams of all CLI users, see forward_results
+    fn disconnect_all_subscribers(&self) {
         let mut list = self.subscription_list.write().unwrap();
-        if let Some(subscribers) = list.get(&task_id) {
-            debug!(
-                "disconnecting {} subscribers for task {}",
-                subscribers.len(),
-                task_id
-            );
-            subscribers.iter().for_each(drop);
-            list.remove(&task_id);
+        debug!("disconnecting subscribers for {} tasks", list.len());
+        list.clear();
+    }
+
+    /// Tells all clients the server is going away, and closes their task streams
+    fn disconnect_clients(&self) {
+        let connections = self
+            .connection_manager
+            .connections
+            .write()
+            .unwrap()
+            .drain()
+            .collect::<Vec<(u32, Connection)>>();
+        info!("disconnecting {} clients", connections.len());
+        for (_, connection) in connections {
+            let mut t = Task::new();
+            t.set_shutdown(Empty::new());
+            if connection.channel.send(t).wait().is_err() {
+                debug!("client {} already left", connection.metadata.id());
+            }
         }
     }
 }
 
+/// Forwards results to the result stream of a CLI user. When the results were interrupted
+/// by the server shutting down, the stream fails as unavailable instead of completing, so
+/// the CLI resumes fetching the results once the server is back.
+fn forward_results<S, F>(
+    results: S,
+    sink: ServerStreamingSink<TaskResult>,
+    interrupted: F,
+) -> impl Future<Item = (), Error = ()>
+where
+    S: Stream<Item = TaskResult, Error = ()>,
+    F: FnOnce() -> bool,
+{
+    results
+        .fold(sink, |sink, result| {
+            sink.send((result, grpcio::WriteFlags::default()))
+                .map_err(|e| error!("failure in result sink: {}", e))
+        })
+        .and_then(move |mut sink| {
+            if interrupted() {
+                let status =
+                    RpcStatus::new(RpcStatusCode::Unavailable, Some(SHUTTING_DOWN.to_string()));
+                future::Either::A(
+                    sink.fail(status)
+                        .map_err(|e| error!("failure in result sink: {}", e)),
+                )
+            } else {
+                future::Either::B(
+                    future::poll_fn(move || sink.close())
+                        .map_err(|e| error!("failure in result sink: {}", e)),
+                )
+            }
+        })
+}
+
 impl Verfploeter for VerfploeterService {
-    fn connect(&mut self, _ctx: RpcContext, metadata: Metadata, sink: ServerStreamingSink<Task>) {
+    fn connect(&mut self, ctx: RpcContext, mut metadata: Metadata, sink: ServerStreamingSink<Task>) {
+        if self.is_shutting_down() {
+            ctx.spawn(
+                sink.fail(RpcStatus::new(
+                    RpcStatusCode::Unavailable,
+                    Some(SHUTTING_DOWN.to_string()),
+                ))
+                .map_err(|_| ()),
+            );
+            return;
+        }
+
+        // With access tokens the hostname and client id of a client are the identity of its
+        // token
+        if self.tokens.is_some() {
+            match self.peer_identity(&ctx) {
+                Some(identity) => {
+                    if identity != metadata.get_hostname() {
+                        info!(
+                            "client reporting hostname {} connected as {}",
+                            metadata.get_hostname(),
+                            identity
+                        );
+                    }
+                    metadata.set_client_id(identity.clone());
+                    metadata.set_hostname(identity);
+                }
+                None => {
+                    warn!("rejecting client {} without a valid access token", ctx.peer());
+                    ctx.spawn(
+                        sink.fail(RpcStatus::new(
+                            RpcStatusCode::Unauthenticated,
+                            Some(ACCESS_TOKEN_REQUIRED.to_string()),
+                        ))
+                        .map_err(|_| ()),
+                    );
+                    return;
+                }
+            }
+        }
+
+        // Clients that do not report a client id are identified by their hostname
+        if metadata.get_client_id().is_empty() {
+            let hostname = metadata.get_hostname().to_string();
+            metadata.set_client_id(hostname);
+        }
+
         let (tx, rx) = channel(1);
 
         let connection_manager = self.connection_manager.clone();
         let connection_id = connection_manager.generate_connection_id();
         let hostname = metadata.get_hostname().to_string();
-        connection_manager.register_connection(
+        let client_id = metadata.get_client_id().to_string();
+        let registered = connection_manager.register_connection(
             connection_id,
             Connection {
                 metadata,
                 channel: tx.clone(),
             },
         );
+        if !registered {
+            warn!("rejecting client {}, a client with that id is connected already", client_id);
+            ctx.spawn(
+                sink.fail(RpcStatus::new(
+                    RpcStatusCode::AlreadyExists,
+                    Some(format!("client {} is connected already", client_id)),
+                ))
+                .map_err(|_| ()),
+            );
+            return;
+        }
 
         // Forward all tasks from the channel to the sink, and unregister from the connection
         // manager on error or completion.
@@ -182,27 +1199,41 @@ impl Verfploeter for VerfploeterService {
                 }
             }))
             .map({
-                let cm = self.connection_manager.clone();
+                let service = self.clone();
                 let hostname = hostname.clone();
                 move |_| {
-                    cm.unregister_connection(connection_id);
+                    service.client_disconnected(connection_id);
                     debug!("exiting task forwarder ({})", hostname);
                 }
             })
             .map_err({
-                let cm = self.connection_manager.clone();
+                let service = self.clone();
                 let hostname = hostname.clone();
                 move |_| {
-                    cm.unregister_connection(connection_id);
+                    service.client_disconnected(connection_id);
                     debug!("exiting task forwarder ({}), with error", hostname);
                 }
             });
         self.runtime.executor().spawn(f);
 
-        // Send keepalives
+        // Send the current keys, so replies to running tasks can be verified
+        match keys::load_keys(self.key_file.as_ref().map(|p| p.as_path())) {
+            Ok(keys) => {
+                let mut t = Task::new();
+                t.set_keys(keys);
+                self.runtime
+                    .executor()
+                    .spawn(tx.clone().send(t).map(|_| ()).map_err(|_| ()));
+            }
+            Err(e) => error!("unable to send keys to {}: {}", hostname, e),
+        }
+
+        // Send keepalives, until the server shuts down and closes the task stream
+        let shutting_down = self.shutting_down.clone();
         self.runtime.executor().spawn(
             Interval::new_interval(Duration::from_secs(5))
                 .map_err(|_| ())
+                .take_while(move |_| Ok(!shutting_down.load(Ordering::SeqCst)))
                 .map(|_| {
                     let mut t = Task::new();
                     t.set_empty(Empty::new());
@@ -214,53 +1245,88 @@ impl Verfploeter for VerfploeterService {
         );
     }
 
-    fn do_task(&mut self, ctx: RpcContext, mut req: ScheduleTask, sink: UnarySink<Ack>) {
+    fn do_task(
+        &mut self,
+        ctx: RpcContext,
+        stream: RequestStream<ScheduleTask>,
+        sink: ClientStreamingSink<Ack>,
+    ) {
         debug!("received do_task request");
-        let mut ack = Ack::new();
-        ack.set_success(false);
-
-        // Handle a ping task
-        if req.has_ping() {
-            let tx=
-            // Get a connection to the client, either by hostname (if provided) or by index
-            if !req.get_client().get_metadata().hostname.is_empty() {
-                self
-                    .connection_manager
-                    .get_client_tx_by_hostname(&req.get_client().get_metadata().hostname)
-            } else {
-                self
-                    .connection_manager
-                    .get_client_tx_by_idx(req.get_client().index)
-            };
-
-            if let Some(tx) = tx {
-                let mut t = Task::new();
-
-                // obtain task id
-                let task_id: u32;
-                {
-                    let mut current_task_id = self.current_task_id.lock().unwrap();
-                    task_id = *current_task_id;
-                    current_task_id.add_assign(1);
-                }
-                ack.set_task_id(task_id);
-
-                t.set_task_id(task_id);
-                t.set_ping(req.take_ping());
-
-                debug!("sending task to client");
-                if tx.send(t).wait().is_ok() {
-                    ack.set_success(true);
-                } else {
-                    ack.set_error_message("client exists, but was unable to send task".to_string());
-                }
-                debug!("task sent");
-            } else {
-                ack.set_error_message("client does not exist".to_string());
-            }
+        let identity = self.peer_identity(&ctx);
+        if let Some(ref identity) = identity {
+            info!("task scheduled by {}", identity);
         }
+        let owner = identity.clone().unwrap_or_default();
+        let upload = match self.user_role(identity) {
+            Ok(role) => TaskUpload::Waiting(role, owner),
+            Err(e) => TaskUpload::Failed(e),
+        };
+        // Errors of the upload are passed to upload_task, so it can abort a started task
+        let f = stream
+            .then(Ok::<_, ()>)
+            .fold(upload, {
+                let service = self.clone();
+                move |upload, request| Ok::<_, ()>(service.upload_task(upload, request))
+            })
+            .and_then({
+                let service = self.clone();
+                move |upload| sink.success(service.complete_upload(upload)).map_err(|_| ())
+            });
+        ctx.spawn(f);
+    }
 
-        let f = sink.success(ack).map_err(|_| ());
+    fn do_campaign(
+        &mut self,
+        ctx: RpcContext,
+        stream: RequestStream<ScheduleCampaign>,
+        sink: ClientStreamingSink<Ack>,
+    ) {
+        debug!("received do_campaign request");
+        let identity = self.peer_identity(&ctx);
+        if let Some(ref identity) = identity {
+            info!("campaign scheduled by {}", identity);
+        }
+        let owner = identity.clone().unwrap_or_default();
+        let role = self.user_role(identity);
+        // Every client of the campaign gets all destinations, so they are collected first
+        let f = stream
+            .fold(
+                (None, Vec::new()),
+                |(campaign, mut destinations): (Option<ScheduleCampaign>, Vec<Address>),
+                 mut req| {
+                    destinations.extend(req.take_destinations().take_addresses().into_iter());
+                    Ok::<_, grpcio::Error>((campaign.or(Some(req)), destinations))
+                },
+            )
+            .then({
+                let service = self.clone();
+                move |upload| {
+                    let ack = match upload {
+                        Ok((Some(req), destinations)) => match role {
+                            Ok(ref role) => {
+                                service.start_campaign(req, destinations, role, owner)
+                            }
+                            Err(ref e) => {
+                                let mut ack = Ack::new();
+                                ack.set_error_message(e.clone());
+                                ack
+                            }
+                        },
+                        Ok((None, _)) => {
+                            let mut ack = Ack::new();
+                            ack.set_error_message("request does not contain a campaign".to_string());
+                            ack
+                        }
+                        Err(e) => {
+                            warn!("upload of campaign failed: {}", e);
+                            let mut ack = Ack::new();
+                            ack.set_error_message(e.to_string());
+                            ack
+                        }
+                    };
+                    sink.success(ack).map_err(|_| ())
+                }
+            });
         ctx.spawn(f);
     }
 
@@ -276,6 +1342,9 @@ impl Verfploeter for VerfploeterService {
                     let mut c = Client::new();
                     c.index = *k;
                     c.set_metadata(v.metadata.clone());
+                    if let Some(clock) = self.clock_offset(v.metadata.id()) {
+                        c.set_clock(clock);
+                    }
                     c
                 })
                 .collect::<Vec<Client>>(),
@@ -287,15 +1356,101 @@ impl Verfploeter for VerfploeterService {
         );
     }
 
-    fn send_result(&mut self, ctx: RpcContext, req: TaskResult, sink: UnarySink<Ack>) {
+    fn send_result(&mut self, ctx: RpcContext, mut req: TaskResult, sink: UnarySink<Ack>) {
+        // With access tokens results are filed under the identity of the token, not the
+        // identity the client reports
+        match self.client_identity(&ctx) {
+            Ok(Some(identity)) => {
+                let metadata = req.mut_client().mut_metadata();
+                metadata.set_client_id(identity.clone());
+                metadata.set_hostname(identity);
+            }
+            Ok(None) => {}
+            Err(e) => {
+                warn!("rejecting results from {}: {}", ctx.peer(), e);
+                let mut ack = Ack::new();
+                ack.set_error_message(e);
+                ctx.spawn(sink.success(ack).map_err(|_| ()));
+                return;
+            }
+        }
+
         let task_id = req.get_task_id();
-        if let Some(subscribers) = self.get_subscribers(task_id) {
-            subscribers
-                .iter()
-                .map(|s| s.clone().send(req.clone()).wait())
-                .for_each(drop);
+        if task_id >= *self.current_task_id.lock().unwrap() {
+            let mut ack = Ack::new();
+            ack.set_error_message(format!("task {} does not exist", task_id));
+            ctx.spawn(sink.success(ack).map_err(|_| ()));
+            return;
         }
-        ctx.spawn(sink.success(Ack::new()).map_err(|_| ()));
+        let campaign_id = self.campaign_tasks.read().unwrap().get(&task_id).cloned();
+        if let Some(campaign_id) = campaign_id {
+            req.set_campaign_id(campaign_id);
+        }
+
+        // Add the clock offsets of the clients that sent the probes and received the replies,
+        // so the time between them can be corrected for the difference between their clocks
+        let transmitter = self
+            .task_registry
+            .get_status(task_id)
+            .and_then(|status| self.clock_offset(status.get_client().get_metadata().id()));
+        let receiver = self.clock_offset(req.get_client().get_metadata().id());
+        if let (Some(transmitter), Some(receiver)) = (transmitter, receiver) {
+            req.set_transmitter_clock(transmitter);
+            req.set_receiver_clock(receiver);
+        }
+
+        // Clients without a site are a site of their own in the summary
+        let site = match req.get_client().get_metadata().get_site() {
+            "" => req.get_client().get_metadata().id().to_string(),
+            site => site.to_string(),
+        };
+
+        // Store the result, count its replies for the summary, and look up the subscribers
+        // while holding the subscription list, so get_results never both reads a result from
+        // the store and receives it live, and the final result stays last (see finish_task).
+        // Results of campaign tasks are also stored for, and sent to, the campaign.
+        let mut subscribers = Vec::new();
+        {
+            let list = self.subscription_list.read().unwrap();
+            // Results of finished tasks would come after their final result, where
+            // get_results does not read them
+            if self.result_store.is_finished(task_id) {
+                drop(list);
+                warn!(
+                    "refusing {} results of task {}, which has finished",
+                    req.get_result_list().len(),
+                    task_id
+                );
+                let mut ack = Ack::new();
+                ack.set_error_message(format!("task {} has finished", task_id));
+                ctx.spawn(sink.success(ack).map_err(|_| ()));
+                return;
+            }
+            for id in Some(task_id).iter().chain(campaign_id.iter()) {
+                if self.result_store.is_finished(*id) {
+                    continue;
+                }
+                self.task_registry.add_replies(
+                    *id,
+                    &site,
+                    req.get_result_list().len() as u32,
+                    req.get_invalid_replies(),
+                );
+                if let Err(e) = self.result_store.append(*id, &req) {
+                    error!("unable to store result for task {}: {}", id, e);
+                }
+                if let Some(s) = list.get(id) {
+                    subscribers.extend(s.iter().cloned());
+                }
+            }
+        }
+        subscribers
+            .into_iter()
+            .map(|s| s.send(req.clone()).wait())
+            .for_each(drop);
+        let mut ack = Ack::new();
+        ack.set_success(true);
+        ctx.spawn(sink.success(ack).map_err(|_| ()));
     }
 
     fn subscribe_result(
@@ -306,22 +1461,323 @@ impl Verfploeter for VerfploeterService {
     ) {
         let (tx, rx) = channel(1);
 
-        let f = rx
-            .map(|i| (i, grpcio::WriteFlags::default()))
-            .forward(sink.sink_map_err(|e| error!("failure in result sink: {}", e)))
-            .map(|_| ())
-            .map_err(|_| error!("closed result stream"));
+        let shutting_down = self.shutting_down.clone();
+        let f = forward_results(rx, sink, move || shutting_down.load(Ordering::SeqCst));
 
-        self.register_subscriber(req.get_task_id(), tx);
+        // While shutting down the stream ends right away
+        if !self.is_shutting_down() {
+            self.register_subscriber(req.get_task_id(), tx);
+        }
 
         self.runtime.executor().spawn(f);
     }
 
     fn task_finished(&mut self, ctx: RpcContext, req: TaskId, sink: UnarySink<Ack>) {
         let task_id = req.get_task_id();
-        self.disconnect_subscribers(task_id);
+        if let Err(e) = self.check_performer(&ctx, task_id) {
+            warn!("refusing to finish task {} for {}: {}", task_id, ctx.peer(), e);
+            let mut ack = Ack::new();
+            ack.set_error_message(e);
+            ctx.spawn(sink.success(ack).map_err(|_| ()));
+            return;
+        }
+
+        self.complete_task(task_id);
         ctx.spawn(sink.success(Ack::new()).map_err(|_| ()));
     }
+
+    fn get_results(
+        &mut self,
+        ctx: RpcContext,
+        req: ResultRequest,
+        sink: ServerStreamingSink<TaskResult>,
+    ) {
+        let task_id = req.get_task_id();
+        debug!(
+            "received get_results request for task {} from offset {}",
+            task_id,
+            req.get_offset()
+        );
+        if task_id >= *self.current_task_id.lock().unwrap() {
+            ctx.spawn(
+                sink.fail(RpcStatus::new(
+                    RpcStatusCode::NotFound,
+                    Some("task does not exist".to_string()),
+                ))
+                .map_err(|_| ()),
+            );
+            return;
+        }
+
+        // Read the stored results and, for a running task, subscribe to the results still
+        // to come while holding the subscription list (see send_result)
+        let (tx, rx) = channel(1);
+        let finished;
+        let stored = {
+            let mut list = self.subscription_list.write().unwrap();
+            let stored = self
+                .result_store
+                .read(task_id, req.get_offset() as usize)
+                .unwrap_or_else(|e| {
+                    error!("unable to read results of task {}: {}", task_id, e);
+                    Vec::new()
+                });
+            finished = self.result_store.is_finished(task_id);
+            if !finished && !self.is_shutting_down() {
+                list.entry(task_id).or_insert_with(Vec::new).push(tx);
+            }
+            stored
+        };
+
+        let shutting_down = self.shutting_down.clone();
+        let f = forward_results(stream::iter_ok(stored).chain(rx), sink, move || {
+            !finished && shutting_down.load(Ordering::SeqCst)
+        });
+        self.runtime.executor().spawn(f);
+    }
+
+    fn list_tasks(&mut self, ctx: RpcContext, _: Empty, sink: UnarySink<TaskStatusList>) {
+        debug!("received list_tasks request");
+        let mut list = TaskStatusList::new();
+        list.set_tasks(RepeatedField::from_vec(self.task_registry.list()));
+        ctx.spawn(
+            sink.success(list)
+                .map(|_| ())
+                .map_err(|e| error!("could not send task list: {}", e)),
+        );
+    }
+
+    fn get_task_status(&mut self, ctx: RpcContext, req: TaskId, sink: UnarySink<TaskStatus>) {
+        debug!("received get_task_status request");
+        let f = match self.task_registry.get_status(req.get_task_id()) {
+            Some(status) => sink.success(status),
+            None => sink.fail(RpcStatus::new(
+                RpcStatusCode::NotFound,
+                Some("task does not exist".to_string()),
+            )),
+        };
+        ctx.spawn(f.map_err(|e| error!("could not send task status: {}", e)));
+    }
+
+    fn cancel_task(&mut self, ctx: RpcContext, req: TaskId, sink: UnarySink<Ack>) {
+        let task_id = req.get_task_id();
+        debug!("received cancel_task request for task {}", task_id);
+        let mut ack = Ack::new();
+        ack.set_task_id(task_id);
+        ack.set_success(false);
+
+        if let Err(e) = self.check_cancel(&ctx, task_id) {
+            warn!("refusing to cancel task {} for {}: {}", task_id, ctx.peer(), e);
+            ack.set_error_message(e);
+            ctx.spawn(sink.success(ack).map_err(|_| ()));
+            return;
+        }
+
+        // Cancelling a campaign keeps it from moving on to the next client, and cancels the
+        // task currently running for it
+        let is_campaign = self
+            .campaign_list
+            .write()
+            .unwrap()
+            .get_mut(&task_id)
+            .map(|c| c.pending_clients.clear())
+            .is_some();
+        let running_task_id = if is_campaign {
+            self.task_registry.get_running_campaign_task(task_id)
+        } else {
+            Some(task_id)
+        };
+
+        match running_task_id.and_then(|id| Some((id, self.task_registry.cancel(id)?))) {
+            Some((running_task_id, connection_id)) => {
+                let mut t = Task::new();
+                t.set_task_id(running_task_id);
+                t.set_cancel(Empty::new());
+                let sent = self
+                    .connection_manager
+                    .get_client_tx_by_idx(connection_id)
+                    .map_or(false, |tx| tx.send(t).wait().is_ok());
+                if sent {
+                    info!("cancelling task {}", running_task_id);
+                    ack.set_success(true);
+                } else {
+                    // The client is gone, so it will not send anything anymore either, nor
+                    // report the task finished to move the campaign on
+                    self.task_registry.finish(running_task_id);
+                    self.finish_held_task(running_task_id);
+                    if is_campaign {
+                        info!("campaign {} cancelled", task_id);
+                        self.finish_task(task_id);
+                    }
+                    ack.set_error_message("client performing the task is not connected".to_string());
+                }
+            }
+            None if is_campaign => ack.set_success(true),
+            None => ack.set_error_message("task is not running".to_string()),
+        }
+
+        ctx.spawn(sink.success(ack).map_err(|_| ()));
+    }
+
+    fn task_progress(&mut self, ctx: RpcContext, req: TaskProgress, sink: UnarySink<Ack>) {
+        let task_id = req.get_task_id();
+        if let Err(e) = self.check_performer(&ctx, task_id) {
+            warn!("refusing progress of task {} from {}: {}", task_id, ctx.peer(), e);
+            let mut ack = Ack::new();
+            ack.set_error_message(e);
+            ctx.spawn(sink.success(ack).map_err(|_| ()));
+            return;
+        }
+
+        self.task_registry
+            .set_progress(task_id, req.get_destinations_sent(), req.get_rate());
+        ctx.spawn(sink.success(Ack::new()).map_err(|_| ()));
+    }
+
+    fn sync_clock(&mut self, ctx: RpcContext, mut req: ClockSync, sink: UnarySink<ClockSync>) {
+        req.set_server_receive_time(current_timestamp());
+        req.set_server_transmit_time(current_timestamp());
+        ctx.spawn(sink.success(req).map_err(|_| ()));
+    }
+
+    fn report_clock_offset(&mut self, ctx: RpcContext, mut req: ClockOffset, sink: UnarySink<Ack>) {
+        let mut ack = Ack::new();
+        // With access tokens the client id is the identity of the token of the client
+        let client_id = if self.tokens.is_some() {
+            self.peer_identity(&ctx)
+        } else {
+            Some(req.get_client_id().to_string())
+        };
+        match client_id {
+            Some(client_id) => {
+                debug!(
+                    "clock of client {} is off by {} us (delay {} us)",
+                    client_id,
+                    req.get_offset() / 1000,
+                    req.get_delay() / 1000
+                );
+                req.set_client_id(client_id.clone());
+                self.clock_offsets.write().unwrap().insert(client_id, req);
+                ack.set_success(true);
+            }
+            None => ack.set_error_message(ACCESS_TOKEN_REQUIRED.to_string()),
+        }
+        ctx.spawn(sink.success(ack).map_err(|_| ()));
+    }
+
+    fn add_schedule(
+        &mut self,
+        ctx: RpcContext,
+        stream: RequestStream<Schedule>,
+        sink: ClientStreamingSink<Ack>,
+    ) {
+        debug!("received add_schedule request");
+        let identity = self.peer_identity(&ctx);
+        if let Some(ref identity) = identity {
+            info!("schedule added by {}", identity);
+        }
+        let role = self.user_role(identity.clone());
+        // The destinations are kept with the schedule, so they are collected first
+        let f = stream
+            .fold(
+                (None, Vec::new()),
+                |(schedule, mut destinations): (Option<Schedule>, Vec<Address>), mut req| {
+                    destinations.extend(
+                        req.mut_task()
+                            .take_destinations()
+                            .take_addresses()
+                            .into_iter(),
+                    );
+                    Ok::<_, grpcio::Error>((schedule.or(Some(req)), destinations))
+                },
+            )
+            .then({
+                let service = self.clone();
+                move |upload| {
+                    let ack = match (upload, role) {
+                        (Ok((Some(req), destinations)), Ok(role)) => {
+                            service.create_schedule(req, destinations, &role, identity)
+                        }
+                        (Ok((Some(_), _)), Err(e)) => {
+                            let mut ack = Ack::new();
+                            ack.set_error_message(e);
+                            ack
+                        }
+                        (Ok((None, _)), _) => {
+                            let mut ack = Ack::new();
+                            ack.set_error_message("request does not contain a schedule".to_string());
+                            ack
+                        }
+                        (Err(e), _) => {
+                            warn!("upload of schedule failed: {}", e);
+                            let mut ack = Ack::new();
+                            ack.set_error_message(e.to_string());
+                            ack
+                        }
+                    };
+                    sink.success(ack).map_err(|_| ())
+                }
+            });
+        ctx.spawn(f);
+    }
+
+    fn list_schedules(&mut self, ctx: RpcContext, _: Empty, sink: UnarySink<ScheduleList>) {
+        debug!("received list_schedules request");
+        let mut list = ScheduleList::new();
+        list.set_schedules(RepeatedField::from_vec(self.schedule_store.list()));
+        ctx.spawn(
+            sink.success(list)
+                .map_err(|e| error!("could not send schedule list: {}", e)),
+        );
+    }
+
+    /// Schedules can only be removed by the user that added them
+    fn remove_schedule(&mut self, ctx: RpcContext, req: ScheduleId, sink: UnarySink<Ack>) {
+        let schedule_id = req.get_schedule_id();
+        debug!("received remove_schedule request for schedule {}", schedule_id);
+        let mut ack = Ack::new();
+        ack.set_task_id(schedule_id);
+        let identity = self.peer_identity(&ctx).unwrap_or_default();
+        let owner = self
+            .schedule_store
+            .list()
+            .into_iter()
+            .find(|s| s.get_schedule_id() == schedule_id)
+            .map(|s| s.get_owner().to_string());
+        match owner {
+            None => ack.set_error_message("schedule does not exist".to_string()),
+            Some(ref owner) if *owner != identity => ack.set_error_message(format!(
+                "not authorized: schedule {} belongs to {}",
+                schedule_id, owner
+            )),
+            Some(_) => match self.schedule_store.remove(schedule_id) {
+                Ok(removed) => {
+                    info!("removed schedule {}", schedule_id);
+                    ack.set_success(removed);
+                }
+                Err(e) => {
+                    error!("unable to remove schedule {}: {}", schedule_id, e);
+                    ack.set_error_message("unable to remove schedule".to_string());
+                }
+            },
+        }
+        ctx.spawn(sink.success(ack).map_err(|_| ()));
+    }
+}
+
+/// Current time in nanoseconds since the epoch, as the clients timestamp probes
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64
+}
+
+/// Seed for the order in which the destinations of a task are probed, when the task
+/// does not ask for a specific one
+fn random_seed() -> u64 {
+    // The hasher keys of RandomState are random for every instance
+    RandomState::new().build_hasher().finish()
 }
 
 type ConnectionList = Arc<RwLock<HashMap<u32, Connection>>>;
@@ -346,14 +1802,25 @@ impl ConnectionManager {
         *counter
     }
 
-    fn register_connection(&self, connection_id: u32, connection: Connection) {
+    /// Registers a connection, unless a client with the same client id is connected
+    /// already, returns whether the connection was registered
+    fn register_connection(&self, connection_id: u32, connection: Connection) -> bool {
         let mut hashmap = self.connections.write().unwrap();
+        let client_id = connection.metadata.get_client_id();
+        if !client_id.is_empty()
+            && hashmap
+                .values()
+                .any(|c| c.metadata.get_client_id() == client_id)
+        {
+            return false;
+        }
         hashmap.insert(connection_id, connection);
         debug!(
             "added connection to list with id {}, connection count: {}",
             connection_id,
             hashmap.len()
         );
+        true
     }
 
     fn unregister_connection(&self, connection_id: u32) {
@@ -374,6 +1841,7 @@ impl ConnectionManager {
         None
     }
 
+    #[allow(dead_code)]
     fn get_client_tx_by_hostname(&self, hostname: &str) -> Option<Sender<Task>> {
         let hashmap = self.connections.read().unwrap();
         hashmap
@@ -381,6 +1849,40 @@ impl ConnectionManager {
             .find(|f| f.1.metadata.hostname == hostname)
             .map(|f| f.1.channel.clone())
     }
+
+    fn get_client_by_idx(&self, connection_id: u32) -> Option<Client> {
+        let hashmap = self.connections.read().unwrap();
+        hashmap.get(&connection_id).map(|c| {
+            let mut client = Client::new();
+            client.index = connection_id;
+            client.set_metadata(c.metadata.clone());
+            client
+        })
+    }
+
+    fn get_all_client_tx(&self) -> Vec<Sender<Task>> {
+        let hashmap = self.connections.read().unwrap();
+        hashmap.values().map(|c| c.channel.clone()).collect()
+    }
+
+    /// Returns the connection of the client with the given client id, or with the given
+    /// hostname if no client has that id
+    fn get_connection_id_by_name(&self, name: &str) -> Option<u32> {
+        let hashmap = self.connections.read().unwrap();
+        hashmap
+            .iter()
+            .find(|f| f.1.metadata.get_client_id() == name)
+            .or_else(|| hashmap.iter().find(|f| f.1.metadata.hostname == name))
+            .map(|f| *f.0)
+    }
+
+    /// Returns the ids of all current connections, in order of connecting
+    fn get_connection_ids(&self) -> Vec<u32> {
+        let hashmap = self.connections.read().unwrap();
+        let mut ids = hashmap.keys().cloned().collect::<Vec<u32>>();
+        ids.sort();
+        ids
+    }
 }
 
 /// Test functions - can be run with 'cargo test'
@@ -446,6 +1948,56 @@ mod connection_manager {
             "registered connection should be retrievable from connection manager"
         );
     }
+
+    #[test]
+    fn connection_ids_are_listed_in_order() {
+        let manager = ConnectionManager::new();
+
+        let mut registered_ids = Vec::new();
+        for i in 0..5 {
+            let connection_id = manager.generate_connection_id();
+            let (channel_tx, _) = channel(0);
+            let mut connection = Connection {
+                channel: channel_tx,
+                metadata: Metadata::default(),
+            };
+            connection.metadata.hostname = format!("host{}", i);
+            manager.register_connection(connection_id, connection);
+            registered_ids.push(connection_id);
+        }
+        manager.unregister_connection(registered_ids.remove(2));
+
+        assert_eq!(manager.get_connection_ids(), registered_ids);
+        assert_eq!(
+            manager.get_connection_id_by_name("host3"),
+            Some(registered_ids[2])
+        );
+    }
+
+    #[test]
+    fn client_ids_are_unique() {
+        let manager = ConnectionManager::new();
+
+        let mut registered = Vec::new();
+        for hostname in &["ams", "ams", "lax"] {
+            let (channel_tx, _) = channel(0);
+            let mut connection = Connection {
+                channel: channel_tx,
+                metadata: Metadata::default(),
+            };
+            connection.metadata.set_hostname("anycast".to_string());
+            connection.metadata.set_client_id(hostname.to_string());
+            let connection_id = manager.generate_connection_id();
+            registered.push(manager.register_connection(connection_id, connection));
+        }
+        assert_eq!(registered, vec![true, false, true]);
+
+        // Clients are found by id before hostname
+        let lax = manager.get_connection_id_by_name("lax").unwrap();
+        assert_eq!(manager.get_client_by_idx(lax).unwrap().get_metadata().id(), "lax");
+        assert!(manager.get_connection_id_by_name("anycast").is_some());
+        assert_eq!(manager.get_connection_id_by_name("syd"), None);
+    }
 }
 
 // End-of-mod.rs
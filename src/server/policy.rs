@@ -0,0 +1,229 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server authorization policy (policy.rs)
+//!----------------------------------------------------------------------------
+//! Limits what CLI users may schedule. The policy file defines roles and
+//! assigns them to the identities of CLI users (see auth.rs), one per line:
+//!
+//!   role <role> <clients> <max rate> <max destinations>
+//!   user <identity> <role>
+//!
+//! Clients is a comma-separated list of client ids (the hostname of clients
+//! that do not set one), or `*` for all clients. A maximum of 0 means
+//! unlimited. The user `*` gives a role to all other users, including users
+//! without an access token, users without a role cannot schedule anything.
+//! Empty lines and lines starting with `#` are ignored. Without a policy file,
+//! every user can schedule any task. The file is read for every task, so
+//! changes take effect without a restart.
+//!----------------------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Identity that matches every user without a role of their own
+const ANY_USER: &str = "*";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Role {
+    name: String,
+    /// None if all clients are allowed
+    clients: Option<Vec<String>>,
+    max_rate: u32,
+    max_destinations: u32,
+}
+
+impl Role {
+    /// The role of every user when there is no policy file
+    pub fn unrestricted() -> Role {
+        Role {
+            name: "unrestricted".to_string(),
+            clients: None,
+            max_rate: 0,
+            max_destinations: 0,
+        }
+    }
+
+    pub fn allows_client(&self, client_id: &str) -> bool {
+        self.clients
+            .as_ref()
+            .map_or(true, |clients| clients.iter().any(|c| c == client_id))
+    }
+
+    pub fn check_client(&self, client_id: &str) -> Result<(), String> {
+        if self.allows_client(client_id) {
+            Ok(())
+        } else {
+            Err(format!(
+                "not authorized: role {} may not use client {}",
+                self.name, client_id
+            ))
+        }
+    }
+
+    /// Returns the rate to use for a requested rate, where 0 (the client maximum)
+    /// becomes the maximum rate of the role
+    pub fn check_rate(&self, rate: u32) -> Result<u32, String> {
+        if self.max_rate == 0 {
+            Ok(rate)
+        } else if rate == 0 {
+            Ok(self.max_rate)
+        } else if rate <= self.max_rate {
+            Ok(rate)
+        } else {
+            Err(format!(
+                "not authorized: rate {} exceeds the maximum of {} for role {}",
+                rate, self.max_rate, self.name
+            ))
+        }
+    }
+
+    pub fn check_destinations(&self, destinations: usize) -> Result<(), String> {
+        if self.max_destinations == 0 || destinations <= self.max_destinations as usize {
+            Ok(())
+        } else {
+            Err(format!(
+                "not authorized: more than {} destinations for role {}",
+                self.max_destinations, self.name
+            ))
+        }
+    }
+}
+
+pub struct Policy {
+    roles: HashMap<String, Role>,
+    users: HashMap<String, String>,
+}
+
+impl Policy {
+    /// Role of a user, identified by their access token
+    pub fn role(&self, identity: Option<&str>) -> Result<&Role, String> {
+        let role = identity
+            .and_then(|identity| self.users.get(identity))
+            .or_else(|| self.users.get(ANY_USER))
+            .ok_or_else(|| match identity {
+                Some(identity) => format!("not authorized: no role for {}", identity),
+                None => "not authorized: no role for users without an access token".to_string(),
+            })?;
+        Ok(&self.roles[role])
+    }
+}
+
+/// Returns the role of a user, every user is unrestricted without a policy file
+pub fn load_role(policy_file: Option<&Path>, identity: Option<&str>) -> Result<Role, String> {
+    let policy_file = match policy_file {
+        Some(policy_file) => policy_file,
+        None => return Ok(Role::unrestricted()),
+    };
+
+    let content = fs::read_to_string(policy_file).map_err(|e| {
+        format!(
+            "unable to read policy file {}: {}",
+            policy_file.display(),
+            e
+        )
+    })?;
+    parse_policy(&content)?.role(identity).map(Role::clone)
+}
+
+fn parse_policy(content: &str) -> Result<Policy, String> {
+    let mut roles = HashMap::new();
+    let mut users = HashMap::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.split_whitespace().collect::<Vec<&str>>();
+        match fields.as_slice() {
+            ["role", name, clients, max_rate, max_destinations] => {
+                let limit = |value: &str| {
+                    value.parse::<u32>().map_err(|_| {
+                        format!("invalid limit {} on line {} of policy file", value, idx + 1)
+                    })
+                };
+                let role = Role {
+                    name: name.to_string(),
+                    clients: match *clients {
+                        "*" => None,
+                        clients => Some(clients.split(',').map(|c| c.to_string()).collect()),
+                    },
+                    max_rate: limit(max_rate)?,
+                    max_destinations: limit(max_destinations)?,
+                };
+                if roles.insert(name.to_string(), role).is_some() {
+                    return Err(format!("duplicate role {} in policy file", name));
+                }
+            }
+            ["user", identity, role] => {
+                if users
+                    .insert(identity.to_string(), role.to_string())
+                    .is_some()
+                {
+                    return Err(format!("duplicate user {} in policy file", identity));
+                }
+            }
+            _ => return Err(format!("invalid line {} of policy file", idx + 1)),
+        }
+    }
+
+    if let Some(role) = users.values().find(|role| !roles.contains_key(*role)) {
+        return Err(format!("undefined role {} in policy file", role));
+    }
+    Ok(Policy { roles, users })
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod policy_file {
+    use super::*;
+
+    const POLICY: &str = "# roles\n\
+                          role operator * 0 0\n\
+                          role student ams,lax 100 1000\n\
+                          \n\
+                          user alice operator\n\
+                          user * student\n";
+
+    #[test]
+    fn users_get_their_role() {
+        let policy = parse_policy(POLICY).unwrap();
+
+        let operator = policy.role(Some("alice")).unwrap();
+        assert!(operator.allows_client("syd"));
+        assert_eq!(operator.check_rate(5000), Ok(5000));
+        assert_eq!(operator.check_destinations(1_000_000), Ok(()));
+
+        let student = policy.role(Some("bob")).unwrap();
+        assert_eq!(student, policy.role(None).unwrap());
+        assert!(student.allows_client("ams"));
+        assert!(student.check_client("syd").is_err());
+        assert_eq!(student.check_rate(0), Ok(100));
+        assert_eq!(student.check_rate(50), Ok(50));
+        assert!(student.check_rate(5000).is_err());
+        assert_eq!(student.check_destinations(1000), Ok(()));
+        assert!(student.check_destinations(1001).is_err());
+    }
+
+    #[test]
+    fn users_without_role_are_denied() {
+        let policy = parse_policy("role operator * 0 0\nuser alice operator").unwrap();
+        assert!(policy.role(Some("alice")).is_ok());
+        assert!(policy.role(Some("bob")).is_err());
+        assert!(policy.role(None).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_lines() {
+        assert!(parse_policy("role operator * fast 0").is_err());
+        assert!(parse_policy("role operator *").is_err());
+        assert!(parse_policy("user alice operator").is_err());
+        assert!(parse_policy("role a * 0 0\nrole a * 0 0").is_err());
+        assert!(parse_policy("group a").is_err());
+    }
+
+    #[test]
+    fn unrestricted_without_file() {
+        assert_eq!(load_role(None, Some("alice")), Ok(Role::unrestricted()));
+    }
+}
@@ -0,0 +1,368 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server task registry (registry.rs)
+//!----------------------------------------------------------------------------
+//! Keeps track of the tasks handed out by this server: which client performs
+//! them, how far the outbound ping is, and whether they are still running.
+//! Replies are counted per task and per campaign, for the summary that ends
+//! their results. Only the most recent finished tasks are kept, and counts are
+//! dropped once the summary has been stored.
+//!----------------------------------------------------------------------------
+
+use crate::schema::verfploeter::{Client, SiteReplies, TaskStatus, TaskSummary};
+use protobuf::RepeatedField;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const TASK_RUNNING: &str = "running";
+pub const TASK_CANCELLING: &str = "cancelling";
+pub const TASK_CANCELLED: &str = "cancelled";
+pub const TASK_FINISHED: &str = "finished";
+
+/// Number of finished (or cancelled) tasks kept, the tasks with the lowest ids are dropped
+const MAX_FINISHED_TASKS: usize = 1000;
+
+/// Probes and replies of a task or campaign, as far as they are not in its task status
+#[derive(Default)]
+struct Tally {
+    probes_sent: u32,
+    /// Replies by the site of the client that received them
+    replies: BTreeMap<String, u32>,
+    invalid_replies: u32,
+}
+
+/// The index of the client in a task status is the connection id of the client
+pub struct TaskRegistry {
+    tasks: RwLock<HashMap<u32, TaskStatus>>,
+    tallies: RwLock<HashMap<u32, Tally>>,
+}
+
+impl TaskRegistry {
+    pub fn new() -> TaskRegistry {
+        TaskRegistry {
+            tasks: RwLock::new(HashMap::new()),
+            tallies: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Registers a task that was sent to the given client, rate is the requested rate
+    /// until the client reports the rate it actually uses, seed determines the order in
+    /// which the destinations are probed
+    pub fn register(
+        &self,
+        task_id: u32,
+        campaign_id: u32,
+        client: Client,
+        destinations: u32,
+        rate: u32,
+        seed: u64,
+    ) {
+        let mut status = TaskStatus::new();
+        status.set_task_id(task_id);
+        status.set_campaign_id(campaign_id);
+        status.set_client(client);
+        status.set_state(TASK_RUNNING.to_string());
+        status.set_destinations(destinations);
+        status.set_rate(rate);
+        status.set_seed(seed);
+        status.set_start_time(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        );
+        self.tasks.write().unwrap().insert(task_id, status);
+    }
+
+    /// Records the identity of the user that scheduled a task
+    pub fn set_owner(&self, task_id: u32, owner: String) {
+        if let Some(task) = self.tasks.write().unwrap().get_mut(&task_id) {
+            task.set_owner(owner);
+        }
+    }
+
+    /// Removes a task that could not be sent to its client
+    pub fn unregister(&self, task_id: u32) {
+        self.tasks.write().unwrap().remove(&task_id);
+    }
+
+    /// Counts destinations as they are sent to the client of a task
+    pub fn add_destinations(&self, task_id: u32, destinations: u32) {
+        if let Some(task) = self.tasks.write().unwrap().get_mut(&task_id) {
+            let total = task.get_destinations() + destinations;
+            task.set_destinations(total);
+        }
+    }
+
+    pub fn set_progress(&self, task_id: u32, destinations_sent: u32, rate: u32) {
+        if let Some(task) = self.tasks.write().unwrap().get_mut(&task_id) {
+            task.set_destinations_sent(destinations_sent);
+            task.set_rate(rate);
+        }
+    }
+
+    /// Marks a task as cancelling, returns the connection id of the client performing it,
+    /// or None if the task is not running
+    pub fn cancel(&self, task_id: u32) -> Option<u32> {
+        let mut tasks = self.tasks.write().unwrap();
+        let task = tasks.get_mut(&task_id)?;
+        if task.get_state() != TASK_RUNNING {
+            return None;
+        }
+        task.set_state(TASK_CANCELLING.to_string());
+        Some(task.get_client().get_index())
+    }
+
+    /// Marks a task as finished, or as cancelled if it was being cancelled
+    pub fn finish(&self, task_id: u32) {
+        let mut tasks = self.tasks.write().unwrap();
+        if let Some(task) = tasks.get_mut(&task_id) {
+            let state = if task.get_state() == TASK_CANCELLING {
+                TASK_CANCELLED
+            } else {
+                TASK_FINISHED
+            };
+            task.set_state(state.to_string());
+        }
+
+        let mut finished = tasks
+            .values()
+            .filter(|t| t.get_state() == TASK_FINISHED || t.get_state() == TASK_CANCELLED)
+            .map(|t| t.get_task_id())
+            .collect::<Vec<u32>>();
+        if finished.len() > MAX_FINISHED_TASKS {
+            finished.sort();
+            for task_id in &finished[..finished.len() - MAX_FINISHED_TASKS] {
+                tasks.remove(task_id);
+            }
+        }
+    }
+
+    /// Counts the probes sent for a campaign by one of its tasks, the probes of a task
+    /// itself are in its status
+    pub fn add_probes(&self, campaign_id: u32, probes: u32) {
+        let mut tallies = self.tallies.write().unwrap();
+        tallies.entry(campaign_id).or_default().probes_sent += probes;
+    }
+
+    /// Counts the replies to a task (or campaign) received at a site, and the replies that
+    /// carried an invalid payload
+    pub fn add_replies(&self, task_id: u32, site: &str, replies: u32, invalid_replies: u32) {
+        let mut tallies = self.tallies.write().unwrap();
+        let tally = tallies.entry(task_id).or_default();
+        if replies > 0 {
+            *tally.replies.entry(site.to_string()).or_insert(0) += replies;
+        }
+        tally.invalid_replies += invalid_replies;
+    }
+
+    /// Drops the counts of a task (or campaign) once its summary is no longer needed
+    pub fn remove_tally(&self, task_id: u32) {
+        self.tallies.write().unwrap().remove(&task_id);
+    }
+
+    /// Totals of a task (or campaign), with the replies ordered by site
+    pub fn summary(&self, task_id: u32) -> TaskSummary {
+        let mut summary = TaskSummary::new();
+        if let Some(task) = self.tasks.read().unwrap().get(&task_id) {
+            summary.set_probes_sent(task.get_destinations_sent());
+        }
+        if let Some(tally) = self.tallies.read().unwrap().get(&task_id) {
+            summary.set_probes_sent(summary.get_probes_sent() + tally.probes_sent);
+            summary.set_site_replies(RepeatedField::from_vec(
+                tally
+                    .replies
+                    .iter()
+                    .map(|(site, replies)| {
+                        let mut site_replies = SiteReplies::new();
+                        site_replies.set_site(site.clone());
+                        site_replies.set_replies(*replies);
+                        site_replies
+                    })
+                    .collect(),
+            ));
+            summary.set_invalid_replies(tally.invalid_replies);
+        }
+        summary
+    }
+
+    /// Returns the running task of a campaign, if any
+    pub fn get_running_campaign_task(&self, campaign_id: u32) -> Option<u32> {
+        let tasks = self.tasks.read().unwrap();
+        tasks
+            .values()
+            .find(|t| {
+                t.get_campaign_id() == campaign_id && t.get_state() == TASK_RUNNING
+            })
+            .map(|t| t.get_task_id())
+    }
+
+    pub fn get_status(&self, task_id: u32) -> Option<TaskStatus> {
+        self.tasks
+            .read()
+            .unwrap()
+            .get(&task_id)
+            .map(|t| t.clone())
+    }
+
+    /// Returns the ids of the tasks that have not finished yet, including tasks that
+    /// are being cancelled
+    pub fn unfinished(&self) -> Vec<u32> {
+        let tasks = self.tasks.read().unwrap();
+        let mut task_ids = tasks
+            .values()
+            .filter(|t| t.get_state() == TASK_RUNNING || t.get_state() == TASK_CANCELLING)
+            .map(|t| t.get_task_id())
+            .collect::<Vec<u32>>();
+        task_ids.sort();
+        task_ids
+    }
+
+    /// Returns the ids of the unfinished tasks performed by the client with a connection id
+    pub fn unfinished_by(&self, connection_id: u32) -> Vec<u32> {
+        self.unfinished()
+            .into_iter()
+            .filter(|task_id| {
+                self.get_status(*task_id)
+                    .map_or(false, |t| t.get_client().get_index() == connection_id)
+            })
+            .collect()
+    }
+
+    /// Returns the status of all tasks, ordered by task id
+    pub fn list(&self) -> Vec<TaskStatus> {
+        let tasks = self.tasks.read().unwrap();
+        let mut list = tasks
+            .values()
+            .map(|t| t.clone())
+            .collect::<Vec<TaskStatus>>();
+        list.sort_by_key(|t| t.get_task_id());
+        list
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod task_registry {
+    use super::*;
+
+    fn client(index: u32) -> Client {
+        let mut client = Client::new();
+        client.set_index(index);
+        client
+    }
+
+    #[test]
+    fn tasks_are_listed_in_order() {
+        let registry = TaskRegistry::new();
+        registry.register(3, 0, client(1), 10, 0, 3);
+        registry.register(1, 0, client(2), 20, 0, 1);
+        registry.set_progress(1, 5, 100);
+        registry.add_destinations(3, 5);
+        registry.set_owner(3, "alice".to_string());
+
+        let list = registry.list();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].get_task_id(), 1);
+        assert_eq!(list[0].get_destinations_sent(), 5);
+        assert_eq!(list[0].get_rate(), 100);
+        assert_eq!(list[0].get_seed(), 1);
+        assert_eq!(list[1].get_state(), TASK_RUNNING);
+        assert_eq!(list[1].get_destinations(), 15);
+        assert_eq!(list[1].get_owner(), "alice");
+    }
+
+    #[test]
+    fn cancelled_tasks_finish_as_cancelled() {
+        let registry = TaskRegistry::new();
+        registry.register(1, 0, client(7), 10, 0, 1);
+        registry.register(2, 0, client(7), 10, 0, 2);
+
+        assert_eq!(registry.cancel(1), Some(7));
+        assert_eq!(registry.cancel(1), None, "task can only be cancelled once");
+        registry.finish(1);
+        registry.finish(2);
+
+        assert_eq!(registry.get_status(1).unwrap().get_state(), TASK_CANCELLED);
+        assert_eq!(registry.get_status(2).unwrap().get_state(), TASK_FINISHED);
+        assert_eq!(registry.cancel(2), None, "finished task cannot be cancelled");
+    }
+
+    #[test]
+    fn unfinished_tasks_are_listed() {
+        let registry = TaskRegistry::new();
+        registry.register(3, 0, client(1), 10, 0, 3);
+        registry.register(1, 0, client(1), 10, 0, 1);
+        registry.register(2, 0, client(1), 10, 0, 2);
+        registry.cancel(1);
+        registry.finish(2);
+
+        assert_eq!(registry.unfinished(), vec![1, 3]);
+
+        registry.register(4, 0, client(2), 10, 0, 4);
+        assert_eq!(registry.unfinished_by(1), vec![1, 3]);
+        assert_eq!(registry.unfinished_by(2), vec![4]);
+        assert!(registry.unfinished_by(3).is_empty());
+    }
+
+    #[test]
+    fn running_campaign_task_is_found() {
+        let registry = TaskRegistry::new();
+        registry.register(2, 1, client(1), 10, 0, 2);
+        registry.finish(2);
+        registry.register(3, 1, client(2), 10, 0, 3);
+
+        assert_eq!(registry.get_running_campaign_task(1), Some(3));
+        assert_eq!(registry.get_running_campaign_task(4), None);
+    }
+
+    #[test]
+    fn replies_are_summarized_per_site() {
+        let registry = TaskRegistry::new();
+        registry.register(2, 1, client(1), 10, 0, 2);
+        registry.set_progress(2, 10, 100);
+        registry.add_replies(2, "lax", 3, 0);
+        registry.add_replies(2, "ams", 4, 1);
+        registry.add_replies(2, "lax", 2, 0);
+        registry.add_replies(2, "syd", 0, 2);
+        registry.add_probes(1, 10);
+        registry.add_probes(1, 5);
+
+        let summary = registry.summary(2);
+        assert_eq!(summary.get_probes_sent(), 10);
+        let sites = summary
+            .get_site_replies()
+            .iter()
+            .map(|s| (s.get_site(), s.get_replies()))
+            .collect::<Vec<(&str, u32)>>();
+        assert_eq!(sites, vec![("ams", 4), ("lax", 5)]);
+        assert_eq!(summary.get_invalid_replies(), 3);
+
+        assert_eq!(registry.summary(1).get_probes_sent(), 15);
+        assert_eq!(registry.summary(3), TaskSummary::new());
+
+        registry.remove_tally(2);
+        assert!(registry.summary(2).get_site_replies().is_empty());
+        assert_eq!(registry.summary(2).get_probes_sent(), 10);
+    }
+
+    #[test]
+    fn oldest_finished_tasks_are_dropped() {
+        let registry = TaskRegistry::new();
+        let last = MAX_FINISHED_TASKS as u32 + 2;
+        for task_id in 1..=last {
+            registry.register(task_id, 0, client(1), 10, 0, 0);
+        }
+        registry.cancel(1);
+        for task_id in 2..=last {
+            registry.finish(task_id);
+        }
+
+        // Task 1 is still being cancelled, so task 2 is the oldest finished task
+        let list = registry.list();
+        assert_eq!(list.len(), MAX_FINISHED_TASKS + 1);
+        assert_eq!(list[0].get_task_id(), 1);
+        assert_eq!(list[1].get_task_id(), 3);
+        assert!(registry.get_status(2).is_none());
+    }
+}
@@ -0,0 +1,527 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server measurement schedules (schedule.rs)
+//!----------------------------------------------------------------------------
+//! Keeps the measurements the server runs by itself: a task with its
+//! destinations, and when to run it. A schedule either follows a cron
+//! expression (`<minute> <hour> <day of month> <month> <day of week>`), or
+//! runs every interval seconds from its start time, or runs once at its start
+//! time. All times are in UTC. Every run is an ordinary task, with its own
+//! task id and results.
+//!
+//! Each schedule is kept in a `<schedule_id>.schedule` file, so schedules
+//! survive a restart of the server. Schedule ids are never reused, the last
+//! id handed out is kept in the same directory. Runs missed while the server
+//! was down are made up for by a single run once it is back.
+//!----------------------------------------------------------------------------
+
+use crate::schema::verfploeter::{Schedule, ScheduleRun};
+use protobuf::{Message, RepeatedField};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+const EXTENSION: &str = "schedule";
+
+/// File with the id of the last schedule added, so ids of removed schedules are not reused
+const LAST_ID_FILE: &str = "last_schedule_id";
+
+/// Number of runs kept in the history of a schedule
+const MAX_RUNS: usize = 100;
+
+/// How far ahead the next match of a cron expression is searched for, a little over the
+/// four years in which every date (including February 29) occurs
+const CRON_SEARCH_DAYS: u64 = 5 * 366;
+
+const MINUTES_PER_DAY: u64 = 24 * 60;
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_of_year = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_of_year + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Year, month and day of a number of days since 1970-01-01
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_of_year = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_of_year + 2) / 5 + 1;
+    let month = if month_of_year < 10 {
+        month_of_year + 3
+    } else {
+        month_of_year - 9
+    };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+/// Parses a UTC time given as `YYYY-MM-DD HH:MM` (or with a `T` between date and time),
+/// or as seconds since the epoch
+pub fn parse_time(value: &str) -> Result<u64, String> {
+    let invalid = || format!("invalid time {}, expected YYYY-MM-DD HH:MM (UTC)", value);
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Ok(seconds);
+    }
+
+    let fields = value
+        .split(&['-', ' ', 'T', ':'][..])
+        .map(|field| field.parse::<u64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<u64>, String>>()?;
+    match fields.as_slice() {
+        [year, month, day, hour, minute] => {
+            let in_range = *year >= 1970 && *month >= 1 && *month <= 12 && *day >= 1;
+            if !in_range || *hour >= 24 || *minute >= 60 {
+                return Err(invalid());
+            }
+            // Days beyond the end of the month end up in the next month
+            let days = days_from_civil(*year, *month, *day);
+            if civil_from_days(days) != (*year, *month, *day) {
+                return Err(invalid());
+            }
+            Ok(days * 86400 + hour * 3600 + minute * 60)
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Formats seconds since the epoch as `YYYY-MM-DD HH:MM` (UTC)
+pub fn format_time(time: u64) -> String {
+    let (year, month, day) = civil_from_days(time / 86400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600 % 24,
+        time / 60 % 60
+    )
+}
+
+/// Parsed cron expression, every field is a bit mask of the values it matches
+#[derive(Debug, PartialEq)]
+pub struct Cron {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// Like cron, a day matches either field if both the day of the month and the day of
+    /// the week are restricted, and both fields otherwise
+    any_day: bool,
+    any_weekday: bool,
+}
+
+/// Parses a field of a cron expression: a comma-separated list of `*`, values and ranges,
+/// each optionally followed by `/step`
+fn parse_field(field: &str, min: u64, max: u64) -> Result<u64, String> {
+    let invalid = || format!("invalid cron field {}", field);
+    let mut mask = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.find('/') {
+            Some(idx) => (&item[..idx], Some(&item[idx + 1..])),
+            None => (item, None),
+        };
+        let step = match step {
+            Some(step) => step.parse::<u64>().map_err(|_| invalid())?,
+            None => 1,
+        };
+        let value = |value: &str| value.parse::<u64>().map_err(|_| invalid());
+        let (first, last) = if range == "*" {
+            (min, max)
+        } else if let Some(idx) = range.find('-') {
+            (value(&range[..idx])?, value(&range[idx + 1..])?)
+        } else if item.contains('/') {
+            (value(range)?, max)
+        } else {
+            (value(range)?, value(range)?)
+        };
+        if step == 0 || first < min || first > last || last > max {
+            return Err(invalid());
+        }
+        for value in (first..=last).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+impl Cron {
+    pub fn parse(expression: &str) -> Result<Cron, String> {
+        let fields = expression.split_whitespace().collect::<Vec<&str>>();
+        let (minutes, hours, days, months, weekdays) = match fields.as_slice() {
+            [minutes, hours, days, months, weekdays] => (minutes, hours, days, months, weekdays),
+            _ => {
+                return Err(format!(
+                    "invalid cron expression {}, expected 5 fields",
+                    expression
+                ))
+            }
+        };
+
+        // Sunday is both 0 and 7
+        let weekday_mask = parse_field(weekdays, 0, 7)?;
+        let weekday_mask = (weekday_mask | weekday_mask >> 7) & 0x7f;
+        let cron = Cron {
+            minutes: parse_field(minutes, 0, 59)?,
+            hours: parse_field(hours, 0, 23)?,
+            days: parse_field(days, 1, 31)?,
+            months: parse_field(months, 1, 12)?,
+            weekdays: weekday_mask,
+            any_day: *days == "*",
+            any_weekday: *weekdays == "*",
+        };
+        if cron.next_after(0).is_none() {
+            return Err(format!("cron expression {} never matches", expression));
+        }
+        Ok(cron)
+    }
+
+    fn day_matches(&self, days: u64) -> bool {
+        let (_, month, day) = civil_from_days(days);
+        // 1970-01-01 was a Thursday
+        let weekday = (days + 4) % 7;
+        let day_matches = self.days & 1 << day != 0;
+        let weekday_matches = self.weekdays & 1 << weekday != 0;
+        self.months & 1 << month != 0
+            && if self.any_day || self.any_weekday {
+                day_matches && weekday_matches
+            } else {
+                day_matches || weekday_matches
+            }
+    }
+
+    /// The first minute after time (in seconds since the epoch) that matches, or None if
+    /// nothing matches within the next years
+    pub fn next_after(&self, time: u64) -> Option<u64> {
+        let mut minute = time / 60 + 1;
+        let limit = minute + CRON_SEARCH_DAYS * MINUTES_PER_DAY;
+        while minute < limit {
+            let days = minute / MINUTES_PER_DAY;
+            if !self.day_matches(days) {
+                minute = (days + 1) * MINUTES_PER_DAY;
+            } else if self.hours & 1 << (minute / 60 % 24) == 0 {
+                minute = (minute / 60 + 1) * 60;
+            } else if self.minutes & 1 << (minute % 60) == 0 {
+                minute += 1;
+            } else {
+                return Some(minute * 60);
+            }
+        }
+        None
+    }
+}
+
+/// Checks that a schedule has a valid cron expression or interval, not both
+pub fn validate(schedule: &Schedule) -> Result<(), String> {
+    if !schedule.get_cron().is_empty() {
+        if schedule.get_interval() > 0 {
+            return Err("a schedule has either a cron expression or an interval".to_string());
+        }
+        Cron::parse(schedule.get_cron())?;
+    }
+    Ok(())
+}
+
+/// The time of the first run of a schedule after time, or None if it does not run anymore
+pub fn next_run(schedule: &Schedule, time: u64) -> Option<u64> {
+    let start_at = schedule.get_start_at();
+    if !schedule.get_cron().is_empty() {
+        let cron = Cron::parse(schedule.get_cron()).ok()?;
+        cron.next_after(time.max(start_at.saturating_sub(1)))
+    } else if time < start_at {
+        Some(start_at)
+    } else if schedule.get_interval() > 0 {
+        let interval = schedule.get_interval();
+        Some(start_at + ((time - start_at) / interval + 1) * interval)
+    } else {
+        None
+    }
+}
+
+pub struct ScheduleStore {
+    directory: PathBuf,
+    schedules: Mutex<BTreeMap<u32, Schedule>>,
+    last_schedule_id: Mutex<u32>,
+}
+
+impl ScheduleStore {
+    /// Opens (or creates) the store in the given directory, with the schedules added
+    /// before a restart
+    pub fn open<P: AsRef<Path>>(directory: P) -> io::Result<ScheduleStore> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory)?;
+        let mut schedules = BTreeMap::new();
+        for entry in fs::read_dir(&directory)? {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(EXTENSION) {
+                continue;
+            }
+            let bytes = fs::read(&path)?;
+            match protobuf::parse_from_bytes::<Schedule>(&bytes) {
+                Ok(schedule) => {
+                    schedules.insert(schedule.get_schedule_id(), schedule);
+                }
+                Err(e) => error!("ignoring unreadable schedule {}: {}", path.display(), e),
+            }
+        }
+        // Stores of older versions have no last id, their ids start after the highest one
+        let last_schedule_id = match fs::read_to_string(directory.join(LAST_ID_FILE)) {
+            Ok(content) => content.trim().parse::<u32>().map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid {}: {}", LAST_ID_FILE, e),
+                )
+            })?,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(e),
+        };
+        let last_schedule_id = schedules
+            .keys()
+            .next_back()
+            .map_or(last_schedule_id, |id| last_schedule_id.max(*id));
+        Ok(ScheduleStore {
+            directory,
+            schedules: Mutex::new(schedules),
+            last_schedule_id: Mutex::new(last_schedule_id),
+        })
+    }
+
+    fn path(&self, schedule_id: u32) -> PathBuf {
+        self.directory
+            .join(format!("{}.{}", schedule_id, EXTENSION))
+    }
+
+    /// Writes a schedule to a temporary file first, so a crash never leaves half a schedule
+    fn write(&self, schedule: &Schedule) -> io::Result<()> {
+        let bytes = schedule
+            .write_to_bytes()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        write_file(&self.path(schedule.get_schedule_id()), &bytes)
+    }
+
+    /// Adds a schedule and returns its id
+    pub fn add(&self, mut schedule: Schedule) -> io::Result<u32> {
+        let mut schedules = self.schedules.lock().unwrap();
+        let mut last_schedule_id = self.last_schedule_id.lock().unwrap();
+        let schedule_id = *last_schedule_id + 1;
+        write_file(
+            &self.directory.join(LAST_ID_FILE),
+            schedule_id.to_string().as_bytes(),
+        )?;
+        *last_schedule_id = schedule_id;
+        schedule.set_schedule_id(schedule_id);
+        self.write(&schedule)?;
+        schedules.insert(schedule_id, schedule);
+        Ok(schedule_id)
+    }
+
+    /// Removes a schedule, returns false if it does not exist
+    pub fn remove(&self, schedule_id: u32) -> io::Result<bool> {
+        let mut schedules = self.schedules.lock().unwrap();
+        if schedules.remove(&schedule_id).is_none() {
+            return Ok(false);
+        }
+        fs::remove_file(self.path(schedule_id))?;
+        Ok(true)
+    }
+
+    /// Returns all schedules, ordered by id, without their destinations
+    pub fn list(&self) -> Vec<Schedule> {
+        let schedules = self.schedules.lock().unwrap();
+        schedules
+            .values()
+            .map(|schedule| {
+                let mut schedule = schedule.clone();
+                schedule.mut_task().clear_destinations();
+                schedule
+            })
+            .collect()
+    }
+
+    /// Returns the schedules that are due to run at time
+    pub fn due(&self, time: u64) -> Vec<Schedule> {
+        let schedules = self.schedules.lock().unwrap();
+        schedules
+            .values()
+            .filter(|s| s.get_next_run() != 0 && s.get_next_run() <= time)
+            .cloned()
+            .collect()
+    }
+
+    /// Adds a run to the history of a schedule, and moves the schedule on to its next run
+    /// after the start of this one
+    pub fn record_run(&self, schedule_id: u32, run: ScheduleRun) -> io::Result<()> {
+        let mut schedules = self.schedules.lock().unwrap();
+        let schedule = match schedules.get_mut(&schedule_id) {
+            Some(schedule) => schedule,
+            // Removed while it was running
+            None => return Ok(()),
+        };
+        let next_run = next_run(schedule, run.get_start_time()).unwrap_or(0);
+        schedule.set_next_run(next_run);
+        let mut runs = schedule.take_runs().into_vec();
+        runs.push(run);
+        let excess = runs.len().saturating_sub(MAX_RUNS);
+        runs.drain(..excess);
+        schedule.set_runs(RepeatedField::from_vec(runs));
+        self.write(schedule)
+    }
+}
+
+/// Writes a file through a temporary file, so it is replaced as a whole
+fn write_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, bytes)?;
+    fs::rename(&temporary, path)
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod schedules {
+    use super::*;
+    use crate::schema::verfploeter::{Address, ScheduleTask};
+
+    // 2019-05-01 00:00 UTC, a Wednesday
+    const MAY_1: u64 = 1_556_668_800;
+
+    #[test]
+    fn times_are_parsed_and_formatted_in_utc() {
+        assert_eq!(parse_time("2019-05-01 00:00"), Ok(MAY_1));
+        assert_eq!(
+            parse_time("2019-05-01T13:37"),
+            Ok(MAY_1 + 13 * 3600 + 37 * 60)
+        );
+        assert_eq!(parse_time("1556668800"), Ok(MAY_1));
+        assert_eq!(format_time(MAY_1 + 13 * 3600 + 37 * 60), "2019-05-01 13:37");
+        assert_eq!(format_time(0), "1970-01-01 00:00");
+        assert_eq!(
+            parse_time("2020-02-29 12:00").map(format_time),
+            Ok("2020-02-29 12:00".to_string())
+        );
+
+        assert!(parse_time("2019-02-29 00:00").is_err());
+        assert!(parse_time("2019-13-01 00:00").is_err());
+        assert!(parse_time("2019-05-01 24:00").is_err());
+        assert!(parse_time("tomorrow").is_err());
+    }
+
+    #[test]
+    fn cron_finds_next_match() {
+        let daily = Cron::parse("30 2 * * *").unwrap();
+        assert_eq!(daily.next_after(MAY_1), Some(MAY_1 + 2 * 3600 + 30 * 60));
+        assert_eq!(
+            daily.next_after(MAY_1 + 2 * 3600 + 30 * 60),
+            Some(MAY_1 + 86400 + 2 * 3600 + 30 * 60)
+        );
+
+        let quarterly = Cron::parse("*/15 * * * *").unwrap();
+        assert_eq!(quarterly.next_after(MAY_1 + 60), Some(MAY_1 + 15 * 60));
+
+        // Mondays, the first Monday after May 1 2019 is May 6
+        let weekly = Cron::parse("0 0 * * 1").unwrap();
+        assert_eq!(weekly.next_after(MAY_1), Some(MAY_1 + 5 * 86400));
+
+        // The 10th of the month or a Sunday, whichever comes first
+        let either = Cron::parse("0 0 10 * 0").unwrap();
+        assert_eq!(either.next_after(MAY_1), Some(MAY_1 + 4 * 86400));
+        assert_eq!(Cron::parse("0 0 10 * 7"), Cron::parse("0 0 10 * 0"));
+
+        let leap_day = Cron::parse("0 0 29 2 *").unwrap();
+        assert_eq!(
+            leap_day.next_after(MAY_1).map(format_time),
+            Some("2020-02-29 00:00".to_string())
+        );
+    }
+
+    #[test]
+    fn cron_rejects_invalid_expressions() {
+        assert!(Cron::parse("0 0 * *").is_err());
+        assert!(Cron::parse("60 0 * * *").is_err());
+        assert!(Cron::parse("0 0 0 * *").is_err());
+        assert!(Cron::parse("*/0 0 * * *").is_err());
+        assert!(Cron::parse("5-1 0 * * *").is_err());
+        assert!(
+            Cron::parse("0 0 31 2 *").is_err(),
+            "February 31 never happens"
+        );
+    }
+
+    #[test]
+    fn intervals_and_one_off_runs() {
+        let mut schedule = Schedule::new();
+        schedule.set_start_at(MAY_1);
+        assert_eq!(next_run(&schedule, MAY_1 - 1), Some(MAY_1));
+        assert_eq!(next_run(&schedule, MAY_1), None);
+
+        // Missed runs are skipped
+        schedule.set_interval(3600);
+        assert_eq!(next_run(&schedule, MAY_1), Some(MAY_1 + 3600));
+        assert_eq!(
+            next_run(&schedule, MAY_1 + 3 * 3600 + 5),
+            Some(MAY_1 + 4 * 3600)
+        );
+
+        schedule.set_cron("0 * * * *".to_string());
+        assert!(validate(&schedule).is_err());
+        schedule.set_interval(0);
+        assert!(validate(&schedule).is_ok());
+        assert_eq!(next_run(&schedule, 0), Some(MAY_1));
+    }
+
+    #[test]
+    fn schedules_are_stored() {
+        let directory =
+            std::env::temp_dir().join(format!("verfploeter-schedules-{}", std::process::id()));
+        fs::remove_dir_all(&directory).ok();
+        let store = ScheduleStore::open(&directory).unwrap();
+
+        let mut schedule = Schedule::new();
+        let mut task = ScheduleTask::new();
+        task.mut_destinations()
+            .mut_addresses()
+            .push(Address::from(std::net::Ipv4Addr::LOCALHOST));
+        schedule.set_task(task);
+        schedule.set_interval(60);
+        schedule.set_start_at(MAY_1);
+        schedule.set_next_run(MAY_1);
+        assert_eq!(store.add(schedule.clone()).unwrap(), 1);
+        assert_eq!(store.add(schedule).unwrap(), 2);
+        assert!(store.remove(2).unwrap());
+        assert!(!store.remove(2).unwrap());
+
+        assert!(store.due(MAY_1 - 1).is_empty());
+        let due = store.due(MAY_1);
+        assert_eq!(due.len(), 1);
+        assert_eq!(
+            due[0].get_task().get_destinations().get_addresses().len(),
+            1
+        );
+        assert!(!store.list()[0].get_task().has_destinations());
+
+        let mut run = ScheduleRun::new();
+        run.set_task_id(7);
+        run.set_start_time(MAY_1 + 10);
+        store.record_run(1, run).unwrap();
+        drop(store);
+
+        let store = ScheduleStore::open(&directory).unwrap();
+        let list = store.list();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].get_next_run(), MAY_1 + 60);
+        assert_eq!(list[0].get_runs()[0].get_task_id(), 7);
+        // The id of the removed schedule is not handed out again
+        assert_eq!(store.add(Schedule::new()).unwrap(), 3);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
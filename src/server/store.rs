@@ -0,0 +1,191 @@
+//!----------------------------------------------------------------------------
+//! # Verfploeter Server result store (store.rs)
+//!----------------------------------------------------------------------------
+//! Keeps every TaskResult received by the server on disk, one file per task id,
+//! so results can be fetched again after the CLI lost its result stream.
+//!
+//! Each task has a `<task_id>.results` file containing length-delimited
+//! TaskResult messages in order of arrival, and a `<task_id>.finished` marker
+//! once no more results are expected.
+//!----------------------------------------------------------------------------
+
+use crate::schema::verfploeter::TaskResult;
+use protobuf::{CodedInputStream, Message};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub struct ResultStore {
+    directory: PathBuf,
+    write_lock: Mutex<()>,
+}
+
+impl ResultStore {
+    /// Opens (or creates) the store in the given directory. Tasks left unfinished by
+    /// a previous server run are marked finished, as their clients are gone. Their
+    /// results end in a TaskResult with is_finished set but without totals, which were
+    /// lost with the previous run.
+    pub fn open<P: AsRef<Path>>(directory: P) -> io::Result<ResultStore> {
+        let store = ResultStore {
+            directory: directory.as_ref().to_path_buf(),
+            write_lock: Mutex::new(()),
+        };
+        fs::create_dir_all(&store.directory)?;
+        for task_id in store.task_ids()? {
+            if !store.is_finished(task_id) {
+                warn!("marking task {} from a previous run as finished", task_id);
+                let mut final_result = TaskResult::new();
+                final_result.set_task_id(task_id);
+                final_result.set_is_finished(true);
+                store.append(task_id, &final_result)?;
+                store.finish(task_id)?;
+            }
+        }
+        Ok(store)
+    }
+
+    /// Appends a result to the store of its task
+    pub fn append(&self, task_id: u32, result: &TaskResult) -> io::Result<()> {
+        let _guard = self.write_lock.lock().unwrap();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.results_path(task_id))?;
+        let mut writer = BufWriter::new(file);
+        result
+            .write_length_delimited_to_writer(&mut writer)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        writer.flush()
+    }
+
+    /// Reads the stored results of a task, skipping the first `offset` results
+    pub fn read(&self, task_id: u32, offset: usize) -> io::Result<Vec<TaskResult>> {
+        let file = match File::open(self.results_path(task_id)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut reader = BufReader::new(file);
+        let mut input = CodedInputStream::new(&mut reader);
+
+        let mut results = Vec::new();
+        let mut index = 0;
+        while !input
+            .eof()
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+        {
+            match input.read_message::<TaskResult>() {
+                Ok(result) => {
+                    if index >= offset {
+                        results.push(result);
+                    }
+                    index += 1;
+                }
+                Err(e) => {
+                    // A record can only be cut short by a crash while writing, which
+                    // leaves it as the last one in the file
+                    warn!("ignoring truncated result in store of task {}: {}", task_id, e);
+                    break;
+                }
+            }
+        }
+        Ok(results)
+    }
+
+    /// Writes the results of all unfinished tasks through to disk, after a result that is
+    /// being appended has been written completely
+    pub fn sync(&self) -> io::Result<()> {
+        let _guard = self.write_lock.lock().unwrap();
+        for task_id in self.task_ids()? {
+            if !self.is_finished(task_id) {
+                File::open(self.results_path(task_id))?.sync_all()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks a task as finished, no more results will be appended
+    pub fn finish(&self, task_id: u32) -> io::Result<()> {
+        File::create(self.finished_path(task_id)).map(|_| ())
+    }
+
+    pub fn is_finished(&self, task_id: u32) -> bool {
+        self.finished_path(task_id).exists()
+    }
+
+    /// Returns the highest task id in the store, so task ids are not reused after a restart
+    pub fn last_task_id(&self) -> io::Result<Option<u32>> {
+        Ok(self.task_ids()?.into_iter().max())
+    }
+
+    fn task_ids(&self) -> io::Result<Vec<u32>> {
+        let mut task_ids = Vec::new();
+        for entry in fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            if path.extension().map_or(false, |e| e == "results") {
+                if let Some(task_id) = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .and_then(|s| s.parse::<u32>().ok())
+                {
+                    task_ids.push(task_id);
+                }
+            }
+        }
+        Ok(task_ids)
+    }
+
+    fn results_path(&self, task_id: u32) -> PathBuf {
+        self.directory.join(format!("{}.results", task_id))
+    }
+
+    fn finished_path(&self, task_id: u32) -> PathBuf {
+        self.directory.join(format!("{}.finished", task_id))
+    }
+}
+
+/// Test functions - can be run with 'cargo test'
+#[cfg(test)]
+mod result_store {
+    use super::*;
+    use std::env;
+
+    fn test_store(name: &str) -> ResultStore {
+        let directory = env::temp_dir().join(format!("verfploeter-store-{}", name));
+        fs::remove_dir_all(&directory).ok();
+        ResultStore::open(&directory).unwrap()
+    }
+
+    fn task_result(task_id: u32, is_finished: bool) -> TaskResult {
+        let mut result = TaskResult::new();
+        result.set_task_id(task_id);
+        result.set_is_finished(is_finished);
+        result
+    }
+
+    #[test]
+    fn results_are_read_in_order() {
+        let store = test_store("order");
+        store.append(1, &task_result(1, false)).unwrap();
+        store.append(1, &task_result(1, true)).unwrap();
+        store.append(2, &task_result(2, false)).unwrap();
+
+        let results = store.read(1, 0).unwrap();
+        assert_eq!(results, vec![task_result(1, false), task_result(1, true)]);
+        assert_eq!(store.read(1, 1).unwrap(), vec![task_result(1, true)]);
+        assert!(store.read(3, 0).unwrap().is_empty());
+        assert_eq!(store.last_task_id().unwrap(), Some(2));
+    }
+
+    #[test]
+    fn unfinished_tasks_are_finished_on_reopen() {
+        let store = test_store("reopen");
+        store.append(5, &task_result(5, false)).unwrap();
+        assert!(!store.is_finished(5));
+
+        let store = ResultStore::open(&store.directory).unwrap();
+        assert!(store.is_finished(5));
+        assert_eq!(store.read(5, 0).unwrap(), vec![task_result(5, false), task_result(5, true)]);
+    }
+}